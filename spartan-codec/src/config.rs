@@ -0,0 +1,276 @@
+//! Configuration of [`Spartan`] with non-default prime, prime size and piece size
+
+use crate::{BlockMapping, IvDerivation, Piece, Spartan, Tweaks};
+use core::fmt;
+use spartan_sloth::ParametersError;
+
+/// SLOTH implementation used by [`SpartanConfig::build()`], supports any prime size, selected with
+/// cargo features
+#[cfg(feature = "software")]
+pub type ConfigurableSloth<const PRIME_SIZE_BYTES: usize, const PIECE_SIZE_BYTES: usize> =
+    spartan_sloth::software::Sloth<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>;
+/// SLOTH implementation used by [`SpartanConfig::build()`], supports any prime size, selected with
+/// cargo features
+#[cfg(all(not(feature = "software"), any(feature = "portable", feature = "auto")))]
+pub type ConfigurableSloth<const PRIME_SIZE_BYTES: usize, const PIECE_SIZE_BYTES: usize> =
+    spartan_sloth::portable::Sloth<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>;
+
+/// Errors that can happen when [`Spartan`] is built from [`SpartanConfig`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ConfigError {
+    /// Default number of rounds is zero
+    ZeroRounds,
+    /// Prime was not provided and there is no known prime for `PRIME_SIZE_BYTES`
+    MissingPrime,
+    /// Provided prime can't be used for SLOTH
+    InvalidPrime(ParametersError),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::ZeroRounds => write!(f, "Number of rounds must not be zero"),
+            ConfigError::MissingPrime => write!(f, "No known prime for this prime size"),
+            ConfigError::InvalidPrime(error) => write!(f, "Invalid prime: {}", error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConfigError {}
+
+impl From<ParametersError> for ConfigError {
+    fn from(error: ParametersError) -> Self {
+        ConfigError::InvalidPrime(error)
+    }
+}
+
+/// Builder for [`Spartan`] with prime of `PRIME_SIZE_BYTES` and pieces of `PIECE_SIZE_BYTES`
+///
+/// Sizes are checked at compile time: prime must be at least 256 bits and piece must consist of
+/// at least two blocks of prime size. By default the largest known prime of given size is used
+/// (see [`spartan_sloth::largest_known_prime()`]) with one round, [`IvDerivation::LegacyXor`],
+/// [`Tweaks::None`] and [`BlockMapping::Swap`].
+#[derive(Debug, Copy, Clone)]
+pub struct SpartanConfig<const PRIME_SIZE_BYTES: usize = 32, const PIECE_SIZE_BYTES: usize = 4096> {
+    prime: Option<[u8; PRIME_SIZE_BYTES]>,
+    iv_derivation: IvDerivation,
+    tweaks: Tweaks,
+    block_mapping: BlockMapping,
+    rounds: usize,
+}
+
+impl<const PRIME_SIZE_BYTES: usize, const PIECE_SIZE_BYTES: usize> Default
+    for SpartanConfig<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const PRIME_SIZE_BYTES: usize, const PIECE_SIZE_BYTES: usize>
+    SpartanConfig<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>
+{
+    /// New config with the largest known prime and one round
+    pub fn new() -> Self {
+        Self {
+            prime: None,
+            iv_derivation: IvDerivation::default(),
+            tweaks: Tweaks::default(),
+            block_mapping: BlockMapping::default(),
+            rounds: 1,
+        }
+    }
+
+    /// Use explicitly provided prime (as little-endian bytes) instead of the largest known one
+    pub fn prime(mut self, prime: [u8; PRIME_SIZE_BYTES]) -> Self {
+        self.prime.replace(prime);
+        self
+    }
+
+    /// IV derivation, available as [`Spartan::iv_derivation()`]
+    pub fn iv_derivation(mut self, iv_derivation: IvDerivation) -> Self {
+        self.iv_derivation = iv_derivation;
+        self
+    }
+
+    /// Tweaks, available as [`Spartan::tweaks()`]
+    pub fn tweaks(mut self, tweaks: Tweaks) -> Self {
+        self.tweaks = tweaks;
+        self
+    }
+
+    /// Mapping of blocks outside of field, see [`BlockMapping`]
+    pub fn block_mapping(mut self, block_mapping: BlockMapping) -> Self {
+        self.block_mapping = block_mapping;
+        self
+    }
+
+    /// Default number of rounds, available as [`Spartan::rounds()`] and used by
+    /// [`Spartan::encode_default_rounds()`] and [`Spartan::is_valid_default_rounds()`]
+    pub fn rounds(mut self, rounds: usize) -> Self {
+        self.rounds = rounds;
+        self
+    }
+
+    /// Validates config and creates [`Spartan`] instance with provided genesis piece
+    pub fn build(
+        self,
+        genesis_piece: Piece<PIECE_SIZE_BYTES>,
+    ) -> Result<
+        Spartan<
+            ConfigurableSloth<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>,
+            PRIME_SIZE_BYTES,
+            PIECE_SIZE_BYTES,
+        >,
+        ConfigError,
+    > {
+        if self.rounds == 0 {
+            return Err(ConfigError::ZeroRounds);
+        }
+        let prime = self
+            .prime
+            .or_else(spartan_sloth::largest_known_prime::<PRIME_SIZE_BYTES>)
+            .ok_or(ConfigError::MissingPrime)?;
+
+        #[cfg(feature = "software")]
+        let sloth = ConfigurableSloth::try_with_prime_bytes(prime)?;
+        #[cfg(not(feature = "software"))]
+        let sloth = ConfigurableSloth::try_with_prime(prime)?;
+        let sloth = sloth.with_block_mapping(self.block_mapping);
+
+        Ok(
+            Spartan::with_sloth_and_rounds(genesis_piece, sloth, self.rounds)
+                .with_iv_derivation(self.iv_derivation)
+                .with_tweaks(self.tweaks),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EncodingKeyHash;
+    use rand::prelude::*;
+
+    fn random_bytes<const BYTES: usize>() -> [u8; BYTES] {
+        let mut bytes = [0u8; BYTES];
+        rand::thread_rng().fill(&mut bytes[..]);
+        bytes
+    }
+
+    #[test]
+    fn test_larger_prime_and_piece() {
+        let genesis_piece = Piece::from(random_bytes::<8192>());
+        let spartan = SpartanConfig::<64, 8192>::new()
+            .iv_derivation(IvDerivation::Hashed(Default::default()))
+            .tweaks(Tweaks::PerBlock)
+            .rounds(2)
+            .build(genesis_piece)
+            .unwrap();
+        assert_eq!(spartan.rounds(), 2);
+        assert_eq!(spartan.tweaks(), Tweaks::PerBlock);
+
+        let encoding_key_hash = EncodingKeyHash::from(rand::random::<[u8; 32]>());
+        let nonce = rand::random::<u64>();
+        let encoding = spartan
+            .encode_default_rounds(encoding_key_hash, nonce)
+            .unwrap();
+        assert_ne!(encoding.as_ref(), genesis_piece.as_ref());
+        assert_eq!(
+            encoding,
+            spartan.encode(encoding_key_hash, nonce, 2).unwrap()
+        );
+        assert!(spartan
+            .is_valid_default_rounds(encoding, encoding_key_hash, nonce)
+            .unwrap());
+        assert!(!spartan
+            .is_valid_default_rounds(encoding, encoding_key_hash, nonce.wrapping_add(1))
+            .unwrap());
+        assert!(!spartan
+            .is_valid(encoding, encoding_key_hash, nonce, 1)
+            .unwrap());
+
+        // Scratch is `()` for implementations that don't need it
+        #[allow(clippy::let_unit_value)]
+        let mut scratch = Default::default();
+        assert!(spartan
+            .is_valid_slice(
+                encoding.as_ref(),
+                encoding_key_hash,
+                nonce,
+                spartan.rounds(),
+                &mut vec![0u8; 8192],
+                &mut scratch,
+            )
+            .unwrap());
+    }
+
+    #[test]
+    fn test_default_sizes_match_spartan() {
+        let genesis_piece = Piece::from(random_bytes());
+        let spartan = Spartan::new(genesis_piece);
+        let configured = SpartanConfig::<32, 4096>::new()
+            .prime(spartan_sloth::largest_known_prime().unwrap())
+            .build(genesis_piece)
+            .unwrap();
+        assert_eq!(configured.rounds(), 1);
+
+        let encoding_key_hash = EncodingKeyHash::from(rand::random::<[u8; 32]>());
+        let nonce = rand::random();
+        assert_eq!(
+            configured.encode(encoding_key_hash, nonce, 2).unwrap(),
+            spartan.encode(encoding_key_hash, nonce, 2).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_block_mapping() {
+        let genesis_piece = Piece::from([0xffu8; 4096]);
+        let spartan = SpartanConfig::<32, 4096>::new()
+            .build(genesis_piece)
+            .unwrap();
+        let rotating = SpartanConfig::<32, 4096>::new()
+            .block_mapping(BlockMapping::Rotate)
+            .build(genesis_piece)
+            .unwrap();
+
+        // Zero IV, such that blocks of genesis piece are outside of field
+        let encoding_key_hash = EncodingKeyHash::from([0u8; 32]);
+        let encoding = rotating.encode(encoding_key_hash, 0, 2).unwrap();
+        assert_ne!(encoding, spartan.encode(encoding_key_hash, 0, 2).unwrap());
+        assert!(rotating
+            .is_valid(encoding, encoding_key_hash, 0, 2)
+            .unwrap());
+        assert!(!spartan.is_valid(encoding, encoding_key_hash, 0, 2).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        let genesis_piece = Piece::from([5u8; 4096]);
+
+        assert_eq!(
+            SpartanConfig::<32, 4096>::new()
+                .rounds(0)
+                .build(genesis_piece)
+                .unwrap_err(),
+            ConfigError::ZeroRounds
+        );
+
+        // `2^256 - 1` is `3 mod 4`, but divisible by 3
+        assert_eq!(
+            SpartanConfig::new()
+                .prime([0xff; 32])
+                .build(genesis_piece)
+                .unwrap_err(),
+            ConfigError::InvalidPrime(ParametersError::NotPrime)
+        );
+
+        assert_eq!(
+            SpartanConfig::<48, 4800>::new()
+                .build(Piece::from([5u8; 4800]))
+                .unwrap_err(),
+            ConfigError::MissingPrime
+        );
+    }
+}
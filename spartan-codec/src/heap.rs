@@ -0,0 +1,295 @@
+//! [`HeapSpartan`] with genesis piece of size chosen at runtime
+
+use crate::{
+    decode_piece_into, encode_piece_into, DecodeError, DefaultSloth, EncodeError, EncodingKeyHash,
+    ExpandedIv, IvDerivation, SlothBackend, Spartan, Tweaks,
+};
+
+/// Same as [`Spartan`], but genesis piece is allocated on the heap and its size is chosen at
+/// runtime (for instance, 1 MiB), encodings are passed as slices of the same size
+///
+/// Genesis piece size must be a multiple of `PRIME_SIZE_BYTES` and consist of at least two blocks,
+/// `PIECE_SIZE_BYTES` only selects [`SlothBackend`] implementation and doesn't limit piece size.
+#[derive(Debug, Clone)]
+pub struct HeapSpartan<
+    Sloth: SlothBackend<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES> = DefaultSloth,
+    const PRIME_SIZE_BYTES: usize = 32,
+    const PIECE_SIZE_BYTES: usize = 4096,
+> {
+    genesis_piece: Vec<u8>,
+    sloth: Sloth,
+    /// Prime of SLOTH implementation, used for IV derivation
+    prime: [u8; PRIME_SIZE_BYTES],
+    iv_derivation: IvDerivation,
+    tweaks: Tweaks,
+}
+
+impl HeapSpartan {
+    /// New instance with 256-bit prime, uses [`DefaultSloth`] implementation
+    ///
+    /// Returns an error if genesis piece size is not a multiple of 32 bytes or is shorter than 64
+    /// bytes.
+    pub fn new(genesis_piece: Vec<u8>) -> Result<Self, EncodeError> {
+        Self::with_sloth(genesis_piece, crate::default_sloth())
+    }
+}
+
+impl<
+        Sloth: SlothBackend<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>,
+        const PRIME_SIZE_BYTES: usize,
+        const PIECE_SIZE_BYTES: usize,
+    > HeapSpartan<Sloth, PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>
+{
+    /// New instance with explicitly provided SLOTH implementation
+    ///
+    /// Returns an error if genesis piece size is not a multiple of `PRIME_SIZE_BYTES` or is shorter
+    /// than two blocks.
+    pub fn with_sloth(genesis_piece: Vec<u8>, sloth: Sloth) -> Result<Self, EncodeError> {
+        #[allow(clippy::let_unit_value)]
+        let () = Spartan::<Sloth, PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>::VALID_PRIME_SIZE;
+
+        // Whole blocks, at least two of them, same as SLOTH implementations require
+        if genesis_piece.len() < 2 * PRIME_SIZE_BYTES
+            || !genesis_piece.len().is_multiple_of(PRIME_SIZE_BYTES)
+        {
+            return Err(EncodeError::InvalidLength);
+        }
+
+        Ok(Self {
+            genesis_piece,
+            prime: sloth.prime(),
+            sloth,
+            iv_derivation: IvDerivation::default(),
+            tweaks: Tweaks::default(),
+        })
+    }
+
+    /// Use provided IV derivation instead of [`IvDerivation::LegacyXor`]
+    pub fn with_iv_derivation(mut self, iv_derivation: IvDerivation) -> Self {
+        self.iv_derivation = iv_derivation;
+        self
+    }
+
+    /// IV derivation used for encoding and validation
+    pub fn iv_derivation(&self) -> IvDerivation {
+        self.iv_derivation
+    }
+
+    /// Use provided tweaks instead of [`Tweaks::None`]
+    pub fn with_tweaks(mut self, tweaks: Tweaks) -> Self {
+        self.tweaks = tweaks;
+        self
+    }
+
+    /// Tweaks used for encoding and validation
+    pub fn tweaks(&self) -> Tweaks {
+        self.tweaks
+    }
+
+    /// Size of genesis piece and hence of every encoding in bytes
+    pub fn piece_size(&self) -> usize {
+        self.genesis_piece.len()
+    }
+
+    fn expand_iv(
+        &self,
+        encoding_key_hash: EncodingKeyHash,
+        nonce: u64,
+    ) -> ExpandedIv<PRIME_SIZE_BYTES> {
+        self.iv_derivation
+            .derive(&self.prime, encoding_key_hash, nonce)
+    }
+
+    /// Create an encoding based on genesis piece using provided encoding key hash, nonce and
+    /// desired number of rounds
+    ///
+    /// Returns an error if `rounds` is zero.
+    pub fn encode(
+        &self,
+        encoding_key_hash: EncodingKeyHash,
+        nonce: u64,
+        rounds: usize,
+    ) -> Result<Vec<u8>, EncodeError> {
+        let mut encoding = vec![0u8; self.piece_size()];
+        self.encode_into(
+            encoding_key_hash,
+            nonce,
+            rounds,
+            &mut encoding,
+            &mut Sloth::Scratch::default(),
+        )?;
+
+        Ok(encoding)
+    }
+
+    /// Same as [`HeapSpartan::encode()`], but encodes directly into provided buffer of piece size
+    /// and reuses provided scratch, such that encoding doesn't allocate on the heap
+    ///
+    /// Returns an error if `rounds` is zero or `encoding` has wrong length.
+    pub fn encode_into(
+        &self,
+        encoding_key_hash: EncodingKeyHash,
+        nonce: u64,
+        rounds: usize,
+        encoding: &mut [u8],
+        scratch: &mut Sloth::Scratch,
+    ) -> Result<(), EncodeError> {
+        encode_piece_into(
+            &self.sloth,
+            &self.genesis_piece,
+            encoding,
+            self.expand_iv(encoding_key_hash, nonce),
+            encoding_key_hash,
+            rounds,
+            self.tweaks,
+            scratch,
+        )
+    }
+
+    /// Check if previously created encoding is valid
+    ///
+    /// Returns an error if `rounds` is zero or `encoding` has wrong length.
+    pub fn is_valid(
+        &self,
+        encoding: &[u8],
+        encoding_key_hash: EncodingKeyHash,
+        nonce: u64,
+        rounds: usize,
+    ) -> Result<bool, DecodeError> {
+        self.is_valid_with_scratch(
+            encoding,
+            encoding_key_hash,
+            nonce,
+            rounds,
+            &mut vec![0u8; self.piece_size()],
+            &mut Sloth::Scratch::default(),
+        )
+    }
+
+    /// Same as [`HeapSpartan::is_valid()`], but decodes encoding into `decoding` buffer of piece
+    /// size and reuses provided scratch, such that validation doesn't allocate on the heap
+    ///
+    /// Returns an error if `rounds` is zero or `encoding` or `decoding` has wrong length.
+    pub fn is_valid_with_scratch(
+        &self,
+        encoding: &[u8],
+        encoding_key_hash: EncodingKeyHash,
+        nonce: u64,
+        rounds: usize,
+        decoding: &mut [u8],
+        scratch: &mut Sloth::Scratch,
+    ) -> Result<bool, DecodeError> {
+        if encoding.len() != self.piece_size() {
+            return Err(DecodeError::InvalidLength);
+        }
+
+        decode_piece_into(
+            &self.sloth,
+            encoding,
+            decoding,
+            self.expand_iv(encoding_key_hash, nonce),
+            encoding_key_hash,
+            rounds,
+            self.tweaks,
+            scratch,
+        )?;
+
+        Ok(*decoding == *self.genesis_piece)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Encoding, Piece};
+    use rand::prelude::*;
+    use std::convert::TryFrom;
+
+    fn random_vec(length: usize) -> Vec<u8> {
+        let mut bytes = vec![0u8; length];
+        rand::thread_rng().fill(&mut bytes[..]);
+        bytes
+    }
+
+    #[test]
+    fn test_matches_spartan() {
+        let genesis_piece = random_vec(4096);
+        let encoding_key = EncodingKeyHash::from([3u8; 32]);
+
+        for &tweaks in &[Tweaks::None, Tweaks::PerBlock] {
+            let spartan = Spartan::new(Piece::from(
+                <[u8; 4096]>::try_from(genesis_piece.as_slice()).unwrap(),
+            ))
+            .with_tweaks(tweaks);
+            let heap_spartan = HeapSpartan::new(genesis_piece.clone())
+                .unwrap()
+                .with_tweaks(tweaks);
+
+            let encoding = heap_spartan.encode(encoding_key, 5, 2).unwrap();
+            assert_eq!(
+                encoding,
+                spartan.encode(encoding_key, 5, 2).unwrap().as_ref()
+            );
+            assert!(heap_spartan
+                .is_valid(&encoding, encoding_key, 5, 2)
+                .unwrap());
+            assert!(spartan
+                .is_valid(
+                    Encoding::from(<[u8; 4096]>::try_from(encoding.as_slice()).unwrap()),
+                    encoding_key,
+                    5,
+                    2
+                )
+                .unwrap());
+        }
+    }
+
+    #[test]
+    fn test_large_piece() {
+        // Piece of this size is impractical to pass by value on the stack
+        let heap_spartan = HeapSpartan::new(random_vec(1024 * 1024)).unwrap();
+        assert_eq!(heap_spartan.piece_size(), 1024 * 1024);
+        let encoding_key = EncodingKeyHash::from([3u8; 32]);
+        // Scratch is `()` for implementations that don't need it
+        #[allow(clippy::let_unit_value)]
+        let mut scratch = Default::default();
+
+        let mut encoding = vec![0u8; heap_spartan.piece_size()];
+        let mut decoding = vec![0u8; heap_spartan.piece_size()];
+        heap_spartan
+            .encode_into(encoding_key, 5, 1, &mut encoding, &mut scratch)
+            .unwrap();
+        assert!(heap_spartan
+            .is_valid_with_scratch(&encoding, encoding_key, 5, 1, &mut decoding, &mut scratch)
+            .unwrap());
+        assert!(!heap_spartan
+            .is_valid_with_scratch(&encoding, encoding_key, 6, 1, &mut decoding, &mut scratch)
+            .unwrap());
+
+        assert_eq!(
+            heap_spartan.encode_into(encoding_key, 5, 1, &mut encoding[32..], &mut scratch),
+            Err(EncodeError::InvalidLength)
+        );
+        assert_eq!(
+            heap_spartan.is_valid(&encoding[32..], encoding_key, 5, 1),
+            Err(DecodeError::InvalidLength)
+        );
+        assert_eq!(
+            heap_spartan.encode(encoding_key, 5, 0),
+            Err(EncodeError::ZeroLayers)
+        );
+    }
+
+    #[test]
+    fn test_invalid_piece_size() {
+        assert_eq!(
+            HeapSpartan::new(vec![0u8; 32]).unwrap_err(),
+            EncodeError::InvalidLength
+        );
+        assert_eq!(
+            HeapSpartan::new(vec![0u8; 4097]).unwrap_err(),
+            EncodeError::InvalidLength
+        );
+        assert!(HeapSpartan::new(vec![0u8; 64]).is_ok());
+    }
+}
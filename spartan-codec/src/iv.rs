@@ -0,0 +1,209 @@
+//! Derivation of expanded IV from encoding key hash and nonce
+
+use crate::{EncodingKeyHash, ExpandedIv};
+use core::cmp::Ordering;
+use sha2::{Digest, Sha256};
+
+/// Prefix of every hash of [`IvDerivation::Hashed`], keeps expanded IVs apart from tweaks that are
+/// also hashed from encoding key hash
+const DOMAIN_SEPARATION_TAG: &[u8] = b"spartan-codec/expanded-iv/v1";
+
+/// Domain that expanded IVs are derived for with [`IvDerivation::Hashed`], such that the same
+/// encoding key hash and nonce result in different IVs on different networks and for different
+/// pieces
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct IvDomain {
+    /// Identifier of the network (for instance, hash of the genesis block)
+    pub network_id: [u8; 32],
+    /// Index of the encoded piece
+    pub piece_index: u64,
+}
+
+/// How expanded IV is derived from encoding key hash and nonce
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum IvDerivation {
+    /// Version 0: little-endian bytes of nonce are XORed into the last 8 bytes of encoding key
+    /// hash, upper bytes of primes bigger than 256 bits are zero
+    ///
+    /// Kept for compatibility with existing encodings, different pairs of encoding key hash and
+    /// nonce result in the same IV whenever `key_hash_1 ^ nonce_1 == key_hash_2 ^ nonce_2`.
+    #[default]
+    LegacyXor,
+    /// Version 1: SHA-256 of domain separation tag, [`IvDomain`], encoding key hash and nonce,
+    /// expanded to prime size in counter mode and reduced below prime by rejection sampling
+    Hashed(IvDomain),
+}
+
+impl IvDerivation {
+    /// Derives expanded IV, `prime` is given as little-endian bytes
+    ///
+    /// Only called by codecs that already ensure that prime is at least 256 bits (see
+    /// `Spartan::VALID_PRIME_SIZE`) and comes from SLOTH backend, such that encoding key hash fits
+    /// into expanded IV and rejection sampling below prime terminates.
+    pub(crate) fn derive<const PRIME_SIZE_BYTES: usize>(
+        &self,
+        prime: &[u8; PRIME_SIZE_BYTES],
+        encoding_key_hash: EncodingKeyHash,
+        nonce: u64,
+    ) -> ExpandedIv<PRIME_SIZE_BYTES> {
+        match self {
+            IvDerivation::LegacyXor => legacy_xor(encoding_key_hash, nonce),
+            IvDerivation::Hashed(domain) => hashed(prime, domain, encoding_key_hash, nonce),
+        }
+    }
+}
+
+fn legacy_xor<const PRIME_SIZE_BYTES: usize>(
+    encoding_key_hash: EncodingKeyHash,
+    nonce: u64,
+) -> ExpandedIv<PRIME_SIZE_BYTES> {
+    debug_assert!(PRIME_SIZE_BYTES >= 32);
+
+    let mut expanded_iv = [0u8; PRIME_SIZE_BYTES];
+    expanded_iv[..32].copy_from_slice(encoding_key_hash.as_ref());
+    for (i, &byte) in nonce.to_le_bytes().iter().rev().enumerate() {
+        expanded_iv[32 - i - 1] ^= byte;
+    }
+    expanded_iv.into()
+}
+
+fn hashed<const PRIME_SIZE_BYTES: usize>(
+    prime: &[u8; PRIME_SIZE_BYTES],
+    domain: &IvDomain,
+    encoding_key_hash: EncodingKeyHash,
+    nonce: u64,
+) -> ExpandedIv<PRIME_SIZE_BYTES> {
+    let prime_bits = prime
+        .iter()
+        .rposition(|&byte| byte != 0)
+        .map_or(0, |i| i * 8 + 8 - prime[i].leading_zeros() as usize);
+    // There are no candidates below 0 or 1, only 0 is below 2
+    debug_assert!(prime_bits > 1);
+
+    let mut candidate = [0u8; PRIME_SIZE_BYTES];
+    for attempt in 0u32.. {
+        for (counter, chunk) in candidate.chunks_mut(32).enumerate() {
+            let hash = Sha256::new()
+                .chain_update(DOMAIN_SEPARATION_TAG)
+                .chain_update(domain.network_id)
+                .chain_update(domain.piece_index.to_le_bytes())
+                .chain_update(encoding_key_hash.as_ref())
+                .chain_update(nonce.to_le_bytes())
+                .chain_update(attempt.to_le_bytes())
+                .chain_update((counter as u32).to_le_bytes())
+                .finalize();
+            chunk.copy_from_slice(&hash[..chunk.len()]);
+        }
+
+        // Bits above the most significant bit of prime are cleared, such that each attempt
+        // succeeds with probability of at least 1/2
+        for (i, byte) in candidate.iter_mut().enumerate() {
+            let bits = prime_bits.saturating_sub(i * 8);
+            if bits < 8 {
+                *byte &= (1u8 << bits).wrapping_sub(1);
+            }
+        }
+
+        if candidate.iter().rev().cmp(prime.iter().rev()) == Ordering::Less {
+            break;
+        }
+    }
+
+    candidate.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prime() -> [u8; 32] {
+        spartan_sloth::largest_known_prime().unwrap()
+    }
+
+    #[test]
+    fn test_legacy_xor() {
+        let encoding_key_hash = EncodingKeyHash::from([0xff; 32]);
+        let expanded_iv = IvDerivation::LegacyXor.derive(&prime(), encoding_key_hash, 0x0102);
+
+        let mut expected = [0xffu8; 32];
+        expected[24] = 0xfd;
+        expected[25] = 0xfe;
+        assert_eq!(expanded_iv, ExpandedIv::from(expected));
+
+        // Upper bytes of bigger primes are zero
+        let expanded_iv = IvDerivation::LegacyXor.derive(&[0xff; 64], encoding_key_hash, 0x0102);
+        assert_eq!(expanded_iv.as_ref()[..32], expected);
+        assert_eq!(expanded_iv.as_ref()[32..], [0u8; 32]);
+    }
+
+    #[test]
+    fn test_hashed_separates_domains() {
+        let domain = IvDomain {
+            network_id: [1; 32],
+            piece_index: 2,
+        };
+        let hashed = IvDerivation::Hashed(domain);
+
+        // Collide with legacy derivation
+        let mut key_hash_1 = [3u8; 32];
+        let key_hash_2 = key_hash_1;
+        key_hash_1[24] ^= 1;
+        let (key_hash_1, key_hash_2) = (
+            EncodingKeyHash::from(key_hash_1),
+            EncodingKeyHash::from(key_hash_2),
+        );
+        assert_eq!(
+            IvDerivation::LegacyXor.derive(&prime(), key_hash_1, 0),
+            IvDerivation::LegacyXor.derive(&prime(), key_hash_2, 1)
+        );
+        let expanded_iv = hashed.derive(&prime(), key_hash_1, 0);
+        assert_ne!(expanded_iv, hashed.derive(&prime(), key_hash_2, 1));
+
+        assert_eq!(expanded_iv, hashed.derive(&prime(), key_hash_1, 0));
+        for other_domain in [
+            IvDomain {
+                network_id: [0; 32],
+                ..domain
+            },
+            IvDomain {
+                piece_index: 3,
+                ..domain
+            },
+        ] {
+            assert_ne!(
+                expanded_iv,
+                IvDerivation::Hashed(other_domain).derive(&prime(), key_hash_1, 0)
+            );
+        }
+    }
+
+    #[test]
+    fn test_hashed_below_prime() {
+        let hashed = IvDerivation::Hashed(IvDomain::default());
+
+        // `2^127 - 1` in 256-bit block
+        let mut small_prime = [0u8; 32];
+        small_prime[..16].copy_from_slice(&(u128::MAX >> 1).to_le_bytes());
+        // `2^512 - 569` needs two hashes
+        let mut large_prime = [0xffu8; 64];
+        large_prime[0] = 0xc7;
+        large_prime[1] = 0xfd;
+
+        for nonce in 0..100 {
+            let encoding_key_hash = EncodingKeyHash::from([nonce as u8; 32]);
+
+            let expanded_iv = hashed.derive(&small_prime, encoding_key_hash, nonce);
+            assert!(expanded_iv.as_ref()[..16] != small_prime[..16]);
+            assert!(expanded_iv.as_ref()[15] < 0x80);
+            assert_eq!(expanded_iv.as_ref()[16..], [0u8; 16]);
+
+            let expanded_iv = hashed.derive(&large_prime, encoding_key_hash, nonce);
+            assert!(expanded_iv
+                .as_ref()
+                .iter()
+                .rev()
+                .lt(large_prime.iter().rev()));
+            assert_ne!(expanded_iv.as_ref()[..32], expanded_iv.as_ref()[32..]);
+        }
+    }
+}
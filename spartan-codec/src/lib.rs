@@ -1,70 +1,633 @@
+//! A proof-of-concept encoder for the Subspace Network Blockchain based on the SLOTH permutation
+//!
+//! SLOTH implementation used by default is selected with cargo features, see [`DefaultSloth`].
+//!
+//! Crate is `no_std` compatible when `std` feature is disabled, use `portable` feature in this case.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![warn(rust_2018_idioms, missing_debug_implementations, missing_docs)]
-//! This is an adaptation of [SLOTH](https://eprint.iacr.org/2015/366) (slow-timed hash function) into a time-asymmetric permutation using a standard CBC block cipher. This code is largely based on the C implementation used in [PySloth](https://github.com/randomchain/pysloth/blob/master/sloth.c) which is the same as used in the paper.
 
-use spartan_sloth::software::Sloth;
+#[cfg(any(feature = "software", feature = "portable", feature = "auto"))]
+mod config;
+#[cfg(feature = "std")]
+mod heap;
+mod iv;
+mod scheme;
+mod tweaks;
 
-/// Max 256-bit prime for Spartan
-const PRIME: &str =
-    "115792089237316195423570985008687907853269984665640564039457584007913129639747";
+#[cfg(any(feature = "software", feature = "portable", feature = "auto"))]
+pub use config::{ConfigError, ConfigurableSloth, SpartanConfig};
+#[cfg(feature = "std")]
+pub use heap::HeapSpartan;
+pub use iv::{IvDerivation, IvDomain};
+pub use scheme::{SchemeError, SchemeVersion};
+#[cfg(feature = "parallel")]
+pub use spartan_sloth::parallel::{ParallelOptions, ThreadPool, ThreadPoolBuilder};
+pub use spartan_sloth::{
+    BlockMapping, Chaining, DecodeError, EncodeError, Encoding, EncodingKeyHash, ExpandedIv,
+    FromHexError, ParametersError, Piece, SlothBackend,
+};
+pub use tweaks::Tweaks;
+
+#[cfg(not(any(
+    feature = "auto",
+    feature = "software",
+    feature = "portable",
+    feature = "x86_64"
+)))]
+compile_error!(
+    "At least one of `auto`, `software`, `portable` or `x86_64` features must be enabled"
+);
+
+/// SLOTH implementation used by [`Spartan::new()`], selected with cargo features
+#[cfg(feature = "auto")]
+pub type DefaultSloth = spartan_sloth::auto::Sloth;
+/// SLOTH implementation used by [`Spartan::new()`], selected with cargo features
+#[cfg(all(feature = "x86_64", target_arch = "x86_64", not(feature = "auto")))]
+pub type DefaultSloth = spartan_sloth::x86_64::Sloth;
+/// SLOTH implementation used by [`Spartan::new()`], selected with cargo features
+#[cfg(all(
+    feature = "software",
+    not(feature = "auto"),
+    not(all(feature = "x86_64", target_arch = "x86_64"))
+))]
+pub type DefaultSloth = spartan_sloth::software::Sloth<32, 4096>;
+/// SLOTH implementation used by [`Spartan::new()`], selected with cargo features
+#[cfg(all(
+    feature = "portable",
+    not(feature = "auto"),
+    not(feature = "software"),
+    not(all(feature = "x86_64", target_arch = "x86_64"))
+))]
+pub type DefaultSloth = spartan_sloth::portable::Sloth<32, 4096>;
+
+#[cfg(feature = "auto")]
+fn default_sloth() -> DefaultSloth {
+    spartan_sloth::auto::Sloth::new()
+}
+
+#[cfg(all(feature = "x86_64", target_arch = "x86_64", not(feature = "auto")))]
+fn default_sloth() -> DefaultSloth {
+    spartan_sloth::x86_64::Sloth::new()
+}
+
+#[cfg(all(
+    feature = "software",
+    not(feature = "auto"),
+    not(all(feature = "x86_64", target_arch = "x86_64"))
+))]
+fn default_sloth() -> DefaultSloth {
+    spartan_sloth::software::Sloth::try_with_prime_bytes(
+        spartan_sloth::largest_known_prime().unwrap(),
+    )
+    .unwrap()
+}
+
+#[cfg(all(
+    feature = "portable",
+    not(feature = "auto"),
+    not(feature = "software"),
+    not(all(feature = "x86_64", target_arch = "x86_64"))
+))]
+fn default_sloth() -> DefaultSloth {
+    spartan_sloth::portable::Sloth::with_prime(spartan_sloth::largest_known_prime().unwrap())
+}
 
 /// Spartan struct used to encode and validate
+///
+/// Uses 256-bit prime and 4096-byte pieces by default, see [`SpartanConfig`] for other sizes.
 #[derive(Debug, Clone)]
-pub struct Spartan {
-    genesis_piece: [u8; 4096],
-    sloth: Sloth<32, 4096>,
+pub struct Spartan<
+    Sloth: SlothBackend<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES> = DefaultSloth,
+    const PRIME_SIZE_BYTES: usize = 32,
+    const PIECE_SIZE_BYTES: usize = 4096,
+> {
+    genesis_piece: Piece<PIECE_SIZE_BYTES>,
+    /// Genesis piece converted into internal representation of SLOTH implementation once, such
+    /// that it is not converted again for every encoding
+    prepared_genesis_piece: Sloth::PreparedPiece,
+    sloth: Sloth,
+    /// Prime of SLOTH implementation, used for IV derivation
+    prime: [u8; PRIME_SIZE_BYTES],
+    iv_derivation: IvDerivation,
+    tweaks: Tweaks,
+    rounds: usize,
 }
 
 impl Spartan {
-    fn new_internal(genesis_piece: [u8; 4096]) -> Self {
-        let sloth: Sloth<32, 4096> = Sloth::with_prime(PRIME.parse().unwrap());
+    /// New instance with 256-bit prime and 4096-byte genesis piece size, uses [`DefaultSloth`]
+    /// implementation
+    pub fn new(genesis_piece: Piece) -> Self {
+        Self::with_sloth(genesis_piece, default_sloth())
+    }
+}
+
+impl<
+        Sloth: SlothBackend<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>,
+        const PRIME_SIZE_BYTES: usize,
+        const PIECE_SIZE_BYTES: usize,
+    > Spartan<Sloth, PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>
+{
+    /// Encoding key hash is a part of expanded IV, so prime must be at least as big, checked at
+    /// compile time when constructor is instantiated
+    const VALID_PRIME_SIZE: () = assert!(
+        PRIME_SIZE_BYTES >= 32,
+        "Prime must be at least 256 bits, such that encoding key hash fits into expanded IV"
+    );
+
+    /// New instance with explicitly provided SLOTH implementation, uses one round by default
+    pub fn with_sloth(genesis_piece: Piece<PIECE_SIZE_BYTES>, sloth: Sloth) -> Self {
+        Self::with_sloth_and_rounds(genesis_piece, sloth, 1)
+    }
+
+    /// New instance with explicitly provided SLOTH implementation and default number of rounds,
+    /// see [`Spartan::rounds()`]
+    pub(crate) fn with_sloth_and_rounds(
+        genesis_piece: Piece<PIECE_SIZE_BYTES>,
+        sloth: Sloth,
+        rounds: usize,
+    ) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_PRIME_SIZE;
+
         Self {
+            prepared_genesis_piece: sloth.prepare_piece(&genesis_piece),
             genesis_piece,
+            prime: sloth.prime(),
             sloth,
+            iv_derivation: IvDerivation::default(),
+            tweaks: Tweaks::default(),
+            rounds,
         }
     }
-}
 
-impl Spartan {
-    /// New instance with 256-bit prime and 4096-byte genesis piece size
-    pub fn new(genesis_piece: [u8; 4096]) -> Self {
-        Self::new_internal(genesis_piece)
+    /// Use provided IV derivation instead of [`IvDerivation::LegacyXor`]
+    pub fn with_iv_derivation(mut self, iv_derivation: IvDerivation) -> Self {
+        self.iv_derivation = iv_derivation;
+        self
+    }
+
+    /// IV derivation used for encoding and validation
+    pub fn iv_derivation(&self) -> IvDerivation {
+        self.iv_derivation
+    }
+
+    /// Use provided tweaks instead of [`Tweaks::None`]
+    pub fn with_tweaks(mut self, tweaks: Tweaks) -> Self {
+        self.tweaks = tweaks;
+        self
+    }
+
+    /// Tweaks used for encoding and validation
+    pub fn tweaks(&self) -> Tweaks {
+        self.tweaks
+    }
+
+    fn expand_iv(
+        &self,
+        encoding_key_hash: EncodingKeyHash,
+        nonce: u64,
+    ) -> ExpandedIv<PRIME_SIZE_BYTES> {
+        self.iv_derivation
+            .derive(&self.prime, encoding_key_hash, nonce)
+    }
+
+    /// Default number of rounds, configured with [`SpartanConfig::rounds()`], used by
+    /// [`Spartan::encode_default_rounds()`] and [`Spartan::is_valid_default_rounds()`]
+    pub fn rounds(&self) -> usize {
+        self.rounds
+    }
+
+    /// Encodes genesis piece, prepared genesis piece is only used without tweaks
+    fn encode_genesis_piece(
+        &self,
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        encoding_key_hash: EncodingKeyHash,
+        rounds: usize,
+        tweaks: Tweaks,
+        scratch: &mut Sloth::Scratch,
+    ) -> Result<Encoding<PIECE_SIZE_BYTES>, EncodeError> {
+        let mut encoding = Encoding::from([0u8; PIECE_SIZE_BYTES]);
+        match tweaks {
+            Tweaks::None => {
+                self.sloth.encode_prepared(
+                    &self.prepared_genesis_piece,
+                    &mut encoding,
+                    expanded_iv,
+                    rounds,
+                    scratch,
+                )?;
+            }
+            tweaks => {
+                encode_piece_into(
+                    &self.sloth,
+                    self.genesis_piece.as_ref(),
+                    encoding.as_mut(),
+                    expanded_iv,
+                    encoding_key_hash,
+                    rounds,
+                    tweaks,
+                    scratch,
+                )?;
+            }
+        }
+
+        Ok(encoding)
     }
-}
 
-impl Spartan {
     /// Create an encoding based on genesis piece using provided encoding key hash, nonce and
     /// desired number of rounds
-    pub fn encode(&self, encoding_key_hash: [u8; 32], nonce: u64, rounds: usize) -> [u8; 4096] {
-        let mut expanded_iv = encoding_key_hash;
-        for (i, &byte) in nonce.to_le_bytes().iter().rev().enumerate() {
-            expanded_iv[32 - i - 1] ^= byte;
-        }
+    ///
+    /// Returns an error if `rounds` is zero.
+    pub fn encode(
+        &self,
+        encoding_key_hash: EncodingKeyHash,
+        nonce: u64,
+        rounds: usize,
+    ) -> Result<Encoding<PIECE_SIZE_BYTES>, EncodeError> {
+        self.encode_with_scratch(
+            encoding_key_hash,
+            nonce,
+            rounds,
+            &mut Sloth::Scratch::default(),
+        )
+    }
 
-        let mut encoding = self.genesis_piece;
-        // TODO: Better error handling
-        self.sloth
-            .encode(&mut encoding, expanded_iv, rounds)
-            .unwrap();
+    /// Same as [`Spartan::encode()`], but reuses provided scratch, such that once scratch is
+    /// allocated, encoding doesn't allocate on the heap
+    pub fn encode_with_scratch(
+        &self,
+        encoding_key_hash: EncodingKeyHash,
+        nonce: u64,
+        rounds: usize,
+        scratch: &mut Sloth::Scratch,
+    ) -> Result<Encoding<PIECE_SIZE_BYTES>, EncodeError> {
+        self.encode_genesis_piece(
+            self.expand_iv(encoding_key_hash, nonce),
+            encoding_key_hash,
+            rounds,
+            self.tweaks,
+            scratch,
+        )
+    }
+
+    /// Same as [`Spartan::encode()`], but uses default number of rounds, see [`Spartan::rounds()`]
+    pub fn encode_default_rounds(
+        &self,
+        encoding_key_hash: EncodingKeyHash,
+        nonce: u64,
+    ) -> Result<Encoding<PIECE_SIZE_BYTES>, EncodeError> {
+        self.encode(encoding_key_hash, nonce, self.rounds)
+    }
+
+    /// Same as [`Spartan::encode_with_scratch()`], but encodes directly into provided buffer of
+    /// piece size instead of returning it (for instance, into `Vec<u8>` allocated on the heap)
+    ///
+    /// Returns an error if `rounds` is zero or `encoding` has wrong length.
+    pub fn encode_into(
+        &self,
+        encoding_key_hash: EncodingKeyHash,
+        nonce: u64,
+        rounds: usize,
+        encoding: &mut [u8],
+        scratch: &mut Sloth::Scratch,
+    ) -> Result<(), EncodeError> {
+        encode_piece_into(
+            &self.sloth,
+            self.genesis_piece.as_ref(),
+            encoding,
+            self.expand_iv(encoding_key_hash, nonce),
+            encoding_key_hash,
+            rounds,
+            self.tweaks,
+            scratch,
+        )
+    }
 
-        encoding
+    /// Create encodings for multiple nonces (for instance, a range or a slice of nonces) in
+    /// parallel, encodings are written into `encodings` in the same order as nonces
+    ///
+    /// Returns an error if `rounds` is zero or number of nonces doesn't match number of encodings.
+    #[cfg(feature = "parallel")]
+    pub fn encode_batch<Nonces>(
+        &self,
+        encoding_key_hash: EncodingKeyHash,
+        nonces: Nonces,
+        rounds: usize,
+        encodings: &mut [Encoding<PIECE_SIZE_BYTES>],
+        options: ParallelOptions<'_>,
+    ) -> Result<(), EncodeError>
+    where
+        Nonces: IntoIterator<Item = u64>,
+        Sloth: Sync,
+    {
+        let mut nonces = nonces.into_iter();
+        let expanded_ivs = nonces
+            .by_ref()
+            .take(encodings.len())
+            .map(|nonce| self.expand_iv(encoding_key_hash, nonce))
+            .collect::<Vec<_>>();
+        if expanded_ivs.len() != encodings.len() || nonces.next().is_some() {
+            return Err(EncodeError::LengthMismatch);
+        }
+
+        encodings.fill(Encoding::from(*self.genesis_piece.as_bytes()));
+        match self.tweaks {
+            Tweaks::None => self.sloth.encode_many(
+                Encoding::slice_as_bytes_mut(encodings),
+                &expanded_ivs,
+                rounds,
+                options,
+            ),
+            tweaks => spartan_sloth::parallel::encode_many_tweaked(
+                &self.sloth,
+                Encoding::slice_as_bytes_mut(encodings),
+                &expanded_ivs,
+                rounds,
+                &|layer, piece| tweaks.apply::<PRIME_SIZE_BYTES>(encoding_key_hash, layer, piece),
+                options,
+            ),
+        }
     }
 
     /// Check if previously created encoding is valid
+    ///
+    /// Returns an error if `rounds` is zero.
     pub fn is_valid(
         &self,
-        mut encoding: [u8; 4096],
-        encoding_key_hash: [u8; 32],
+        encoding: Encoding<PIECE_SIZE_BYTES>,
+        encoding_key_hash: EncodingKeyHash,
         nonce: u64,
         rounds: usize,
-    ) -> bool {
-        let mut expanded_iv = encoding_key_hash;
-        for (i, &byte) in nonce.to_le_bytes().iter().rev().enumerate() {
-            expanded_iv[32 - i - 1] ^= byte;
+    ) -> Result<bool, DecodeError> {
+        self.is_valid_with_scratch(
+            encoding,
+            encoding_key_hash,
+            nonce,
+            rounds,
+            &mut Sloth::Scratch::default(),
+        )
+    }
+
+    /// Same as [`Spartan::is_valid()`], but uses default number of rounds, see [`Spartan::rounds()`]
+    pub fn is_valid_default_rounds(
+        &self,
+        encoding: Encoding<PIECE_SIZE_BYTES>,
+        encoding_key_hash: EncodingKeyHash,
+        nonce: u64,
+    ) -> Result<bool, DecodeError> {
+        self.is_valid(encoding, encoding_key_hash, nonce, self.rounds)
+    }
+
+    /// Same as [`Spartan::is_valid()`], but reuses provided scratch
+    pub fn is_valid_with_scratch(
+        &self,
+        mut encoding: Encoding<PIECE_SIZE_BYTES>,
+        encoding_key_hash: EncodingKeyHash,
+        nonce: u64,
+        rounds: usize,
+        scratch: &mut Sloth::Scratch,
+    ) -> Result<bool, DecodeError> {
+        decode_piece(
+            &self.sloth,
+            encoding.as_bytes_mut(),
+            self.expand_iv(encoding_key_hash, nonce),
+            encoding_key_hash,
+            rounds,
+            self.tweaks,
+            scratch,
+        )?;
+
+        Ok(encoding.as_bytes() == self.genesis_piece.as_bytes())
+    }
+
+    /// Same as [`Spartan::is_valid_with_scratch()`], but takes encoding by reference (for
+    /// instance, from `Vec<u8>` allocated on the heap) and decodes it into `decoding` buffer of
+    /// piece size, which can be reused between calls
+    ///
+    /// Returns an error if `rounds` is zero or `encoding` or `decoding` has wrong length.
+    pub fn is_valid_slice(
+        &self,
+        encoding: &[u8],
+        encoding_key_hash: EncodingKeyHash,
+        nonce: u64,
+        rounds: usize,
+        decoding: &mut [u8],
+        scratch: &mut Sloth::Scratch,
+    ) -> Result<bool, DecodeError> {
+        if encoding.len() != PIECE_SIZE_BYTES {
+            return Err(DecodeError::InvalidLength);
         }
 
-        self.sloth.decode(&mut encoding, expanded_iv, rounds);
+        decode_piece_into(
+            &self.sloth,
+            encoding,
+            decoding,
+            self.expand_iv(encoding_key_hash, nonce),
+            encoding_key_hash,
+            rounds,
+            self.tweaks,
+            scratch,
+        )?;
+
+        Ok(decoding == self.genesis_piece.as_ref())
+    }
+
+    /// Check many previously created encodings, each item is `(encoding, encoding_key_hash, nonce)`,
+    /// returns validity of each item in the same order
+    ///
+    /// Without tweaks encodings are decoded [`SlothBackend::lanes()`] at a time with
+    /// [`SlothBackend::decode_lanes()`], such that squarings of several encodings are interleaved.
+    /// Scratch is shared between all items, use [`Spartan::are_all_valid()`] if only the fact that
+    /// all items are valid matters. Returns an error if `rounds` is zero.
+    #[cfg(feature = "std")]
+    pub fn is_valid_batch(
+        &self,
+        items: &[(Encoding<PIECE_SIZE_BYTES>, EncodingKeyHash, u64)],
+        rounds: usize,
+    ) -> Result<Vec<bool>, DecodeError> {
+        let mut results = Vec::with_capacity(items.len());
+        self.check_batch(items, rounds, |valid| {
+            results.push(valid);
+            true
+        })?;
 
-        encoding == self.genesis_piece
+        Ok(results)
+    }
+
+    /// Same as [`Spartan::is_valid_batch()`], but only checks whether all items are valid and
+    /// stops at the first chunk with an invalid one
+    #[cfg(feature = "std")]
+    pub fn are_all_valid(
+        &self,
+        items: &[(Encoding<PIECE_SIZE_BYTES>, EncodingKeyHash, u64)],
+        rounds: usize,
+    ) -> Result<bool, DecodeError> {
+        let mut all_valid = true;
+        self.check_batch(items, rounds, |valid| {
+            all_valid = valid;
+            valid
+        })?;
+
+        Ok(all_valid)
+    }
+
+    /// Checks items in order, `on_result` is called with validity of each item and returns `false`
+    /// to stop checking remaining items
+    #[cfg(feature = "std")]
+    fn check_batch(
+        &self,
+        items: &[(Encoding<PIECE_SIZE_BYTES>, EncodingKeyHash, u64)],
+        rounds: usize,
+        mut on_result: impl FnMut(bool) -> bool,
+    ) -> Result<(), DecodeError> {
+        if rounds == 0 {
+            return Err(DecodeError::ZeroLayers);
+        }
+
+        let mut scratch = Sloth::Scratch::default();
+        if self.tweaks != Tweaks::None {
+            // Tweaks are derived from encoding key hash of each item, so encodings are decoded one
+            // by one
+            for &(encoding, encoding_key_hash, nonce) in items {
+                let valid = self.is_valid_with_scratch(
+                    encoding,
+                    encoding_key_hash,
+                    nonce,
+                    rounds,
+                    &mut scratch,
+                )?;
+                if !on_result(valid) {
+                    break;
+                }
+            }
+            return Ok(());
+        }
+
+        let lanes = self.sloth.lanes().max(1);
+        let mut encodings = Vec::with_capacity(lanes);
+        let mut expanded_ivs = Vec::with_capacity(lanes);
+        for chunk in items.chunks(lanes) {
+            encodings.clear();
+            expanded_ivs.clear();
+            for &(encoding, encoding_key_hash, nonce) in chunk {
+                encodings.push(encoding);
+                expanded_ivs.push(self.expand_iv(encoding_key_hash, nonce));
+            }
+
+            self.sloth.decode_lanes(
+                Encoding::slice_as_bytes_mut(&mut encodings),
+                &expanded_ivs,
+                rounds,
+                &mut scratch,
+            )?;
+
+            for encoding in &encodings {
+                if !on_result(encoding.as_bytes() == self.genesis_piece.as_bytes()) {
+                    return Ok(());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Encodes `piece` into `encoding` of the same length, `tweaks` are derived from encoding key
+/// hash
+#[allow(clippy::too_many_arguments)]
+fn encode_piece_into<
+    Sloth: SlothBackend<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>,
+    const PRIME_SIZE_BYTES: usize,
+    const PIECE_SIZE_BYTES: usize,
+>(
+    sloth: &Sloth,
+    piece: &[u8],
+    encoding: &mut [u8],
+    expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+    encoding_key_hash: EncodingKeyHash,
+    rounds: usize,
+    tweaks: Tweaks,
+    scratch: &mut Sloth::Scratch,
+) -> Result<(), EncodeError> {
+    match tweaks {
+        Tweaks::None => sloth.encode_into(piece, encoding, expanded_iv, rounds, scratch),
+        tweaks => {
+            if piece.len() != encoding.len() {
+                return Err(EncodeError::InvalidLength);
+            }
+
+            encoding.copy_from_slice(piece);
+            sloth.encode_tweaked(
+                encoding,
+                expanded_iv,
+                rounds,
+                &|layer, piece| tweaks.apply::<PRIME_SIZE_BYTES>(encoding_key_hash, layer, piece),
+                scratch,
+            )
+        }
+    }
+}
+
+/// Decodes encoding in place, `tweaks` are derived from encoding key hash
+fn decode_piece<
+    Sloth: SlothBackend<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>,
+    const PRIME_SIZE_BYTES: usize,
+    const PIECE_SIZE_BYTES: usize,
+>(
+    sloth: &Sloth,
+    encoding: &mut [u8],
+    expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+    encoding_key_hash: EncodingKeyHash,
+    rounds: usize,
+    tweaks: Tweaks,
+    scratch: &mut Sloth::Scratch,
+) -> Result<(), DecodeError> {
+    match tweaks {
+        Tweaks::None => sloth.decode_slice(encoding, expanded_iv, rounds, scratch),
+        tweaks => sloth.decode_tweaked(
+            encoding,
+            expanded_iv,
+            rounds,
+            &|layer, piece| tweaks.apply::<PRIME_SIZE_BYTES>(encoding_key_hash, layer, piece),
+            scratch,
+        ),
+    }
+}
+
+/// Decodes `encoding` into `piece` of the same length, `tweaks` are derived from encoding key hash
+#[allow(clippy::too_many_arguments)]
+fn decode_piece_into<
+    Sloth: SlothBackend<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>,
+    const PRIME_SIZE_BYTES: usize,
+    const PIECE_SIZE_BYTES: usize,
+>(
+    sloth: &Sloth,
+    encoding: &[u8],
+    piece: &mut [u8],
+    expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+    encoding_key_hash: EncodingKeyHash,
+    rounds: usize,
+    tweaks: Tweaks,
+    scratch: &mut Sloth::Scratch,
+) -> Result<(), DecodeError> {
+    match tweaks {
+        Tweaks::None => sloth.decode_into(encoding, piece, expanded_iv, rounds, scratch),
+        tweaks => {
+            if encoding.len() != piece.len() {
+                return Err(DecodeError::InvalidLength);
+            }
+
+            piece.copy_from_slice(encoding);
+            decode_piece(
+                sloth,
+                piece,
+                expanded_iv,
+                encoding_key_hash,
+                rounds,
+                tweaks,
+                scratch,
+            )
+        }
     }
 }
 
@@ -81,14 +644,305 @@ mod tests {
 
     #[test]
     fn test_random_piece() {
-        let genesis_piece = random_bytes();
-        let encoding_key = random_bytes();
+        let genesis_piece = Piece::from(random_bytes());
+        let encoding_key = EncodingKeyHash::from(random_bytes());
         let nonce = rand::random();
 
         let spartan = Spartan::new(genesis_piece);
-        let encoding = spartan.encode(encoding_key, nonce, 1);
+        let encoding = spartan.encode(encoding_key, nonce, 1).unwrap();
+
+        assert!(spartan.is_valid(encoding, encoding_key, nonce, 1).unwrap());
+    }
+
+    #[test]
+    // Scratch is `()` for implementations that don't need it
+    #[allow(clippy::let_unit_value)]
+    fn test_scratch_reuse() {
+        let spartan = Spartan::new(Piece::from(random_bytes()));
+        let encoding_key = EncodingKeyHash::from(random_bytes());
+
+        let mut scratch = Default::default();
+        for nonce in 0..3 {
+            let encoding = spartan
+                .encode_with_scratch(encoding_key, nonce, 2, &mut scratch)
+                .unwrap();
+            assert_eq!(encoding, spartan.encode(encoding_key, nonce, 2).unwrap());
+            assert!(spartan
+                .is_valid_with_scratch(encoding, encoding_key, nonce, 2, &mut scratch)
+                .unwrap());
+        }
+    }
+
+    #[test]
+    // Scratch is `()` for implementations that don't need it
+    #[allow(clippy::let_unit_value)]
+    fn test_slices() {
+        let spartan = Spartan::new(Piece::from(random_bytes()));
+        let encoding_key = EncodingKeyHash::from(random_bytes());
+        let mut scratch = Default::default();
+
+        let mut encoding = vec![0u8; 4096];
+        let mut decoding = vec![0u8; 4096];
+        spartan
+            .encode_into(encoding_key, 3, 2, &mut encoding, &mut scratch)
+            .unwrap();
+        assert_eq!(
+            encoding,
+            spartan.encode(encoding_key, 3, 2).unwrap().as_ref()
+        );
+        assert!(spartan
+            .is_valid_slice(&encoding, encoding_key, 3, 2, &mut decoding, &mut scratch)
+            .unwrap());
+        assert!(!spartan
+            .is_valid_slice(&encoding, encoding_key, 4, 2, &mut decoding, &mut scratch)
+            .unwrap());
+
+        assert_eq!(
+            spartan.encode_into(encoding_key, 3, 2, &mut encoding[1..], &mut scratch),
+            Err(EncodeError::InvalidLength)
+        );
+        assert_eq!(
+            spartan.is_valid_slice(
+                &encoding[32..],
+                encoding_key,
+                3,
+                2,
+                &mut decoding,
+                &mut scratch
+            ),
+            Err(DecodeError::InvalidLength)
+        );
+        assert_eq!(
+            spartan.is_valid_slice(
+                &encoding,
+                encoding_key,
+                3,
+                2,
+                &mut decoding[32..],
+                &mut scratch
+            ),
+            Err(DecodeError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn test_hashed_iv_derivation() {
+        let genesis_piece = Piece::from(random_bytes());
+        let encoding_key = EncodingKeyHash::from(random_bytes());
+        let domain = IvDomain {
+            network_id: random_bytes(),
+            piece_index: rand::random(),
+        };
+
+        let legacy = Spartan::new(genesis_piece);
+        let spartan = legacy
+            .clone()
+            .with_iv_derivation(IvDerivation::Hashed(domain));
+        assert_eq!(spartan.iv_derivation(), IvDerivation::Hashed(domain));
 
-        assert!(spartan.is_valid(encoding, encoding_key, nonce, 1));
+        let encoding = spartan.encode(encoding_key, 7, 1).unwrap();
+        assert_ne!(encoding, legacy.encode(encoding_key, 7, 1).unwrap());
+        assert!(spartan.is_valid(encoding, encoding_key, 7, 1).unwrap());
+        assert!(!legacy.is_valid(encoding, encoding_key, 7, 1).unwrap());
+
+        let other_network = legacy.with_iv_derivation(IvDerivation::Hashed(IvDomain {
+            network_id: [0; 32],
+            ..domain
+        }));
+        assert!(!other_network
+            .is_valid(encoding, encoding_key, 7, 1)
+            .unwrap());
+    }
+
+    #[test]
+    fn test_zero_rounds() {
+        let spartan = Spartan::new(Piece::from(random_bytes()));
+        let encoding_key = EncodingKeyHash::from(random_bytes());
+
+        assert_eq!(
+            spartan.encode(encoding_key, 0, 0),
+            Err(EncodeError::ZeroLayers)
+        );
+        assert_eq!(
+            spartan.is_valid(Encoding::from(random_bytes()), encoding_key, 0, 0),
+            Err(DecodeError::ZeroLayers)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_is_valid_batch() {
+        let genesis_piece = Piece::from(random_bytes());
+        let encoding_key = EncodingKeyHash::from(random_bytes());
+
+        for &tweaks in &[Tweaks::None, Tweaks::PerLayer] {
+            let spartan = Spartan::new(genesis_piece).with_tweaks(tweaks);
+
+            // More items than lanes of any implementation, with a remainder
+            let mut items = (0..11)
+                .map(|nonce| {
+                    let encoding = spartan.encode(encoding_key, nonce, 2).unwrap();
+                    (encoding, encoding_key, nonce)
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(spartan.is_valid_batch(&items, 2).unwrap(), vec![true; 11]);
+            assert!(spartan.are_all_valid(&items, 2).unwrap());
+            assert!(spartan.are_all_valid(&[], 2).unwrap());
+
+            // Wrong nonce and corrupted encoding
+            items[1].2 = 50;
+            items[9].0.as_mut()[0] ^= 1;
+            let mut expected = vec![true; 11];
+            expected[1] = false;
+            expected[9] = false;
+            assert_eq!(spartan.is_valid_batch(&items, 2).unwrap(), expected);
+            assert!(!spartan.are_all_valid(&items, 2).unwrap());
+            assert!(!spartan.are_all_valid(&items[9..], 2).unwrap());
+
+            assert_eq!(
+                spartan.is_valid_batch(&items, 0),
+                Err(DecodeError::ZeroLayers)
+            );
+            assert_eq!(spartan.are_all_valid(&[], 0), Err(DecodeError::ZeroLayers));
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_encode_batch() {
+        let spartan = Spartan::new(Piece::from(random_bytes()));
+        let encoding_key = EncodingKeyHash::from(random_bytes());
+
+        let thread_pool = ThreadPoolBuilder::new().num_threads(2).build().unwrap();
+        let options = ParallelOptions {
+            thread_pool: Some(&thread_pool),
+            chunk_size: 2,
+        };
+
+        let mut encodings = [Encoding::from([0u8; 4096]); 3];
+        spartan
+            .encode_batch(encoding_key, 5..8, 1, &mut encodings, options)
+            .unwrap();
+        for (nonce, encoding) in (5..8).zip(encodings.iter()) {
+            assert_eq!(spartan.encode(encoding_key, nonce, 1).unwrap(), *encoding);
+        }
+
+        let nonces = [9, 1, u64::MAX];
+        spartan
+            .encode_batch(
+                encoding_key,
+                nonces.iter().copied(),
+                1,
+                &mut encodings,
+                ParallelOptions::default(),
+            )
+            .unwrap();
+        for (&nonce, encoding) in nonces.iter().zip(encodings.iter()) {
+            assert!(spartan.is_valid(*encoding, encoding_key, nonce, 1).unwrap());
+        }
+
+        assert_eq!(
+            spartan.encode_batch(encoding_key, 0..2, 1, &mut encodings, options),
+            Err(EncodeError::LengthMismatch)
+        );
+        assert_eq!(
+            spartan.encode_batch(encoding_key, 0..4, 1, &mut encodings, options),
+            Err(EncodeError::LengthMismatch)
+        );
+    }
+
+    #[test]
+    fn test_tweaks() {
+        let spartan = Spartan::new(Piece::from(random_bytes()));
+        let encoding_key = EncodingKeyHash::from(random_bytes());
+        // Bounded, such that range of nonces below doesn't overflow
+        let nonce = u64::from(rand::random::<u32>());
+        let encoding = spartan.encode(encoding_key, nonce, 2).unwrap();
+
+        for &tweaks in &[Tweaks::PerLayer, Tweaks::PerBlock] {
+            let tweaked_spartan = spartan.clone().with_tweaks(tweaks);
+            assert_eq!(tweaked_spartan.tweaks(), tweaks);
+
+            let tweaked_encoding = tweaked_spartan.encode(encoding_key, nonce, 2).unwrap();
+            assert_ne!(tweaked_encoding, encoding);
+            assert!(tweaked_spartan
+                .is_valid(tweaked_encoding, encoding_key, nonce, 2)
+                .unwrap());
+            assert!(tweaked_spartan
+                .is_valid_slice(
+                    tweaked_encoding.as_ref(),
+                    encoding_key,
+                    nonce,
+                    2,
+                    &mut [0u8; 4096],
+                    &mut Default::default()
+                )
+                .unwrap());
+            assert!(!spartan
+                .is_valid(tweaked_encoding, encoding_key, nonce, 2)
+                .unwrap());
+            assert!(!tweaked_spartan
+                .is_valid(encoding, encoding_key, nonce, 2)
+                .unwrap());
+
+            #[cfg(feature = "parallel")]
+            {
+                let mut encodings = [Encoding::from([0u8; 4096]); 2];
+                tweaked_spartan
+                    .encode_batch(
+                        encoding_key,
+                        nonce..nonce + 2,
+                        2,
+                        &mut encodings,
+                        ParallelOptions::default(),
+                    )
+                    .unwrap();
+                assert_eq!(encodings[0], tweaked_encoding);
+            }
+        }
+    }
+
+    #[test]
+    fn test_backends_match() {
+        let genesis_piece = Piece::from(random_bytes());
+        let encoding_key = EncodingKeyHash::from(random_bytes());
+        let nonce = rand::random();
+
+        let encoding = Spartan::new(genesis_piece)
+            .encode(encoding_key, nonce, 2)
+            .unwrap();
+
+        #[cfg(feature = "software")]
+        {
+            let sloth = spartan_sloth::software::Sloth::<32, 4096>::try_with_prime_bytes(
+                spartan_sloth::largest_known_prime().unwrap(),
+            )
+            .unwrap();
+            let spartan = Spartan::with_sloth(genesis_piece, sloth);
+            assert_eq!(spartan.encode(encoding_key, nonce, 2).unwrap(), encoding);
+            assert!(spartan.is_valid(encoding, encoding_key, nonce, 2).unwrap());
+        }
+        #[cfg(feature = "portable")]
+        {
+            let sloth = spartan_sloth::portable::Sloth::<32, 4096>::with_prime(
+                spartan_sloth::largest_known_prime().unwrap(),
+            );
+            let spartan = Spartan::with_sloth(genesis_piece, sloth);
+            assert_eq!(spartan.encode(encoding_key, nonce, 2).unwrap(), encoding);
+            assert!(spartan.is_valid(encoding, encoding_key, nonce, 2).unwrap());
+        }
+        #[cfg(all(feature = "x86_64", target_arch = "x86_64"))]
+        {
+            let spartan = Spartan::with_sloth(genesis_piece, spartan_sloth::x86_64::Sloth::new());
+            assert_eq!(spartan.encode(encoding_key, nonce, 2).unwrap(), encoding);
+            assert!(spartan.is_valid(encoding, encoding_key, nonce, 2).unwrap());
+        }
+        #[cfg(feature = "auto")]
+        {
+            let spartan = Spartan::with_sloth(genesis_piece, spartan_sloth::auto::Sloth::new());
+            assert_eq!(spartan.encode(encoding_key, nonce, 2).unwrap(), encoding);
+            assert!(spartan.is_valid(encoding, encoding_key, nonce, 2).unwrap());
+        }
     }
 
     #[test]
@@ -388,15 +1242,15 @@ mod tests {
             0x0c, 0x5d, 0x0f, 0x73, 0x39, 0xa6, 0x34, 0xa1, 0x2e, 0xc2, 0x06, 0xd9, 0x99, 0x61,
             0x74, 0xb4, 0x78, 0x17, 0x68, 0xaf, 0xaa, 0x21,
         ];
-        let genesis_piece = [5u8; 4096];
-        let encoding_key = [3u8; 32];
+        let genesis_piece = Piece::from([5u8; 4096]);
+        let encoding_key = EncodingKeyHash::from([3u8; 32]);
         let nonce = 1;
 
         let spartan = Spartan::new(genesis_piece);
-        let encoding = spartan.encode(encoding_key, nonce, 1);
+        let encoding = spartan.encode(encoding_key, nonce, 1).unwrap();
 
-        assert_eq!(encoding, correct_encoding);
+        assert_eq!(encoding, Encoding::from(correct_encoding));
 
-        assert!(spartan.is_valid(encoding, encoding_key, nonce, 1));
+        assert!(spartan.is_valid(encoding, encoding_key, nonce, 1).unwrap());
     }
 }
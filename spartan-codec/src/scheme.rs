@@ -0,0 +1,463 @@
+//! Versioned encoding schemes, such that encodings created with older versions stay verifiable
+//! after protocol upgrades
+
+use crate::{
+    decode_piece, BlockMapping, Chaining, Encoding, EncodingKeyHash, IvDerivation, IvDomain,
+    Spartan, Tweaks,
+};
+use core::convert::TryFrom;
+use core::fmt;
+use spartan_sloth::SlothBackend;
+
+/// Version of encoding scheme, pins every parameter that affects encoding: prime, piece size, IV
+/// derivation, chaining, mapping of blocks outside of field, tweaks and number of rounds
+///
+/// Parameters of existing versions never change, known-answer tests make sure of that. Version is
+/// stored as a single byte, see `u8` conversions.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum SchemeVersion {
+    /// 256-bit prime `2^256 - 189`, 4096-byte pieces, one round, [`Chaining::Cbc`],
+    /// [`Tweaks::None`], [`IvDerivation::LegacyXor`]
+    V0,
+    /// Same as [`SchemeVersion::V0`], but with [`IvDerivation::Hashed`]
+    V1,
+    /// Same as [`SchemeVersion::V1`], but with two rounds of [`Chaining::Alternating`], such that
+    /// every block of encoding depends on every block of the piece
+    ///
+    /// Requires SLOTH implementation with alternating chaining, see
+    /// [`spartan_sloth::alternating::Sloth`].
+    V2,
+    /// Same as [`SchemeVersion::V2`], but with [`Tweaks::PerBlock`], such that encoding key hash
+    /// affects every block of every layer
+    V3,
+}
+
+impl SchemeVersion {
+    /// Version new encodings should be created with
+    ///
+    /// Versions with [`Chaining::Alternating`] are opt-in, since they require a different SLOTH
+    /// implementation than [`Spartan::new()`] uses.
+    pub const LATEST: Self = SchemeVersion::V1;
+
+    /// Prime as little-endian bytes
+    pub fn prime(self) -> [u8; 32] {
+        // `2^256 - 189`
+        spartan_sloth::largest_known_prime().expect("256-bit prime is known; qed")
+    }
+
+    /// Number of rounds
+    pub fn rounds(self) -> usize {
+        match self {
+            SchemeVersion::V0 | SchemeVersion::V1 => 1,
+            // Direction only alternates starting with the second layer
+            SchemeVersion::V2 | SchemeVersion::V3 => 2,
+        }
+    }
+
+    /// Chaining of blocks, SLOTH implementation must use the same one
+    pub fn chaining(self) -> Chaining {
+        match self {
+            SchemeVersion::V0 | SchemeVersion::V1 => Chaining::Cbc,
+            SchemeVersion::V2 | SchemeVersion::V3 => Chaining::Alternating,
+        }
+    }
+
+    /// Mapping of blocks outside of field, SLOTH implementation must use the same one
+    ///
+    /// All versions use [`BlockMapping::Swap`], other mappings need a new version.
+    pub fn block_mapping(self) -> BlockMapping {
+        match self {
+            SchemeVersion::V0 | SchemeVersion::V1 | SchemeVersion::V2 | SchemeVersion::V3 => {
+                BlockMapping::Swap
+            }
+        }
+    }
+
+    /// Tweaks derived from encoding key hash
+    pub fn tweaks(self) -> Tweaks {
+        match self {
+            SchemeVersion::V0 | SchemeVersion::V1 | SchemeVersion::V2 => Tweaks::None,
+            SchemeVersion::V3 => Tweaks::PerBlock,
+        }
+    }
+
+    /// IV derivation for encodings of provided domain, which is ignored by versions that don't
+    /// separate domains
+    pub fn iv_derivation(self, domain: IvDomain) -> IvDerivation {
+        match self {
+            SchemeVersion::V0 => IvDerivation::LegacyXor,
+            SchemeVersion::V1 | SchemeVersion::V2 | SchemeVersion::V3 => {
+                IvDerivation::Hashed(domain)
+            }
+        }
+    }
+}
+
+impl From<SchemeVersion> for u8 {
+    fn from(version: SchemeVersion) -> Self {
+        match version {
+            SchemeVersion::V0 => 0,
+            SchemeVersion::V1 => 1,
+            SchemeVersion::V2 => 2,
+            SchemeVersion::V3 => 3,
+        }
+    }
+}
+
+impl TryFrom<u8> for SchemeVersion {
+    type Error = SchemeError;
+
+    fn try_from(version: u8) -> Result<Self, Self::Error> {
+        match version {
+            0 => Ok(SchemeVersion::V0),
+            1 => Ok(SchemeVersion::V1),
+            2 => Ok(SchemeVersion::V2),
+            3 => Ok(SchemeVersion::V3),
+            _ => Err(SchemeError::UnknownVersion),
+        }
+    }
+}
+
+/// Errors that can happen when encoding or validating with a specific [`SchemeVersion`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SchemeError {
+    /// Version is not known to this version of the crate
+    UnknownVersion,
+    /// Prime of SLOTH implementation doesn't match prime of scheme version
+    PrimeMismatch,
+    /// Chaining of SLOTH implementation doesn't match chaining of scheme version
+    ChainingMismatch,
+    /// Mapping of blocks outside of field of SLOTH implementation doesn't match the one of scheme
+    /// version
+    BlockMappingMismatch,
+}
+
+impl fmt::Display for SchemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemeError::UnknownVersion => write!(f, "Unknown scheme version"),
+            SchemeError::PrimeMismatch => write!(f, "Prime doesn't match scheme version"),
+            SchemeError::ChainingMismatch => write!(f, "Chaining doesn't match scheme version"),
+            SchemeError::BlockMappingMismatch => {
+                write!(f, "Block mapping doesn't match scheme version")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SchemeError {}
+
+impl<Sloth: SlothBackend<32, 4096>> Spartan<Sloth, 32, 4096> {
+    fn check_scheme(&self, version: SchemeVersion) -> Result<(), SchemeError> {
+        if self.prime != version.prime() {
+            return Err(SchemeError::PrimeMismatch);
+        }
+        if self.sloth.chaining() != version.chaining() {
+            return Err(SchemeError::ChainingMismatch);
+        }
+        if self.sloth.block_mapping() != version.block_mapping() {
+            return Err(SchemeError::BlockMappingMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// Create an encoding with parameters of provided scheme version, default number of rounds, IV
+    /// derivation and tweaks of this instance are ignored
+    ///
+    /// Returns an error if SLOTH implementation uses a different prime, chaining or block
+    /// mapping.
+    pub fn encode_with(
+        &self,
+        version: SchemeVersion,
+        domain: &IvDomain,
+        encoding_key_hash: EncodingKeyHash,
+        nonce: u64,
+    ) -> Result<Encoding, SchemeError> {
+        self.check_scheme(version)?;
+
+        let encoding = self
+            .encode_genesis_piece(
+                version
+                    .iv_derivation(*domain)
+                    .derive(&self.prime, encoding_key_hash, nonce),
+                encoding_key_hash,
+                version.rounds(),
+                version.tweaks(),
+                &mut Sloth::Scratch::default(),
+            )
+            .expect("Scheme version has non-zero number of rounds; qed");
+
+        Ok(encoding)
+    }
+
+    /// Check if encoding was created with provided scheme version, see [`Spartan::encode_with()`]
+    pub fn is_valid_with(
+        &self,
+        version: SchemeVersion,
+        domain: &IvDomain,
+        mut encoding: Encoding,
+        encoding_key_hash: EncodingKeyHash,
+        nonce: u64,
+    ) -> Result<bool, SchemeError> {
+        self.check_scheme(version)?;
+
+        decode_piece(
+            &self.sloth,
+            encoding.as_bytes_mut(),
+            version
+                .iv_derivation(*domain)
+                .derive(&self.prime, encoding_key_hash, nonce),
+            encoding_key_hash,
+            version.rounds(),
+            version.tweaks(),
+            &mut Sloth::Scratch::default(),
+        )
+        .expect("Scheme version has non-zero number of rounds; qed");
+
+        Ok(encoding.as_bytes() == self.genesis_piece.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DefaultSloth, Piece};
+    use sha2::{Digest, Sha256};
+    use spartan_sloth::alternating;
+
+    const VERSIONS: [SchemeVersion; 4] = [
+        SchemeVersion::V0,
+        SchemeVersion::V1,
+        SchemeVersion::V2,
+        SchemeVersion::V3,
+    ];
+
+    /// SHA-256 of encoding of genesis piece of `5`s with encoding key hash of `3`s and nonce `1`,
+    /// for domain with network id of `1`s and piece index `2`
+    ///
+    /// These values are frozen: if a test fails, encoding of existing version has changed, which
+    /// must never happen, add a new version instead.
+    const KNOWN_ANSWERS: [(SchemeVersion, &str); 4] = [
+        (
+            SchemeVersion::V0,
+            "797b811d6fe5ec053a76d8fb731edef562847533b5e6f47f146e32027243e30e",
+        ),
+        (
+            SchemeVersion::V1,
+            "23ede029809b9d24996585cd8127f742665f4c1dacd3651333944c8fa94ac572",
+        ),
+        (
+            SchemeVersion::V2,
+            "ab9e0c383a6236c490b180c2a45b684d036aa8baadfafb800f3ec2b4b0c124bb",
+        ),
+        (
+            SchemeVersion::V3,
+            "684d4e697e4a3f6a05743e18b863c1fec431840ff5fb2a309db0cad441032565",
+        ),
+    ];
+
+    fn domain() -> IvDomain {
+        IvDomain {
+            network_id: [1; 32],
+            piece_index: 2,
+        }
+    }
+
+    fn alternating_spartan() -> Spartan<alternating::Sloth<DefaultSloth>> {
+        Spartan::with_sloth(
+            Piece::from([5u8; 4096]),
+            alternating::Sloth::new(crate::default_sloth()),
+        )
+    }
+
+    /// Encodes genesis piece of `5`s with SLOTH implementation of the same chaining as `version`
+    fn encode(version: SchemeVersion, encoding_key_hash: EncodingKeyHash, nonce: u64) -> Encoding {
+        match version.chaining() {
+            Chaining::Cbc => Spartan::new(Piece::from([5u8; 4096])).encode_with(
+                version,
+                &domain(),
+                encoding_key_hash,
+                nonce,
+            ),
+            Chaining::Alternating => {
+                alternating_spartan().encode_with(version, &domain(), encoding_key_hash, nonce)
+            }
+        }
+        .unwrap()
+    }
+
+    /// Checks encoding of genesis piece of `5`s with SLOTH implementation of the same chaining as
+    /// `version`
+    fn is_valid(
+        version: SchemeVersion,
+        encoding: Encoding,
+        encoding_key_hash: EncodingKeyHash,
+        nonce: u64,
+    ) -> bool {
+        match version.chaining() {
+            Chaining::Cbc => Spartan::new(Piece::from([5u8; 4096])).is_valid_with(
+                version,
+                &domain(),
+                encoding,
+                encoding_key_hash,
+                nonce,
+            ),
+            Chaining::Alternating => alternating_spartan().is_valid_with(
+                version,
+                &domain(),
+                encoding,
+                encoding_key_hash,
+                nonce,
+            ),
+        }
+        .unwrap()
+    }
+
+    #[test]
+    fn test_known_answers() {
+        let encoding_key_hash = EncodingKeyHash::from([3u8; 32]);
+
+        for &(version, known_answer) in &KNOWN_ANSWERS {
+            let encoding = encode(version, encoding_key_hash, 1);
+            let hash = Sha256::digest(encoding.as_ref());
+            let hash = hash
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect::<String>();
+            assert_eq!(hash, known_answer, "{:?}", version);
+
+            assert!(is_valid(version, encoding, encoding_key_hash, 1));
+        }
+    }
+
+    #[test]
+    fn test_versions() {
+        let spartan = Spartan::new(Piece::from([5u8; 4096]));
+        let encoding_key_hash = EncodingKeyHash::from([3u8; 32]);
+
+        for &version in &VERSIONS {
+            assert_eq!(SchemeVersion::try_from(u8::from(version)), Ok(version));
+
+            let encoding = encode(version, encoding_key_hash, 1);
+            for &other_version in &VERSIONS {
+                assert_eq!(
+                    is_valid(other_version, encoding, encoding_key_hash, 1),
+                    version == other_version
+                );
+            }
+        }
+        assert_eq!(
+            SchemeVersion::try_from(u8::MAX),
+            Err(SchemeError::UnknownVersion)
+        );
+
+        // V0 is the same as default encoding
+        assert_eq!(
+            spartan
+                .encode_with(SchemeVersion::V0, &domain(), encoding_key_hash, 1)
+                .unwrap(),
+            spartan.encode(encoding_key_hash, 1, 1).unwrap()
+        );
+        // V2 is the same as two rounds of alternating chaining with hashed IV
+        assert_eq!(
+            encode(SchemeVersion::V2, encoding_key_hash, 1),
+            alternating_spartan()
+                .with_iv_derivation(IvDerivation::Hashed(domain()))
+                .encode(encoding_key_hash, 1, 2)
+                .unwrap()
+        );
+        // V3 additionally applies tweaks
+        assert_eq!(
+            encode(SchemeVersion::V3, encoding_key_hash, 1),
+            alternating_spartan()
+                .with_iv_derivation(IvDerivation::Hashed(domain()))
+                .with_tweaks(Tweaks::PerBlock)
+                .encode(encoding_key_hash, 1, 2)
+                .unwrap()
+        );
+    }
+
+    #[cfg(any(feature = "software", feature = "portable", feature = "auto"))]
+    #[test]
+    fn test_prime_mismatch() {
+        // `2^127 - 1` in 256-bit block
+        let mut prime = [0u8; 32];
+        prime[..16].copy_from_slice(&(u128::MAX >> 1).to_le_bytes());
+        let spartan = crate::SpartanConfig::<32, 4096>::new()
+            .prime(prime)
+            .build(Piece::from([5u8; 4096]))
+            .unwrap();
+        let encoding_key_hash = EncodingKeyHash::from([3u8; 32]);
+
+        assert_eq!(
+            spartan.encode_with(SchemeVersion::V0, &domain(), encoding_key_hash, 1),
+            Err(SchemeError::PrimeMismatch)
+        );
+        assert_eq!(
+            spartan.is_valid_with(
+                SchemeVersion::V1,
+                &domain(),
+                Encoding::from([0u8; 4096]),
+                encoding_key_hash,
+                1
+            ),
+            Err(SchemeError::PrimeMismatch)
+        );
+    }
+
+    #[cfg(feature = "portable")]
+    #[test]
+    fn test_chaining_mismatch() {
+        let sloth =
+            spartan_sloth::alternating::Sloth::new(
+                spartan_sloth::portable::Sloth::<32, 4096>::with_prime(SchemeVersion::V0.prime()),
+            );
+        let spartan = Spartan::with_sloth(Piece::from([5u8; 4096]), sloth);
+        let encoding_key_hash = EncodingKeyHash::from([3u8; 32]);
+
+        assert_eq!(
+            spartan.encode_with(SchemeVersion::V0, &domain(), encoding_key_hash, 1),
+            Err(SchemeError::ChainingMismatch)
+        );
+        assert_eq!(
+            Spartan::new(Piece::from([5u8; 4096])).encode_with(
+                SchemeVersion::V2,
+                &domain(),
+                encoding_key_hash,
+                1
+            ),
+            Err(SchemeError::ChainingMismatch)
+        );
+
+        let encoding = spartan.encode(encoding_key_hash, 1, 2).unwrap();
+        assert!(spartan.is_valid(encoding, encoding_key_hash, 1, 2).unwrap());
+    }
+
+    #[cfg(feature = "portable")]
+    #[test]
+    fn test_block_mapping_mismatch() {
+        let sloth =
+            spartan_sloth::portable::Sloth::<32, 4096>::with_prime(SchemeVersion::V1.prime())
+                .with_block_mapping(BlockMapping::Rotate);
+        let spartan = Spartan::with_sloth(Piece::from([5u8; 4096]), sloth);
+        let encoding_key_hash = EncodingKeyHash::from([3u8; 32]);
+
+        assert_eq!(
+            spartan.encode_with(SchemeVersion::V1, &domain(), encoding_key_hash, 1),
+            Err(SchemeError::BlockMappingMismatch)
+        );
+        assert_eq!(
+            spartan.is_valid_with(
+                SchemeVersion::V1,
+                &domain(),
+                Encoding::from([0u8; 4096]),
+                encoding_key_hash,
+                1
+            ),
+            Err(SchemeError::BlockMappingMismatch)
+        );
+    }
+}
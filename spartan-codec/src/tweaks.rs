@@ -0,0 +1,128 @@
+//! Tweaks derived from encoding key hash, XORed into blocks before square root permutation
+
+use crate::EncodingKeyHash;
+use sha2::{Digest, Sha256};
+
+/// Prefix of every tweak hash, such that tweak of a layer never reproduces expanded IV of the same
+/// encoding key hash
+const DOMAIN_SEPARATION_TAG: &[u8] = b"spartan-codec/tweak/v1";
+
+/// Tweaks derived from encoding key hash and XORed into blocks of every layer before square root
+/// permutation (see [`SlothBackend::encode_tweaked()`](crate::SlothBackend::encode_tweaked())),
+/// such that encoding key hash affects the whole permutation chain rather than only expanded IV,
+/// which makes precomputation across encoding keys harder
+///
+/// Tweak is SHA-256 of domain separation tag, encoding key hash, index of the layer and index of
+/// the block (`u64::MAX` for tweaks per layer), expanded to prime size in counter mode.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Tweaks {
+    /// No tweaks, encoding key hash only affects expanded IV
+    #[default]
+    None,
+    /// The same tweak for all blocks of a layer, distinct for every layer
+    PerLayer,
+    /// Distinct tweak for every block of every layer
+    PerBlock,
+}
+
+impl Tweaks {
+    /// XORs tweaks of `layer` into blocks of `piece`, applying the same tweaks again removes them
+    pub fn apply<const PRIME_SIZE_BYTES: usize>(
+        &self,
+        encoding_key_hash: EncodingKeyHash,
+        layer: usize,
+        piece: &mut [u8],
+    ) {
+        match self {
+            Tweaks::None => {}
+            Tweaks::PerLayer => {
+                let tweak = derive::<PRIME_SIZE_BYTES>(encoding_key_hash, layer, u64::MAX);
+                for block in piece.chunks_exact_mut(PRIME_SIZE_BYTES) {
+                    xor(block, &tweak);
+                }
+            }
+            Tweaks::PerBlock => {
+                for (index, block) in piece.chunks_exact_mut(PRIME_SIZE_BYTES).enumerate() {
+                    let tweak = derive::<PRIME_SIZE_BYTES>(encoding_key_hash, layer, index as u64);
+                    xor(block, &tweak);
+                }
+            }
+        }
+    }
+}
+
+fn derive<const PRIME_SIZE_BYTES: usize>(
+    encoding_key_hash: EncodingKeyHash,
+    layer: usize,
+    block: u64,
+) -> [u8; PRIME_SIZE_BYTES] {
+    let mut tweak = [0u8; PRIME_SIZE_BYTES];
+    for (counter, chunk) in tweak.chunks_mut(32).enumerate() {
+        let hash = Sha256::new()
+            .chain_update(DOMAIN_SEPARATION_TAG)
+            .chain_update(encoding_key_hash.as_ref())
+            .chain_update((layer as u64).to_le_bytes())
+            .chain_update(block.to_le_bytes())
+            .chain_update((counter as u32).to_le_bytes())
+            .finalize();
+        chunk.copy_from_slice(&hash[..chunk.len()]);
+    }
+    tweak
+}
+
+fn xor(block: &mut [u8], tweak: &[u8]) {
+    block
+        .iter_mut()
+        .zip(tweak)
+        .for_each(|(byte, tweak)| *byte ^= tweak);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tweaks() {
+        let encoding_key_hash = EncodingKeyHash::from([3u8; 32]);
+        let piece = [5u8; 128];
+
+        let mut tweaked = piece;
+        Tweaks::None.apply::<32>(encoding_key_hash, 0, &mut tweaked);
+        assert_eq!(tweaked, piece);
+
+        for &tweaks in &[Tweaks::PerLayer, Tweaks::PerBlock] {
+            let mut tweaked = piece;
+            tweaks.apply::<32>(encoding_key_hash, 0, &mut tweaked);
+            assert_ne!(tweaked, piece);
+
+            let blocks = tweaked.chunks_exact(32).collect::<Vec<_>>();
+            assert_eq!(blocks[0] == blocks[1], tweaks == Tweaks::PerLayer);
+
+            let mut other_layer = piece;
+            tweaks.apply::<32>(encoding_key_hash, 1, &mut other_layer);
+            assert_ne!(other_layer, tweaked);
+
+            let mut other_key = piece;
+            tweaks.apply::<32>(EncodingKeyHash::from([4u8; 32]), 0, &mut other_key);
+            assert_ne!(other_key, tweaked);
+
+            tweaks.apply::<32>(encoding_key_hash, 0, &mut tweaked);
+            assert_eq!(tweaked, piece);
+        }
+
+        // Derivation is frozen, changing it changes existing encodings
+        let tweak = derive::<32>(encoding_key_hash, 1, 2)
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>();
+        assert_eq!(
+            tweak,
+            "8498145c8636ae4d2e3db8e7a60ef265de120b0990c7fbac7d09bf7990b03c16"
+        );
+
+        // Tweaks of bigger primes are expanded
+        let mut tweaked = [0u8; 128];
+        Tweaks::PerLayer.apply::<64>(encoding_key_hash, 0, &mut tweaked);
+        assert_ne!(tweaked[..32], tweaked[32..64]);
+    }
+}
@@ -4,6 +4,7 @@ use criterion::{black_box, criterion_group};
 use rand::Rng;
 use rayon::prelude::*;
 use spartan_sloth::software::Sloth;
+use spartan_sloth::ExpandedIv;
 use std::time::{Duration, Instant};
 
 const PRIME: &str =
@@ -21,7 +22,7 @@ pub fn criterion_benchmark(c: &mut Criterion) {
     group.measurement_time(Duration::from_secs(30));
 
     let genesis_piece = random_bytes::<4096>();
-    let expanded_iv = random_bytes::<32>();
+    let expanded_iv = ExpandedIv::from(random_bytes::<32>());
 
     let sloth: Sloth<32, 4096> = Sloth::with_prime(PRIME.parse().unwrap());
 
@@ -36,10 +37,11 @@ pub fn criterion_benchmark(c: &mut Criterion) {
         b.iter_custom(|iters| {
             let start = Instant::now();
 
-            black_box((0..iters).into_par_iter().for_each(|_i| {
+            (0..iters).into_par_iter().for_each(|_i| {
                 let mut piece = input;
                 sloth.encode(&mut piece, expanded_iv, 1).unwrap();
-            }));
+                black_box(piece);
+            });
 
             start.elapsed()
         })
@@ -51,7 +53,7 @@ pub fn criterion_benchmark(c: &mut Criterion) {
     group.bench_with_input("Decode", &encoding, |b, &input| {
         b.iter(|| {
             let mut piece = input;
-            sloth.decode(&mut piece, expanded_iv, 1);
+            sloth.decode(&mut piece, expanded_iv, 1).unwrap();
         })
     });
 
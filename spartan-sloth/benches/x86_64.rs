@@ -1,59 +1,168 @@
-use criterion::criterion_main;
-use criterion::Criterion;
-use criterion::{black_box, criterion_group};
-use rand::Rng;
-use rayon::prelude::*;
-use spartan_sloth::x86_64::Sloth;
-use std::time::{Duration, Instant};
-
-fn random_bytes<const BYTES: usize>() -> [u8; BYTES] {
-    let mut bytes = [0u8; BYTES];
-    rand::thread_rng().fill(&mut bytes[..]);
-    bytes
-}
+// Benchmark only makes sense (and compiles) on x86-64
+#[cfg(target_arch = "x86_64")]
+mod bench {
+    use criterion::Criterion;
+    use criterion::{black_box, criterion_group};
+    use rand::Rng;
+    use rayon::prelude::*;
+    use spartan_sloth::x86_64::Sloth;
+    use spartan_sloth::ExpandedIv;
+    use spartan_sloth::{x86_64_avx2, x86_64_ifma};
+    use std::time::{Duration, Instant};
+
+    fn random_bytes<const BYTES: usize>() -> [u8; BYTES] {
+        let mut bytes = [0u8; BYTES];
+        rand::thread_rng().fill(&mut bytes[..]);
+        bytes
+    }
+
+    pub fn criterion_benchmark(c: &mut Criterion) {
+        let mut group = c.benchmark_group("Optimized x86-64");
+        group.sample_size(500);
+        group.measurement_time(Duration::from_secs(30));
+
+        let genesis_piece = random_bytes::<4096>();
+        let expanded_iv = ExpandedIv::from(random_bytes::<32>());
+
+        let sloth: Sloth = Sloth::new();
+
+        group.bench_with_input("Encode-single", &genesis_piece, |b, &input| {
+            b.iter(|| {
+                let mut piece = input;
+                sloth.encode(&mut piece, expanded_iv, 1).unwrap();
+            })
+        });
+
+        group.bench_with_input("Encode-parallel", &genesis_piece, |b, &input| {
+            b.iter_custom(|iters| {
+                let start = Instant::now();
 
-pub fn criterion_benchmark(c: &mut Criterion) {
-    let mut group = c.benchmark_group("Optimized x86-64");
-    group.sample_size(500);
-    group.measurement_time(Duration::from_secs(30));
+                (0..iters).into_par_iter().for_each(|_i| {
+                    let mut piece = input;
+                    sloth.encode(&mut piece, expanded_iv, 1).unwrap();
+                    black_box(piece);
+                });
 
-    let genesis_piece = random_bytes::<4096>();
-    let expanded_iv = random_bytes::<32>();
+                start.elapsed()
+            })
+        });
 
-    let sloth: Sloth = Sloth::new();
+        if x86_64_ifma::Sloth::is_supported() {
+            let sloth = x86_64_ifma::Sloth::new();
 
-    group.bench_with_input("Encode-single", &genesis_piece, |b, &input| {
-        b.iter(|| {
-            let mut piece = input;
-            sloth.encode(&mut piece, expanded_iv).unwrap();
-        })
-    });
+            // Encodes `x86_64_ifma::LANES` pieces per iteration
+            group.bench_with_input(
+                format!("Encode-{}-lanes", x86_64_ifma::LANES),
+                &genesis_piece,
+                |b, &input| {
+                    b.iter(|| {
+                        let mut pieces = [input; x86_64_ifma::LANES];
+                        sloth
+                            .encode_lanes(&mut pieces, [expanded_iv; x86_64_ifma::LANES], 1)
+                            .unwrap();
+                        black_box(pieces);
+                    })
+                },
+            );
+        }
 
-    group.bench_with_input("Encode-parallel", &genesis_piece, |b, &input| {
-        b.iter_custom(|iters| {
-            let start = Instant::now();
+        if x86_64_avx2::Sloth::is_supported() {
+            let sloth = x86_64_avx2::Sloth::new();
 
-            black_box((0..iters).into_par_iter().for_each(|_i| {
+            // Encodes `x86_64_avx2::LANES` pieces per iteration
+            group.bench_with_input(
+                format!("Encode-{}-lanes-avx2", x86_64_avx2::LANES),
+                &genesis_piece,
+                |b, &input| {
+                    b.iter(|| {
+                        let mut pieces = [input; x86_64_avx2::LANES];
+                        sloth
+                            .encode_lanes(&mut pieces, [expanded_iv; x86_64_avx2::LANES], 1)
+                            .unwrap();
+                        black_box(pieces);
+                    })
+                },
+            );
+        }
+
+        let mut encoding = genesis_piece;
+        sloth.encode(&mut encoding, expanded_iv, 1).unwrap();
+
+        group.bench_with_input("Decode", &encoding, |b, &input| {
+            b.iter(|| {
                 let mut piece = input;
-                sloth.encode(&mut piece, expanded_iv).unwrap();
-            }));
+                sloth.decode(&mut piece, expanded_iv, 1).unwrap();
+            })
+        });
+
+        if x86_64_ifma::Sloth::is_supported() {
+            let sloth = x86_64_ifma::Sloth::new();
+
+            group.bench_with_input(
+                format!("Decode-{}-lanes", x86_64_ifma::LANES),
+                &encoding,
+                |b, &input| {
+                    b.iter(|| {
+                        let mut piece = input;
+                        sloth.decode(&mut piece, expanded_iv, 1).unwrap();
+                    })
+                },
+            );
 
-            start.elapsed()
-        })
-    });
+            // Decodes `x86_64_ifma::LANES` encodings per iteration
+            group.bench_with_input(
+                format!("Decode-{}-interleaved", x86_64_ifma::LANES),
+                &encoding,
+                |b, &input| {
+                    b.iter(|| {
+                        let mut pieces = [input; x86_64_ifma::LANES];
+                        sloth
+                            .decode_lanes(&mut pieces, [expanded_iv; x86_64_ifma::LANES], 1)
+                            .unwrap();
+                        black_box(pieces);
+                    })
+                },
+            );
+        }
 
-    let mut encoding = genesis_piece;
-    sloth.encode(&mut encoding, expanded_iv).unwrap();
+        if x86_64_avx2::Sloth::is_supported() {
+            let sloth = x86_64_avx2::Sloth::new();
 
-    group.bench_with_input("Decode", &encoding, |b, &input| {
-        b.iter(|| {
-            let mut piece = input;
-            sloth.decode(&mut piece, expanded_iv);
-        })
-    });
+            group.bench_with_input(
+                format!("Decode-{}-lanes-avx2", x86_64_avx2::LANES),
+                &encoding,
+                |b, &input| {
+                    b.iter(|| {
+                        let mut piece = input;
+                        sloth.decode(&mut piece, expanded_iv, 1).unwrap();
+                    })
+                },
+            );
 
-    group.finish();
+            // Decodes `x86_64_avx2::LANES` encodings per iteration
+            group.bench_with_input(
+                format!("Decode-{}-interleaved-avx2", x86_64_avx2::LANES),
+                &encoding,
+                |b, &input| {
+                    b.iter(|| {
+                        let mut pieces = [input; x86_64_avx2::LANES];
+                        sloth
+                            .decode_lanes(&mut pieces, [expanded_iv; x86_64_avx2::LANES], 1)
+                            .unwrap();
+                        black_box(pieces);
+                    })
+                },
+            );
+        }
+
+        group.finish();
+    }
+
+    criterion_group!(benches, criterion_benchmark);
 }
 
-criterion_group!(benches, criterion_benchmark);
-criterion_main!(benches);
+#[cfg(target_arch = "x86_64")]
+criterion::criterion_main!(bench::benches);
+
+#[cfg(not(target_arch = "x86_64"))]
+fn main() {}
@@ -0,0 +1,596 @@
+//! Optimized implementation of Sloth in AArch64 assembly for 256-bit prime
+//! 115792089237316195423570985008687907853269984665640564039457584007913129639747
+//!
+//! Prime is `2^256 - 189`, so multiplication results are reduced by folding upper half multiplied
+//! by 189 into the lower half. Multiplication and squaring use MUL/UMULH with ADDS/ADCS carry
+//! chains, all of which are part of base ARMv8-A, so no runtime feature detection is needed.
+
+use crate::prime_256::{
+    self, blocks_to_piece, piece_to_blocks, Arithmetic, Limbs, BLOCKS_PER_PIECE, BLOCK_SIZE_BYTES,
+    PRIME_BYTES,
+};
+use crate::{DecodeError, EncodeError, Encoding, ExpandedIv, Piece, SlothBackend};
+use core::arch::asm;
+
+/// Multiplies two numbers modulo prime (result is below `2^256`, but may not be below prime)
+#[inline(always)]
+fn mul(a: &Limbs, b: &Limbs) -> Limbs {
+    let (r0, r1, r2, r3): (u64, u64, u64, u64);
+    // SAFETY: Only registers are used, all of them are declared
+    unsafe {
+        asm!(
+            // a * b[0] -> columns 0..4 in t0..t4
+            "mul {t0}, {a0}, {b0}",
+            "umulh {t1}, {a0}, {b0}",
+            "mul {lo}, {a1}, {b0}",
+            "umulh {t2}, {a1}, {b0}",
+            "adds {t1}, {t1}, {lo}",
+            "mul {lo}, {a2}, {b0}",
+            "umulh {t3}, {a2}, {b0}",
+            "adcs {t2}, {t2}, {lo}",
+            "mul {lo}, {a3}, {b0}",
+            "umulh {t4}, {a3}, {b0}",
+            "adcs {t3}, {t3}, {lo}",
+            "adc {t4}, {t4}, xzr",
+            // a * b[1] -> columns 1..5, lower words first, then upper words
+            "mul {lo}, {a0}, {b1}",
+            "mul {hi}, {a1}, {b1}",
+            "adds {t1}, {t1}, {lo}",
+            "adcs {t2}, {t2}, {hi}",
+            "mul {lo}, {a2}, {b1}",
+            "mul {hi}, {a3}, {b1}",
+            "adcs {t3}, {t3}, {lo}",
+            "adcs {t4}, {t4}, {hi}",
+            "adc {t5}, xzr, xzr",
+            "umulh {lo}, {a0}, {b1}",
+            "umulh {hi}, {a1}, {b1}",
+            "adds {t2}, {t2}, {lo}",
+            "adcs {t3}, {t3}, {hi}",
+            "umulh {lo}, {a2}, {b1}",
+            "umulh {hi}, {a3}, {b1}",
+            "adcs {t4}, {t4}, {lo}",
+            "adc {t5}, {t5}, {hi}",
+            // a * b[2] -> columns 2..6
+            "mul {lo}, {a0}, {b2}",
+            "mul {hi}, {a1}, {b2}",
+            "adds {t2}, {t2}, {lo}",
+            "adcs {t3}, {t3}, {hi}",
+            "mul {lo}, {a2}, {b2}",
+            "mul {hi}, {a3}, {b2}",
+            "adcs {t4}, {t4}, {lo}",
+            "adcs {t5}, {t5}, {hi}",
+            "adc {t6}, xzr, xzr",
+            "umulh {lo}, {a0}, {b2}",
+            "umulh {hi}, {a1}, {b2}",
+            "adds {t3}, {t3}, {lo}",
+            "adcs {t4}, {t4}, {hi}",
+            "umulh {lo}, {a2}, {b2}",
+            "umulh {hi}, {a3}, {b2}",
+            "adcs {t5}, {t5}, {lo}",
+            "adc {t6}, {t6}, {hi}",
+            // a * b[3] -> columns 3..7
+            "mul {lo}, {a0}, {b3}",
+            "mul {hi}, {a1}, {b3}",
+            "adds {t3}, {t3}, {lo}",
+            "adcs {t4}, {t4}, {hi}",
+            "mul {lo}, {a2}, {b3}",
+            "mul {hi}, {a3}, {b3}",
+            "adcs {t5}, {t5}, {lo}",
+            "adcs {t6}, {t6}, {hi}",
+            "adc {t7}, xzr, xzr",
+            "umulh {lo}, {a0}, {b3}",
+            "umulh {hi}, {a1}, {b3}",
+            "adds {t4}, {t4}, {lo}",
+            "adcs {t5}, {t5}, {hi}",
+            "umulh {lo}, {a2}, {b3}",
+            "umulh {hi}, {a3}, {b3}",
+            "adcs {t6}, {t6}, {lo}",
+            "adc {t7}, {t7}, {hi}",
+            // Upper half multiplied by 189 (`2^256 mod prime`): lower words in a0..a3, upper words
+            // in b0..b3
+            "mov {lo}, #189",
+            "mul {a0}, {t4}, {lo}",
+            "umulh {b0}, {t4}, {lo}",
+            "mul {a1}, {t5}, {lo}",
+            "umulh {b1}, {t5}, {lo}",
+            "mul {a2}, {t6}, {lo}",
+            "umulh {b2}, {t6}, {lo}",
+            "mul {a3}, {t7}, {lo}",
+            "umulh {b3}, {t7}, {lo}",
+            // Add lower words and lower half, then upper words shifted by one limb, top word is
+            // small and ends up in hi
+            "adds {t0}, {t0}, {a0}",
+            "adcs {t1}, {t1}, {a1}",
+            "adcs {t2}, {t2}, {a2}",
+            "adcs {t3}, {t3}, {a3}",
+            "adc {hi}, {b3}, xzr",
+            "adds {t1}, {t1}, {b0}",
+            "adcs {t2}, {t2}, {b1}",
+            "adcs {t3}, {t3}, {b2}",
+            "adc {hi}, {hi}, xzr",
+            // Fold small top word multiplied by 189 back in
+            "mul {hi}, {hi}, {lo}",
+            "adds {t0}, {t0}, {hi}",
+            "adcs {t1}, {t1}, xzr",
+            "adcs {t2}, {t2}, xzr",
+            "adcs {t3}, {t3}, xzr",
+            // Fold potential carry once more, can't overflow again
+            "csel {hi}, {lo}, xzr, cs",
+            "adds {t0}, {t0}, {hi}",
+            "adcs {t1}, {t1}, xzr",
+            "adcs {t2}, {t2}, xzr",
+            "adc {t3}, {t3}, xzr",
+            a0 = inout(reg) a[0] => _,
+            a1 = inout(reg) a[1] => _,
+            a2 = inout(reg) a[2] => _,
+            a3 = inout(reg) a[3] => _,
+            b0 = inout(reg) b[0] => _,
+            b1 = inout(reg) b[1] => _,
+            b2 = inout(reg) b[2] => _,
+            b3 = inout(reg) b[3] => _,
+            t0 = out(reg) r0,
+            t1 = out(reg) r1,
+            t2 = out(reg) r2,
+            t3 = out(reg) r3,
+            t4 = out(reg) _,
+            t5 = out(reg) _,
+            t6 = out(reg) _,
+            t7 = out(reg) _,
+            lo = out(reg) _,
+            hi = out(reg) _,
+            options(pure, nomem, nostack),
+        );
+    }
+    [r0, r1, r2, r3]
+}
+
+/// Squares a number modulo prime (result is below `2^256`, but may not be below prime)
+///
+/// This is the hottest function, cross products are only computed once and doubled.
+#[inline(always)]
+fn sqr(a: &Limbs) -> Limbs {
+    let (r0, r1, r2, r3): (u64, u64, u64, u64);
+    // SAFETY: Only registers are used, all of them are declared
+    unsafe {
+        asm!(
+            // Cross products a[i] * a[j], i < j -> columns 1..6 in t1..t6
+            "mul {t1}, {a0}, {a1}",
+            "umulh {t2}, {a0}, {a1}",
+            "mul {lo}, {a0}, {a2}",
+            "umulh {t3}, {a0}, {a2}",
+            "adds {t2}, {t2}, {lo}",
+            "mul {lo}, {a0}, {a3}",
+            "umulh {t4}, {a0}, {a3}",
+            "adcs {t3}, {t3}, {lo}",
+            "mul {lo}, {a1}, {a3}",
+            "umulh {t5}, {a1}, {a3}",
+            "adcs {t4}, {t4}, {lo}",
+            "adc {t5}, {t5}, xzr",
+            "mul {lo}, {a1}, {a2}",
+            "umulh {hi}, {a1}, {a2}",
+            "mul {t0}, {a2}, {a3}",
+            "umulh {t6}, {a2}, {a3}",
+            "adds {t3}, {t3}, {lo}",
+            "adcs {t4}, {t4}, {hi}",
+            "adcs {t5}, {t5}, {t0}",
+            "adc {t6}, {t6}, xzr",
+            // Double cross products -> columns 1..7
+            "adds {t1}, {t1}, {t1}",
+            "adcs {t2}, {t2}, {t2}",
+            "adcs {t3}, {t3}, {t3}",
+            "adcs {t4}, {t4}, {t4}",
+            "adcs {t5}, {t5}, {t5}",
+            "adcs {t6}, {t6}, {t6}",
+            "adc {t7}, xzr, xzr",
+            // Add squares a[i] * a[i]
+            "mul {t0}, {a0}, {a0}",
+            "umulh {hi}, {a0}, {a0}",
+            "adds {t1}, {t1}, {hi}",
+            "mul {lo}, {a1}, {a1}",
+            "umulh {hi}, {a1}, {a1}",
+            "adcs {t2}, {t2}, {lo}",
+            "adcs {t3}, {t3}, {hi}",
+            "mul {lo}, {a2}, {a2}",
+            "umulh {hi}, {a2}, {a2}",
+            "adcs {t4}, {t4}, {lo}",
+            "adcs {t5}, {t5}, {hi}",
+            "mul {lo}, {a3}, {a3}",
+            "umulh {hi}, {a3}, {a3}",
+            "adcs {t6}, {t6}, {lo}",
+            "adc {t7}, {t7}, {hi}",
+            // Upper half multiplied by 189 (`2^256 mod prime`): lower words in a0..a3, upper words
+            // in u0..u3
+            "mov {lo}, #189",
+            "mul {a0}, {t4}, {lo}",
+            "umulh {u0}, {t4}, {lo}",
+            "mul {a1}, {t5}, {lo}",
+            "umulh {u1}, {t5}, {lo}",
+            "mul {a2}, {t6}, {lo}",
+            "umulh {u2}, {t6}, {lo}",
+            "mul {a3}, {t7}, {lo}",
+            "umulh {u3}, {t7}, {lo}",
+            // Add lower words and lower half, then upper words shifted by one limb, top word is
+            // small and ends up in hi
+            "adds {t0}, {t0}, {a0}",
+            "adcs {t1}, {t1}, {a1}",
+            "adcs {t2}, {t2}, {a2}",
+            "adcs {t3}, {t3}, {a3}",
+            "adc {hi}, {u3}, xzr",
+            "adds {t1}, {t1}, {u0}",
+            "adcs {t2}, {t2}, {u1}",
+            "adcs {t3}, {t3}, {u2}",
+            "adc {hi}, {hi}, xzr",
+            // Fold small top word multiplied by 189 back in
+            "mul {hi}, {hi}, {lo}",
+            "adds {t0}, {t0}, {hi}",
+            "adcs {t1}, {t1}, xzr",
+            "adcs {t2}, {t2}, xzr",
+            "adcs {t3}, {t3}, xzr",
+            // Fold potential carry once more, can't overflow again
+            "csel {hi}, {lo}, xzr, cs",
+            "adds {t0}, {t0}, {hi}",
+            "adcs {t1}, {t1}, xzr",
+            "adcs {t2}, {t2}, xzr",
+            "adc {t3}, {t3}, xzr",
+            a0 = inout(reg) a[0] => _,
+            a1 = inout(reg) a[1] => _,
+            a2 = inout(reg) a[2] => _,
+            a3 = inout(reg) a[3] => _,
+            t0 = out(reg) r0,
+            t1 = out(reg) r1,
+            t2 = out(reg) r2,
+            t3 = out(reg) r3,
+            t4 = out(reg) _,
+            t5 = out(reg) _,
+            t6 = out(reg) _,
+            t7 = out(reg) _,
+            u0 = out(reg) _,
+            u1 = out(reg) _,
+            u2 = out(reg) _,
+            u3 = out(reg) _,
+            lo = out(reg) _,
+            hi = out(reg) _,
+            options(pure, nomem, nostack),
+        );
+    }
+    [r0, r1, r2, r3]
+}
+
+/// Arithmetic with MUL/UMULH instructions and ADDS/ADCS carry chains
+struct Armv8;
+
+impl Arithmetic for Armv8 {
+    #[inline(always)]
+    fn mul(a: &Limbs, b: &Limbs) -> Limbs {
+        mul(a, b)
+    }
+
+    #[inline(always)]
+    fn sqr(a: &Limbs) -> Limbs {
+        sqr(a)
+    }
+}
+
+/// Piece converted into limbs, see [`Sloth::prepare_piece()`]
+#[derive(Debug, Clone)]
+pub struct PreparedPiece {
+    blocks: [Limbs; BLOCKS_PER_PIECE],
+}
+
+#[derive(Debug, Clone)]
+pub struct Sloth {}
+
+impl Sloth {
+    /// Initializes SLOTH for 256-bit prime
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Sequentially encodes a 4096 byte piece
+    pub fn encode(
+        &self,
+        piece: &mut [u8; 4096],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+    ) -> Result<(), EncodeError> {
+        if layers == 0 {
+            return Err(EncodeError::ZeroLayers);
+        }
+
+        let mut blocks = piece_to_blocks(piece);
+        prime_256::encode_blocks::<Armv8>(&mut blocks, expanded_iv.as_bytes(), layers);
+        blocks_to_piece(&blocks, piece);
+
+        Ok(())
+    }
+
+    /// Converts piece into limbs, such that it can be encoded multiple times without conversion
+    pub fn prepare_piece(&self, piece: &Piece<4096>) -> PreparedPiece {
+        PreparedPiece {
+            blocks: piece_to_blocks(piece.as_bytes()),
+        }
+    }
+
+    /// Encodes prepared piece into `encoding`, otherwise the same as [`Sloth::encode()`]
+    pub fn encode_prepared(
+        &self,
+        piece: &PreparedPiece,
+        encoding: &mut Encoding<4096>,
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+    ) -> Result<(), EncodeError> {
+        if layers == 0 {
+            return Err(EncodeError::ZeroLayers);
+        }
+
+        let mut blocks = piece.blocks;
+        prime_256::encode_blocks::<Armv8>(&mut blocks, expanded_iv.as_bytes(), layers);
+        blocks_to_piece(&blocks, encoding.as_bytes_mut());
+
+        Ok(())
+    }
+
+    /// Same as [`Sloth::encode()`], but for a piece of any length that is a multiple of 32 bytes
+    /// (at least two blocks)
+    pub fn encode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+    ) -> Result<(), EncodeError> {
+        if layers == 0 {
+            return Err(EncodeError::ZeroLayers);
+        }
+        if !crate::is_valid_length(piece.len(), BLOCK_SIZE_BYTES) {
+            return Err(EncodeError::InvalidLength);
+        }
+
+        prime_256::encode_bytes::<Armv8>(piece, expanded_iv.as_bytes(), layers);
+
+        Ok(())
+    }
+
+    /// Decodes a 4096 byte encoding in time << encode time
+    pub fn decode(
+        &self,
+        piece: &mut [u8; 4096],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+    ) -> Result<(), DecodeError> {
+        self.decode_slice(piece, expanded_iv, layers)
+    }
+
+    /// Same as [`Sloth::decode()`], but for an encoding of any length that is a multiple of 32
+    /// bytes (at least two blocks)
+    pub fn decode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+    ) -> Result<(), DecodeError> {
+        if layers == 0 {
+            return Err(DecodeError::ZeroLayers);
+        }
+        if !crate::is_valid_length(piece.len(), BLOCK_SIZE_BYTES) {
+            return Err(DecodeError::InvalidLength);
+        }
+
+        prime_256::decode_bytes::<Armv8>(piece, expanded_iv.as_bytes(), layers);
+
+        Ok(())
+    }
+}
+
+impl SlothBackend<32, 4096> for Sloth {
+    type PreparedPiece = PreparedPiece;
+    // Blocks are kept on the stack, so no working memory is needed
+    type Scratch = ();
+
+    fn prime(&self) -> [u8; 32] {
+        PRIME_BYTES
+    }
+
+    fn encode(
+        &self,
+        piece: &mut [u8; 4096],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+    ) -> Result<(), EncodeError> {
+        Sloth::encode(self, piece, expanded_iv, layers)
+    }
+
+    fn decode(
+        &self,
+        piece: &mut [u8; 4096],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+    ) -> Result<(), DecodeError> {
+        Sloth::decode(self, piece, expanded_iv, layers)
+    }
+
+    fn encode_with_scratch(
+        &self,
+        piece: &mut [u8; 4096],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+        _scratch: &mut (),
+    ) -> Result<(), EncodeError> {
+        Sloth::encode(self, piece, expanded_iv, layers)
+    }
+
+    fn decode_with_scratch(
+        &self,
+        piece: &mut [u8; 4096],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+        _scratch: &mut (),
+    ) -> Result<(), DecodeError> {
+        Sloth::decode(self, piece, expanded_iv, layers)
+    }
+
+    fn prepare_piece(&self, piece: &Piece<4096>) -> PreparedPiece {
+        Sloth::prepare_piece(self, piece)
+    }
+
+    fn encode_prepared(
+        &self,
+        piece: &PreparedPiece,
+        encoding: &mut Encoding<4096>,
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+        _scratch: &mut (),
+    ) -> Result<(), EncodeError> {
+        Sloth::encode_prepared(self, piece, encoding, expanded_iv, layers)
+    }
+
+    fn encode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+        _scratch: &mut (),
+    ) -> Result<(), EncodeError> {
+        Sloth::encode_slice(self, piece, expanded_iv, layers)
+    }
+
+    fn decode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+        _scratch: &mut (),
+    ) -> Result<(), DecodeError> {
+        Sloth::decode_slice(self, piece, expanded_iv, layers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prime_256::PRIME;
+    use crate::test_vectors::KNOWN_PIECE_ENCODING;
+    use rand::prelude::*;
+
+    fn random_bytes<const BYTES: usize>() -> [u8; BYTES] {
+        let mut bytes = [0u8; BYTES];
+        rand::thread_rng().fill(&mut bytes[..]);
+        bytes
+    }
+
+    #[test]
+    fn test_random_piece() {
+        let expanded_iv = ExpandedIv::from(random_bytes());
+        let piece = random_bytes();
+
+        let sloth = Sloth::new();
+        let mut encoding = piece;
+        sloth.encode(&mut encoding, expanded_iv, 1).unwrap();
+        let mut decoding = encoding;
+        sloth.decode(&mut decoding, expanded_iv, 1).unwrap();
+
+        assert_eq!(piece.to_vec(), decoding.to_vec());
+    }
+
+    #[test]
+    fn test_known_piece() {
+        let expanded_iv = ExpandedIv::from([3u8; 32]);
+        let piece = [5u8; 4096];
+
+        let sloth = Sloth::new();
+        let mut encoding = piece;
+        sloth.encode(&mut encoding, expanded_iv, 1).unwrap();
+        assert_eq!(encoding, KNOWN_PIECE_ENCODING);
+        let mut decoding = encoding;
+        sloth.decode(&mut decoding, expanded_iv, 1).unwrap();
+
+        assert_eq!(piece.to_vec(), decoding.to_vec());
+    }
+
+    #[test]
+    fn test_data_bigger_than_prime() {
+        let sloth = Sloth::new();
+
+        let mut prime_piece = [0u8; 4096];
+        prime_piece[..32].copy_from_slice(&PRIME_BYTES);
+
+        for &piece in &[[0xffu8; 4096], prime_piece, [0u8; 4096]] {
+            for layers in 1..=3 {
+                let mut encoding = piece;
+                sloth
+                    .encode(&mut encoding, ExpandedIv::from([0u8; 32]), layers)
+                    .unwrap();
+                let mut decoding = encoding;
+                sloth
+                    .decode(&mut decoding, ExpandedIv::from([0u8; 32]), layers)
+                    .unwrap();
+
+                assert_eq!(piece.to_vec(), decoding.to_vec());
+            }
+        }
+
+        // Prime and zero are swapped, numbers above prime are not changed
+        let mut block = PRIME;
+        prime_256::sqrt_permutation::<Armv8>(&mut block);
+        assert_eq!(block, [0u64; 4]);
+        let mut block = [u64::MAX; 4];
+        prime_256::sqrt_permutation::<Armv8>(&mut block);
+        assert_eq!(block, [u64::MAX; 4]);
+    }
+
+    /// Compares encoding and decoding against another implementation
+    #[allow(dead_code)]
+    fn test_matches<Other: SlothBackend<32, 4096>>(other: &Other) {
+        let sloth = Sloth::new();
+
+        for layers in 1..=3 {
+            let expanded_iv = ExpandedIv::from(random_bytes());
+            let piece = random_bytes();
+
+            let mut encoding = piece;
+            sloth.encode(&mut encoding, expanded_iv, layers).unwrap();
+            let mut other_encoding = piece;
+            other
+                .encode(&mut other_encoding, expanded_iv, layers)
+                .unwrap();
+            assert_eq!(encoding.to_vec(), other_encoding.to_vec());
+
+            let mut decoding = encoding;
+            sloth.decode(&mut decoding, expanded_iv, layers).unwrap();
+            let mut other_decoding = encoding;
+            other
+                .decode(&mut other_decoding, expanded_iv, layers)
+                .unwrap();
+            assert_eq!(decoding.to_vec(), other_decoding.to_vec());
+        }
+
+        // Blocks bigger than prime and carries through all limbs
+        let expanded_iv = ExpandedIv::from([0xffu8; 32]);
+        let encoding = [0xffu8; 4096];
+        let mut decoding = encoding;
+        sloth.decode(&mut decoding, expanded_iv, 1).unwrap();
+        let mut other_decoding = encoding;
+        other.decode(&mut other_decoding, expanded_iv, 1).unwrap();
+        assert_eq!(decoding.to_vec(), other_decoding.to_vec());
+    }
+
+    #[cfg(feature = "software")]
+    #[test]
+    fn test_matches_software() {
+        use crate::software;
+
+        test_matches(&software::Sloth::<32, 4096>::with_prime(
+            software::largest_prime(32),
+        ));
+    }
+
+    /// GMP is often not available when cross-compiling, so compare with pure Rust implementation
+    /// too
+    #[cfg(feature = "portable")]
+    #[test]
+    fn test_matches_portable() {
+        use crate::portable;
+
+        test_matches(&portable::Sloth::<32, 4096>::with_prime(PRIME_BYTES));
+    }
+}
@@ -0,0 +1,158 @@
+//! Sliding window addition chains for exponentiation by a fixed exponent
+//!
+//! Exponent `(prime + 1) / 4` used for square root doesn't change for the lifetime of SLOTH
+//! instance, so its decomposition into squarings and multiplications by odd powers of the base is
+//! computed once in constructor instead of scanning exponent bits on every exponentiation.
+
+/// Window size in bits
+const WINDOW_BITS: usize = 5;
+/// Number of odd powers of the base (`x^1, x^3, ..., x^(2^WINDOW_BITS - 1)`) chain multiplies by
+pub(crate) const ODD_POWERS: usize = 1 << (WINDOW_BITS - 1);
+/// Number of steps per byte of exponent, windows start at least `WINDOW_BITS` bits apart, so this
+/// is enough for any exponent
+const STEPS_PER_BYTE: usize = 2;
+
+/// Single step of the chain: square the result a few times, then multiply it by an odd power of the
+/// base
+#[derive(Debug, Copy, Clone, Default)]
+pub(crate) struct Step {
+    /// Number of squarings before multiplication
+    pub(crate) squarings: u16,
+    /// Index of odd power to multiply by, power is `2 * odd_power + 1`
+    pub(crate) odd_power: u8,
+}
+
+/// Addition chain for a fixed exponent, result is computed by starting with odd power of the first
+/// step, applying remaining steps and squaring [`AdditionChain::trailing_squarings()`] times
+///
+/// Storage for steps is sized from `EXPONENT_SIZE_BYTES`.
+#[derive(Debug, Clone)]
+pub(crate) struct AdditionChain<const EXPONENT_SIZE_BYTES: usize> {
+    steps: [[Step; STEPS_PER_BYTE]; EXPONENT_SIZE_BYTES],
+    len: usize,
+    trailing_squarings: u16,
+}
+
+impl<const EXPONENT_SIZE_BYTES: usize> AdditionChain<EXPONENT_SIZE_BYTES> {
+    /// Creates addition chain for non-zero exponent (as little-endian 64-bit limbs) that fits into
+    /// `EXPONENT_SIZE_BYTES`
+    pub(crate) fn new(exponent: &[u64]) -> Self {
+        let bit = |i: usize| (exponent[i / 64] >> (i % 64)) & 1 == 1;
+
+        let mut chain = Self {
+            steps: [[Step::default(); STEPS_PER_BYTE]; EXPONENT_SIZE_BYTES],
+            len: 0,
+            trailing_squarings: 0,
+        };
+        let mut squarings = 0;
+        // Bits below `position` are not processed yet
+        let mut position = exponent.len() * 64;
+        while position > 0 {
+            if !bit(position - 1) {
+                // Leading zeros don't need squarings
+                if chain.len > 0 {
+                    squarings += 1;
+                }
+                position -= 1;
+                continue;
+            }
+
+            // Window starts with one and ends with one, so that its value is odd
+            let mut window_end = position.saturating_sub(WINDOW_BITS);
+            while !bit(window_end) {
+                window_end += 1;
+            }
+            let value = (window_end..position)
+                .rev()
+                .fold(0, |value, i| (value << 1) | bit(i) as usize);
+
+            chain.steps.as_flattened_mut()[chain.len] = Step {
+                squarings: if chain.len == 0 {
+                    0
+                } else {
+                    squarings + (position - window_end) as u16
+                },
+                odd_power: (value >> 1) as u8,
+            };
+            chain.len += 1;
+            squarings = 0;
+            position = window_end;
+        }
+        chain.trailing_squarings = squarings;
+
+        chain
+    }
+
+    /// Steps of the chain, the first step has no squarings
+    pub(crate) fn steps(&self) -> &[Step] {
+        &self.steps.as_flattened()[..self.len]
+    }
+
+    /// Number of squarings after the last step
+    pub(crate) fn trailing_squarings(&self) -> u16 {
+        self.trailing_squarings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::*;
+
+    /// Mersenne prime `2^61 - 1`
+    const MODULUS: u128 = (1 << 61) - 1;
+
+    fn pow_with_chain(base: u128, chain: &AdditionChain<8>) -> u128 {
+        let mut odd_powers = [base; ODD_POWERS];
+        let square = base * base % MODULUS;
+        for i in 1..ODD_POWERS {
+            odd_powers[i] = odd_powers[i - 1] * square % MODULUS;
+        }
+
+        let steps = chain.steps();
+        let mut result = odd_powers[steps[0].odd_power as usize];
+        for step in &steps[1..] {
+            for _ in 0..step.squarings {
+                result = result * result % MODULUS;
+            }
+            result = result * odd_powers[step.odd_power as usize] % MODULUS;
+        }
+        for _ in 0..chain.trailing_squarings() {
+            result = result * result % MODULUS;
+        }
+        result
+    }
+
+    fn pow(base: u128, exponent: u64) -> u128 {
+        (0..64).rev().fold(1, |result, i| {
+            let result = result * result % MODULUS;
+            if (exponent >> i) & 1 == 1 {
+                result * base % MODULUS
+            } else {
+                result
+            }
+        })
+    }
+
+    #[test]
+    fn test_chain_matches_square_and_multiply() {
+        let mut rng = rand::thread_rng();
+        let exponents = [1, 2, 31, 32, 0b100001, 1 << 63, u64::MAX];
+        for i in 0..1000 {
+            let exponent = exponents
+                .get(i)
+                .copied()
+                .unwrap_or_else(|| (rng.gen::<u64>() >> rng.gen_range(0..64)).max(1));
+            let base = rng.gen_range(0..MODULUS);
+
+            // Leading zero limb must not change the result
+            let chain = AdditionChain::new(&[exponent, 0]);
+            assert_eq!(
+                pow_with_chain(base, &chain),
+                pow(base, exponent),
+                "exponent {}",
+                exponent
+            );
+        }
+    }
+}
@@ -0,0 +1,256 @@
+//! SLOTH with alternating direction of layers, see [`Chaining::Alternating`]
+//!
+//! Works on top of any other implementation, which is used to apply one layer at a time.
+
+use crate::{
+    BlockMapping, Chaining, DecodeError, EncodeError, Encoding, ExpandedIv, Piece, SlothBackend,
+};
+
+/// SLOTH that processes blocks of odd layers in reverse order using `Inner` implementation, such
+/// that after two layers every block of encoding depends on every block of the piece
+#[derive(Debug, Clone)]
+pub struct Sloth<Inner> {
+    inner: Inner,
+}
+
+impl<Inner> Sloth<Inner> {
+    /// Initializes SLOTH on top of provided implementation
+    pub fn new(inner: Inner) -> Self {
+        Self { inner }
+    }
+
+    /// Underlying implementation
+    pub fn inner(&self) -> &Inner {
+        &self.inner
+    }
+}
+
+impl<Inner, const PRIME_SIZE_BYTES: usize, const PIECE_SIZE_BYTES: usize>
+    SlothBackend<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES> for Sloth<Inner>
+where
+    Inner: SlothBackend<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>,
+{
+    // Piece is copied into encoding and encoded one layer at a time anyway
+    type PreparedPiece = Piece<PIECE_SIZE_BYTES>;
+    type Scratch = Inner::Scratch;
+
+    fn prime(&self) -> [u8; PRIME_SIZE_BYTES] {
+        self.inner.prime()
+    }
+
+    fn chaining(&self) -> Chaining {
+        Chaining::Alternating
+    }
+
+    fn block_mapping(&self) -> BlockMapping {
+        self.inner.block_mapping()
+    }
+
+    fn encode(
+        &self,
+        piece: &mut [u8; PIECE_SIZE_BYTES],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+    ) -> Result<(), EncodeError> {
+        self.encode_slice(piece, expanded_iv, layers, &mut Inner::Scratch::default())
+    }
+
+    fn decode(
+        &self,
+        piece: &mut [u8; PIECE_SIZE_BYTES],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+    ) -> Result<(), DecodeError> {
+        self.decode_slice(piece, expanded_iv, layers, &mut Inner::Scratch::default())
+    }
+
+    fn encode_with_scratch(
+        &self,
+        piece: &mut [u8; PIECE_SIZE_BYTES],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        scratch: &mut Self::Scratch,
+    ) -> Result<(), EncodeError> {
+        self.encode_slice(piece, expanded_iv, layers, scratch)
+    }
+
+    fn decode_with_scratch(
+        &self,
+        piece: &mut [u8; PIECE_SIZE_BYTES],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        scratch: &mut Self::Scratch,
+    ) -> Result<(), DecodeError> {
+        self.decode_slice(piece, expanded_iv, layers, scratch)
+    }
+
+    fn prepare_piece(&self, piece: &Piece<PIECE_SIZE_BYTES>) -> Self::PreparedPiece {
+        *piece
+    }
+
+    fn encode_prepared(
+        &self,
+        piece: &Self::PreparedPiece,
+        encoding: &mut Encoding<PIECE_SIZE_BYTES>,
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        scratch: &mut Self::Scratch,
+    ) -> Result<(), EncodeError> {
+        encoding.as_bytes_mut().copy_from_slice(piece.as_bytes());
+        self.encode_slice(encoding.as_bytes_mut(), expanded_iv, layers, scratch)
+    }
+
+    fn encode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        scratch: &mut Self::Scratch,
+    ) -> Result<(), EncodeError> {
+        crate::layers::encode(
+            &self.inner,
+            piece,
+            expanded_iv,
+            layers,
+            Chaining::Alternating,
+            &|_, _| {},
+            scratch,
+        )
+    }
+
+    fn decode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        scratch: &mut Self::Scratch,
+    ) -> Result<(), DecodeError> {
+        crate::layers::decode(
+            &self.inner,
+            piece,
+            expanded_iv,
+            layers,
+            Chaining::Alternating,
+            &|_, _| {},
+            scratch,
+        )
+    }
+}
+
+#[cfg(all(test, feature = "portable"))]
+mod tests {
+    use super::*;
+    use crate::prime_256::PRIME_BYTES;
+    use crate::test_vectors::{KNOWN_PIECE_ALTERNATING_ENCODING, KNOWN_PIECE_ENCODING};
+    use rand::prelude::*;
+
+    type PortableSloth = crate::portable::Sloth<32, 4096>;
+
+    fn random_bytes<const BYTES: usize>() -> [u8; BYTES] {
+        let mut bytes = [0u8; BYTES];
+        rand::thread_rng().fill(&mut bytes[..]);
+        bytes
+    }
+
+    fn test_known_piece_with<Inner: SlothBackend<32, 4096>>(inner: Inner) {
+        let sloth = Sloth::new(inner);
+        let expanded_iv = ExpandedIv::from([3u8; 32]);
+        let piece = [5u8; 4096];
+
+        // Single layer is the same as CBC
+        let mut encoding = piece;
+        sloth.encode(&mut encoding, expanded_iv, 1).unwrap();
+        assert_eq!(encoding, KNOWN_PIECE_ENCODING);
+
+        let mut encoding = piece;
+        sloth.encode(&mut encoding, expanded_iv, 2).unwrap();
+        assert_eq!(encoding, KNOWN_PIECE_ALTERNATING_ENCODING);
+
+        sloth.decode(&mut encoding, expanded_iv, 2).unwrap();
+        assert_eq!(encoding, piece);
+    }
+
+    #[test]
+    fn test_known_piece() {
+        assert_eq!(
+            Sloth::new(PortableSloth::with_prime(PRIME_BYTES)).chaining(),
+            Chaining::Alternating
+        );
+        test_known_piece_with(PortableSloth::with_prime(PRIME_BYTES));
+        #[cfg(feature = "software")]
+        test_known_piece_with(
+            crate::software::Sloth::<32, 4096>::try_with_prime_bytes(PRIME_BYTES).unwrap(),
+        );
+        #[cfg(all(target_arch = "x86_64", feature = "std"))]
+        if crate::x86_64::Sloth::is_supported() {
+            test_known_piece_with(crate::x86_64::Sloth::new());
+        }
+    }
+
+    #[test]
+    fn test_diffusion() {
+        let sloth = Sloth::new(PortableSloth::with_prime(PRIME_BYTES));
+        let expanded_iv = ExpandedIv::from(random_bytes());
+        let piece = random_bytes::<4096>();
+        let mut other_piece = piece;
+        other_piece[4095] ^= 1;
+
+        for layers in 1..=3 {
+            let mut encoding = piece;
+            sloth.encode(&mut encoding, expanded_iv, layers).unwrap();
+            let mut other_encoding = other_piece;
+            sloth
+                .encode(&mut other_encoding, expanded_iv, layers)
+                .unwrap();
+
+            let changed_blocks = encoding
+                .chunks_exact(32)
+                .zip(other_encoding.chunks_exact(32))
+                .filter(|(block, other_block)| block != other_block)
+                .count();
+            // Change of the last block only reaches other blocks once layer is reversed
+            assert_eq!(changed_blocks, if layers == 1 { 1 } else { 128 });
+
+            sloth.decode(&mut encoding, expanded_iv, layers).unwrap();
+            assert_eq!(encoding, piece);
+        }
+    }
+
+    #[test]
+    fn test_slice() {
+        let sloth = Sloth::new(PortableSloth::with_prime(PRIME_BYTES));
+        let expanded_iv = ExpandedIv::from(random_bytes());
+        let mut scratch = ();
+
+        // Odd number of blocks, such that the middle block stays in place when reversed
+        let piece = random_bytes::<96>();
+        let mut encoding = piece;
+        SlothBackend::<32, 4096>::encode_slice(&sloth, &mut encoding, expanded_iv, 4, &mut scratch)
+            .unwrap();
+        assert_ne!(encoding, piece);
+        SlothBackend::<32, 4096>::decode_slice(&sloth, &mut encoding, expanded_iv, 4, &mut scratch)
+            .unwrap();
+        assert_eq!(encoding, piece);
+
+        assert_eq!(
+            SlothBackend::<32, 4096>::encode_slice(
+                &sloth,
+                &mut encoding,
+                expanded_iv,
+                0,
+                &mut scratch
+            ),
+            Err(EncodeError::ZeroLayers)
+        );
+        assert_eq!(
+            SlothBackend::<32, 4096>::decode_slice(
+                &sloth,
+                &mut encoding[..32],
+                expanded_iv,
+                1,
+                &mut scratch
+            ),
+            Err(DecodeError::InvalidLength)
+        );
+    }
+}
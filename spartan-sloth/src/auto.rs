@@ -0,0 +1,842 @@
+//! Implementation of Sloth for 256-bit prime
+//! 115792089237316195423570985008687907853269984665640564039457584007913129639747
+//! that picks the fastest implementation supported by current CPU at runtime
+//!
+//! CPU features are checked once, the first time [`detect()`] is called (which happens in
+//! [`Sloth::new()`]), if none of optimized implementations are supported, portable implementation
+//! is used.
+
+use crate::portable;
+use crate::prime_256::PRIME_BYTES;
+use crate::{DecodeError, EncodeError, Encoding, ExpandedIv, Piece, SlothBackend};
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Detected backend (`Backend as u8 + 1`), `0` means detection didn't happen yet
+static DETECTED_BACKEND: AtomicU8 = AtomicU8::new(0);
+
+/// SLOTH implementation that can be selected at runtime
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Backend {
+    /// Optimized x86-64 assembly, requires BMI2 and ADX
+    X86_64,
+    /// Optimized AArch64 assembly
+    Aarch64,
+    /// Software implementation using `rug` (GMP) library
+    Software,
+    /// Software implementation in pure Rust
+    Portable,
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Backend::X86_64 => "x86-64",
+            Backend::Aarch64 => "aarch64",
+            Backend::Software => "software",
+            Backend::Portable => "portable",
+        })
+    }
+}
+
+impl Backend {
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Backend::X86_64),
+            1 => Some(Backend::Aarch64),
+            2 => Some(Backend::Software),
+            3 => Some(Backend::Portable),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the fastest backend supported by current CPU, CPU features are only checked on the
+/// first call
+pub fn detect() -> Backend {
+    if let Some(backend) =
+        Backend::from_u8(DETECTED_BACKEND.load(Ordering::Relaxed).wrapping_sub(1))
+    {
+        return backend;
+    }
+
+    let backend = detect_uncached();
+    // Detection is deterministic, so concurrent callers will store the same value
+    DETECTED_BACKEND.store(backend as u8 + 1, Ordering::Relaxed);
+    backend
+}
+
+fn detect_uncached() -> Backend {
+    #[cfg(target_arch = "x86_64")]
+    if crate::x86_64::Sloth::is_supported() {
+        return Backend::X86_64;
+    }
+
+    // Instructions used by AArch64 implementation are part of base ARMv8-A, so it is always
+    // supported
+    if cfg!(target_arch = "aarch64") {
+        Backend::Aarch64
+    } else {
+        // Portable implementation uses specialized arithmetic for this prime and is faster than
+        // GMP, which is only available with explicit `Sloth::try_with_backend()`
+        Backend::Portable
+    }
+}
+
+/// Vectorized implementation used together with x86-64 backend, see [`Sloth::vectorization()`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Vectorization {
+    /// AVX-512 IFMA, 8 lanes
+    Avx512Ifma,
+    /// AVX2, 4 lanes
+    Avx2,
+}
+
+impl fmt::Display for Vectorization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Vectorization::Avx512Ifma => "avx512ifma",
+            Vectorization::Avx2 => "avx2",
+        })
+    }
+}
+
+/// Returns the fastest vectorized implementation supported by current CPU, `None` if there is none
+/// or if current architecture is not x86-64
+pub fn detect_vectorization() -> Option<Vectorization> {
+    #[cfg(target_arch = "x86_64")]
+    if crate::x86_64_ifma::Sloth::is_supported() {
+        return Some(Vectorization::Avx512Ifma);
+    } else if crate::x86_64_avx2::Sloth::is_supported() {
+        return Some(Vectorization::Avx2);
+    }
+
+    None
+}
+
+/// Implementation that encodes multiple pieces (and inverts multiple blocks of a layer when
+/// decoding) at the same time, used together with x86-64 backend
+#[cfg(target_arch = "x86_64")]
+#[derive(Debug, Clone)]
+enum Lanes {
+    Avx512Ifma(crate::x86_64_ifma::Sloth),
+    Avx2(crate::x86_64_avx2::Sloth),
+}
+
+#[cfg(target_arch = "x86_64")]
+impl Lanes {
+    fn new(vectorization: Vectorization) -> Option<Self> {
+        match vectorization {
+            Vectorization::Avx512Ifma if crate::x86_64_ifma::Sloth::is_supported() => {
+                Some(Lanes::Avx512Ifma(crate::x86_64_ifma::Sloth::new()))
+            }
+            Vectorization::Avx2 if crate::x86_64_avx2::Sloth::is_supported() => {
+                Some(Lanes::Avx2(crate::x86_64_avx2::Sloth::new()))
+            }
+            _ => None,
+        }
+    }
+
+    fn vectorization(&self) -> Vectorization {
+        match self {
+            Lanes::Avx512Ifma(_) => Vectorization::Avx512Ifma,
+            Lanes::Avx2(_) => Vectorization::Avx2,
+        }
+    }
+
+    fn lanes(&self) -> usize {
+        match self {
+            Lanes::Avx512Ifma(_) => crate::x86_64_ifma::LANES,
+            Lanes::Avx2(_) => crate::x86_64_avx2::LANES,
+        }
+    }
+
+    /// Smallest number of pieces for which encoding them at the same time is faster than encoding
+    /// them one by one with x86-64 backend
+    fn min_pieces(&self) -> usize {
+        match self {
+            Lanes::Avx512Ifma(_) => 2,
+            Lanes::Avx2(_) => crate::x86_64_avx2::LANES,
+        }
+    }
+
+    fn encode(
+        &self,
+        pieces: &mut [[u8; 4096]],
+        expanded_ivs: &[ExpandedIv<32>],
+        layers: usize,
+    ) -> Result<(), EncodeError> {
+        let expanded_ivs = ExpandedIv::slice_as_bytes(expanded_ivs);
+        match self {
+            Lanes::Avx512Ifma(sloth) => sloth.encode_up_to_lanes(pieces, expanded_ivs, layers),
+            Lanes::Avx2(sloth) => sloth.encode_up_to_lanes(pieces, expanded_ivs, layers),
+        }
+    }
+
+    fn decode(
+        &self,
+        pieces: &mut [[u8; 4096]],
+        expanded_ivs: &[ExpandedIv<32>],
+        layers: usize,
+    ) -> Result<(), DecodeError> {
+        let expanded_ivs = ExpandedIv::slice_as_bytes(expanded_ivs);
+        match self {
+            Lanes::Avx512Ifma(sloth) => sloth.decode_up_to_lanes(pieces, expanded_ivs, layers),
+            Lanes::Avx2(sloth) => sloth.decode_up_to_lanes(pieces, expanded_ivs, layers),
+        }
+    }
+
+    fn decode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+    ) -> Result<(), DecodeError> {
+        match self {
+            Lanes::Avx512Ifma(sloth) => sloth.decode_slice(piece, expanded_iv, layers),
+            Lanes::Avx2(sloth) => sloth.decode_slice(piece, expanded_iv, layers),
+        }
+    }
+}
+
+// Portable implementation with precomputed addition chain is a few hundred bytes, which doesn't
+// matter for an instance that is created once
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone)]
+enum Inner {
+    // Vectorized implementation is used for encoding multiple pieces at once and for decoding when
+    // supported by CPU
+    #[cfg(target_arch = "x86_64")]
+    X86_64(crate::x86_64::Sloth, Option<Lanes>),
+    #[cfg(target_arch = "aarch64")]
+    Aarch64(crate::aarch64::Sloth),
+    #[cfg(feature = "software")]
+    Software(crate::software::Sloth<32, 4096>),
+    Portable(portable::Sloth<32, 4096>),
+}
+
+/// Piece prepared for encoding, see [`Sloth::prepare_piece()`]
+#[derive(Debug, Clone)]
+pub struct PreparedPiece {
+    piece: Piece<4096>,
+    // Only software implementation benefits from preparation, others convert blocks for free
+    #[cfg(feature = "software")]
+    software: Option<crate::software::PreparedPiece>,
+}
+
+/// Working memory for encoding and decoding, see [`Sloth::encode_with_scratch()`]
+#[derive(Debug, Clone, Default)]
+pub struct Scratch {
+    #[cfg(feature = "software")]
+    software: crate::software::Scratch,
+}
+
+/// SLOTH that dispatches to one of the implementations, see [`Sloth::backend()`] for the one
+/// being used
+#[derive(Debug, Clone)]
+pub struct Sloth {
+    inner: Inner,
+}
+
+impl Sloth {
+    /// Initializes SLOTH using the fastest implementation supported by current CPU
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self::with_backend(detect())
+    }
+
+    /// Initializes SLOTH using specified implementation, returns `None` if it is not available
+    /// (not supported by CPU or not enabled with cargo features)
+    ///
+    /// The fastest vectorized implementation supported by CPU is used together with x86-64
+    /// backend, see [`Sloth::try_with_backend_and_vectorization()`] to pick it explicitly.
+    pub fn try_with_backend(backend: Backend) -> Option<Self> {
+        Self::try_with_backend_and_vectorization(backend, detect_vectorization())
+    }
+
+    /// Same as [`Sloth::try_with_backend()`], but with explicitly specified vectorized
+    /// implementation (`None` to process pieces and blocks one at a time), returns `None` if
+    /// vectorized implementation is not supported by CPU or can't be used with `backend` (only
+    /// x86-64 backend supports vectorization)
+    pub fn try_with_backend_and_vectorization(
+        backend: Backend,
+        vectorization: Option<Vectorization>,
+    ) -> Option<Self> {
+        #[cfg(target_arch = "x86_64")]
+        let lanes = match vectorization {
+            Some(vectorization) if backend == Backend::X86_64 => Some(Lanes::new(vectorization)?),
+            Some(_) => {
+                return None;
+            }
+            None => None,
+        };
+        #[cfg(not(target_arch = "x86_64"))]
+        if vectorization.is_some() {
+            return None;
+        }
+
+        let inner = match backend {
+            #[cfg(target_arch = "x86_64")]
+            Backend::X86_64 if crate::x86_64::Sloth::is_supported() => {
+                Inner::X86_64(crate::x86_64::Sloth::new(), lanes)
+            }
+            #[cfg(target_arch = "aarch64")]
+            Backend::Aarch64 => Inner::Aarch64(crate::aarch64::Sloth::new()),
+            #[cfg(feature = "software")]
+            Backend::Software => {
+                use rug::{integer::Order, Integer};
+
+                Inner::Software(crate::software::Sloth::with_prime(Integer::from_digits(
+                    &PRIME_BYTES,
+                    Order::Lsf,
+                )))
+            }
+            Backend::Portable => Inner::Portable(portable::Sloth::with_prime(PRIME_BYTES)),
+            #[allow(unreachable_patterns)]
+            _ => {
+                return None;
+            }
+        };
+
+        Some(Self { inner })
+    }
+
+    fn with_backend(backend: Backend) -> Self {
+        Self::try_with_backend(backend).expect("Detected backend is always available; qed")
+    }
+
+    /// Implementation that is used by this instance
+    pub fn backend(&self) -> Backend {
+        match self.inner {
+            #[cfg(target_arch = "x86_64")]
+            Inner::X86_64(..) => Backend::X86_64,
+            #[cfg(target_arch = "aarch64")]
+            Inner::Aarch64(_) => Backend::Aarch64,
+            #[cfg(feature = "software")]
+            Inner::Software(_) => Backend::Software,
+            Inner::Portable(_) => Backend::Portable,
+        }
+    }
+
+    /// Vectorized implementation used by this instance for [`Sloth::encode_lanes()`] and for
+    /// decoding (which inverts [`Sloth::lanes()`] blocks of each layer at the same time), `None` if
+    /// pieces and blocks are processed one at a time by [`Sloth::backend()`]
+    pub fn vectorization(&self) -> Option<Vectorization> {
+        match &self.inner {
+            #[cfg(target_arch = "x86_64")]
+            Inner::X86_64(_, Some(lanes)) => Some(lanes.vectorization()),
+            _ => None,
+        }
+    }
+
+    /// Sequentially encodes a 4096 byte piece
+    pub fn encode(
+        &self,
+        piece: &mut [u8; 4096],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+    ) -> Result<(), EncodeError> {
+        self.encode_with_scratch(piece, expanded_iv, layers, &mut Scratch::default())
+    }
+
+    /// Same as [`Sloth::encode()`], but reuses provided scratch
+    pub fn encode_with_scratch(
+        &self,
+        piece: &mut [u8; 4096],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+        #[allow(unused_variables)] scratch: &mut Scratch,
+    ) -> Result<(), EncodeError> {
+        match &self.inner {
+            #[cfg(target_arch = "x86_64")]
+            Inner::X86_64(sloth, _) => sloth.encode(piece, expanded_iv, layers),
+            #[cfg(target_arch = "aarch64")]
+            Inner::Aarch64(sloth) => sloth.encode(piece, expanded_iv, layers),
+            #[cfg(feature = "software")]
+            Inner::Software(sloth) => {
+                sloth.encode_with_scratch(piece, expanded_iv, layers, &mut scratch.software)
+            }
+            Inner::Portable(sloth) => sloth.encode(piece, expanded_iv, layers),
+        }
+    }
+
+    /// Same as [`Sloth::encode_with_scratch()`], but for a piece of any length that is a multiple
+    /// of 32 bytes (at least two blocks)
+    pub fn encode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+        #[allow(unused_variables)] scratch: &mut Scratch,
+    ) -> Result<(), EncodeError> {
+        match &self.inner {
+            #[cfg(target_arch = "x86_64")]
+            Inner::X86_64(sloth, _) => sloth.encode_slice(piece, expanded_iv, layers),
+            #[cfg(target_arch = "aarch64")]
+            Inner::Aarch64(sloth) => sloth.encode_slice(piece, expanded_iv, layers),
+            #[cfg(feature = "software")]
+            Inner::Software(sloth) => {
+                sloth.encode_slice(piece, expanded_iv, layers, &mut scratch.software)
+            }
+            Inner::Portable(sloth) => sloth.encode_slice(piece, expanded_iv, layers),
+        }
+    }
+
+    /// Converts piece into representation of the backend used, such that it can be encoded
+    /// multiple times without conversion
+    pub fn prepare_piece(&self, piece: &Piece<4096>) -> PreparedPiece {
+        PreparedPiece {
+            piece: *piece,
+            #[cfg(feature = "software")]
+            software: match &self.inner {
+                Inner::Software(sloth) => Some(sloth.prepare_piece(piece)),
+                #[allow(unreachable_patterns)]
+                _ => None,
+            },
+        }
+    }
+
+    /// Encodes prepared piece into `encoding`, otherwise the same as
+    /// [`Sloth::encode_with_scratch()`]
+    pub fn encode_prepared(
+        &self,
+        piece: &PreparedPiece,
+        encoding: &mut Encoding<4096>,
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+        scratch: &mut Scratch,
+    ) -> Result<(), EncodeError> {
+        #[cfg(feature = "software")]
+        if let (Inner::Software(sloth), Some(prepared)) = (&self.inner, &piece.software) {
+            return sloth.encode_prepared(
+                prepared,
+                encoding,
+                expanded_iv,
+                layers,
+                &mut scratch.software,
+            );
+        }
+
+        // Piece was prepared by instance with a different backend or backend doesn't need
+        // preparation
+        *encoding.as_bytes_mut() = *piece.piece.as_bytes();
+        self.encode_with_scratch(encoding.as_bytes_mut(), expanded_iv, layers, scratch)
+    }
+
+    /// Number of pieces [`Sloth::encode_lanes()`] encodes at the same time, bigger than `1` when
+    /// CPU supports vectorized encoding of multiple pieces (AVX-512 IFMA or AVX2 on x86-64)
+    pub fn lanes(&self) -> usize {
+        match &self.inner {
+            #[cfg(target_arch = "x86_64")]
+            Inner::X86_64(_, Some(lanes)) => lanes.lanes(),
+            _ => 1,
+        }
+    }
+
+    /// Sequentially encodes multiple 4096 byte pieces, [`Sloth::lanes()`] pieces at the same time,
+    /// each piece is encoded with expanded IV at the same index
+    pub fn encode_lanes(
+        &self,
+        pieces: &mut [[u8; 4096]],
+        expanded_ivs: &[ExpandedIv<32>],
+        layers: usize,
+        scratch: &mut Scratch,
+    ) -> Result<(), EncodeError> {
+        if pieces.len() != expanded_ivs.len() {
+            return Err(EncodeError::LengthMismatch);
+        }
+
+        #[cfg(target_arch = "x86_64")]
+        if let Inner::X86_64(_, Some(lanes)) = &self.inner {
+            let mut pieces = pieces.chunks_mut(lanes.lanes());
+            let mut expanded_ivs = expanded_ivs.chunks(lanes.lanes());
+            for (pieces, expanded_ivs) in pieces.by_ref().zip(expanded_ivs.by_ref()) {
+                if pieces.len() < lanes.min_pieces() {
+                    // Remaining pieces are encoded faster one by one
+                    return pieces.iter_mut().zip(expanded_ivs).try_for_each(
+                        |(piece, &expanded_iv)| {
+                            self.encode_with_scratch(piece, expanded_iv, layers, scratch)
+                        },
+                    );
+                }
+                lanes.encode(pieces, expanded_ivs, layers)?;
+            }
+            return Ok(());
+        }
+
+        pieces
+            .iter_mut()
+            .zip(expanded_ivs)
+            .try_for_each(|(piece, &expanded_iv)| {
+                self.encode_with_scratch(piece, expanded_iv, layers, scratch)
+            })
+    }
+
+    /// Sequentially decodes multiple 4096 byte encodings, squarings of [`Sloth::lanes()`]
+    /// encodings are interleaved, each encoding is decoded with expanded IV at the same index
+    ///
+    /// Encodings that don't fill all lanes are decoded one by one with [`Sloth::decode()`].
+    pub fn decode_lanes(
+        &self,
+        pieces: &mut [[u8; 4096]],
+        expanded_ivs: &[ExpandedIv<32>],
+        layers: usize,
+        scratch: &mut Scratch,
+    ) -> Result<(), DecodeError> {
+        if pieces.len() != expanded_ivs.len() {
+            return Err(DecodeError::LengthMismatch);
+        }
+
+        #[cfg(target_arch = "x86_64")]
+        if let Inner::X86_64(_, Some(lanes)) = &self.inner {
+            let mut pieces = pieces.chunks_exact_mut(lanes.lanes());
+            let mut expanded_ivs = expanded_ivs.chunks_exact(lanes.lanes());
+            for (pieces, expanded_ivs) in pieces.by_ref().zip(expanded_ivs.by_ref()) {
+                lanes.decode(pieces, expanded_ivs, layers)?;
+            }
+            return pieces
+                .into_remainder()
+                .iter_mut()
+                .zip(expanded_ivs.remainder())
+                .try_for_each(|(piece, &expanded_iv)| {
+                    self.decode_with_scratch(piece, expanded_iv, layers, scratch)
+                });
+        }
+
+        pieces
+            .iter_mut()
+            .zip(expanded_ivs)
+            .try_for_each(|(piece, &expanded_iv)| {
+                self.decode_with_scratch(piece, expanded_iv, layers, scratch)
+            })
+    }
+
+    /// Decodes a 4096 byte encoding in time << encode time, see [`Sloth::vectorization()`] for
+    /// implementation used
+    pub fn decode(
+        &self,
+        piece: &mut [u8; 4096],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+    ) -> Result<(), DecodeError> {
+        self.decode_with_scratch(piece, expanded_iv, layers, &mut Scratch::default())
+    }
+
+    /// Same as [`Sloth::decode()`], but reuses provided scratch
+    pub fn decode_with_scratch(
+        &self,
+        piece: &mut [u8; 4096],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+        #[allow(unused_variables)] scratch: &mut Scratch,
+    ) -> Result<(), DecodeError> {
+        match &self.inner {
+            #[cfg(target_arch = "x86_64")]
+            Inner::X86_64(_, Some(lanes)) => lanes.decode_slice(piece, expanded_iv, layers),
+            #[cfg(target_arch = "x86_64")]
+            Inner::X86_64(sloth, None) => sloth.decode(piece, expanded_iv, layers),
+            #[cfg(target_arch = "aarch64")]
+            Inner::Aarch64(sloth) => sloth.decode(piece, expanded_iv, layers),
+            #[cfg(feature = "software")]
+            Inner::Software(sloth) => {
+                sloth.decode_with_scratch(piece, expanded_iv, layers, &mut scratch.software)
+            }
+            Inner::Portable(sloth) => sloth.decode(piece, expanded_iv, layers),
+        }
+    }
+
+    /// Same as [`Sloth::decode_with_scratch()`], but for an encoding of any length that is a
+    /// multiple of 32 bytes (at least two blocks)
+    pub fn decode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+        #[allow(unused_variables)] scratch: &mut Scratch,
+    ) -> Result<(), DecodeError> {
+        match &self.inner {
+            #[cfg(target_arch = "x86_64")]
+            Inner::X86_64(_, Some(lanes)) => lanes.decode_slice(piece, expanded_iv, layers),
+            #[cfg(target_arch = "x86_64")]
+            Inner::X86_64(sloth, None) => sloth.decode_slice(piece, expanded_iv, layers),
+            #[cfg(target_arch = "aarch64")]
+            Inner::Aarch64(sloth) => sloth.decode_slice(piece, expanded_iv, layers),
+            #[cfg(feature = "software")]
+            Inner::Software(sloth) => {
+                sloth.decode_slice(piece, expanded_iv, layers, &mut scratch.software)
+            }
+            Inner::Portable(sloth) => sloth.decode_slice(piece, expanded_iv, layers),
+        }
+    }
+}
+
+impl SlothBackend<32, 4096> for Sloth {
+    type PreparedPiece = PreparedPiece;
+    type Scratch = Scratch;
+
+    fn prime(&self) -> [u8; 32] {
+        // All backends use the same prime
+        PRIME_BYTES
+    }
+
+    fn encode(
+        &self,
+        piece: &mut [u8; 4096],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+    ) -> Result<(), EncodeError> {
+        Sloth::encode(self, piece, expanded_iv, layers)
+    }
+
+    fn decode(
+        &self,
+        piece: &mut [u8; 4096],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+    ) -> Result<(), DecodeError> {
+        Sloth::decode(self, piece, expanded_iv, layers)
+    }
+
+    fn encode_with_scratch(
+        &self,
+        piece: &mut [u8; 4096],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+        scratch: &mut Scratch,
+    ) -> Result<(), EncodeError> {
+        Sloth::encode_with_scratch(self, piece, expanded_iv, layers, scratch)
+    }
+
+    fn decode_with_scratch(
+        &self,
+        piece: &mut [u8; 4096],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+        scratch: &mut Scratch,
+    ) -> Result<(), DecodeError> {
+        Sloth::decode_with_scratch(self, piece, expanded_iv, layers, scratch)
+    }
+
+    fn prepare_piece(&self, piece: &Piece<4096>) -> PreparedPiece {
+        Sloth::prepare_piece(self, piece)
+    }
+
+    fn encode_prepared(
+        &self,
+        piece: &PreparedPiece,
+        encoding: &mut Encoding<4096>,
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+        scratch: &mut Scratch,
+    ) -> Result<(), EncodeError> {
+        Sloth::encode_prepared(self, piece, encoding, expanded_iv, layers, scratch)
+    }
+
+    fn encode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+        scratch: &mut Scratch,
+    ) -> Result<(), EncodeError> {
+        Sloth::encode_slice(self, piece, expanded_iv, layers, scratch)
+    }
+
+    fn lanes(&self) -> usize {
+        Sloth::lanes(self)
+    }
+
+    fn encode_lanes(
+        &self,
+        pieces: &mut [[u8; 4096]],
+        expanded_ivs: &[ExpandedIv<32>],
+        layers: usize,
+        scratch: &mut Scratch,
+    ) -> Result<(), EncodeError> {
+        Sloth::encode_lanes(self, pieces, expanded_ivs, layers, scratch)
+    }
+
+    fn decode_lanes(
+        &self,
+        pieces: &mut [[u8; 4096]],
+        expanded_ivs: &[ExpandedIv<32>],
+        layers: usize,
+        scratch: &mut Scratch,
+    ) -> Result<(), DecodeError> {
+        Sloth::decode_lanes(self, pieces, expanded_ivs, layers, scratch)
+    }
+
+    fn decode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+        scratch: &mut Scratch,
+    ) -> Result<(), DecodeError> {
+        Sloth::decode_slice(self, piece, expanded_iv, layers, scratch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_vectors::KNOWN_PIECE_ENCODING;
+
+    #[test]
+    fn test_detect_is_stable() {
+        assert_eq!(detect(), detect());
+        assert_eq!(Sloth::new().backend(), detect());
+        // GMP is never picked automatically
+        assert_ne!(detect(), Backend::Software);
+    }
+
+    #[test]
+    fn test_known_piece_all_backends() {
+        let expanded_iv = ExpandedIv::from([3u8; 32]);
+        let piece = [5u8; 4096];
+
+        for &backend in &[
+            Backend::X86_64,
+            Backend::Aarch64,
+            Backend::Software,
+            Backend::Portable,
+        ] {
+            let sloth = match Sloth::try_with_backend(backend) {
+                Some(sloth) => sloth,
+                None => {
+                    continue;
+                }
+            };
+            assert_eq!(sloth.backend(), backend);
+
+            let mut encoding = piece;
+            sloth.encode(&mut encoding, expanded_iv, 1).unwrap();
+            assert_eq!(encoding, KNOWN_PIECE_ENCODING, "backend {}", backend);
+            let mut decoding = encoding;
+            sloth.decode(&mut decoding, expanded_iv, 1).unwrap();
+
+            assert_eq!(piece.to_vec(), decoding.to_vec(), "backend {}", backend);
+        }
+    }
+
+    #[test]
+    fn test_all_vectorizations() {
+        let piece = [5u8; 4096];
+        let expanded_iv = ExpandedIv::from([3u8; 32]);
+
+        assert_eq!(Sloth::new().vectorization(), detect_vectorization());
+        for &vectorization in &[
+            None,
+            Some(Vectorization::Avx512Ifma),
+            Some(Vectorization::Avx2),
+        ] {
+            let sloth =
+                match Sloth::try_with_backend_and_vectorization(Backend::X86_64, vectorization) {
+                    Some(sloth) => sloth,
+                    None => {
+                        continue;
+                    }
+                };
+            assert_eq!(sloth.vectorization(), vectorization);
+
+            let mut encodings = [piece; 5];
+            sloth
+                .encode_lanes(
+                    &mut encodings,
+                    &[expanded_iv; 5],
+                    1,
+                    &mut Scratch::default(),
+                )
+                .unwrap();
+            for encoding in encodings.iter() {
+                assert_eq!(encoding.to_vec(), KNOWN_PIECE_ENCODING.to_vec());
+            }
+            let mut decoding = encodings[0];
+            sloth.decode(&mut decoding, expanded_iv, 1).unwrap();
+            assert_eq!(piece.to_vec(), decoding.to_vec());
+            sloth
+                .decode_lanes(
+                    &mut encodings,
+                    &[expanded_iv; 5],
+                    1,
+                    &mut Scratch::default(),
+                )
+                .unwrap();
+            for decoding in encodings.iter() {
+                assert_eq!(piece.to_vec(), decoding.to_vec());
+            }
+        }
+
+        // Vectorization is only supported by x86-64 backend
+        assert!(Sloth::try_with_backend_and_vectorization(
+            Backend::Portable,
+            Some(Vectorization::Avx2)
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_encode_lanes() {
+        let sloth = Sloth::new();
+        assert!(sloth.lanes() >= 1);
+
+        // Fewer pieces than lanes, whole lanes and lanes with a remainder
+        for &count in &[1, 3, 4, 9, 17] {
+            let pieces = (0..count).map(|i| [i as u8; 4096]).collect::<Vec<_>>();
+            let expanded_ivs = (0..count)
+                .map(|i| ExpandedIv::from([i as u8 + 1; 32]))
+                .collect::<Vec<_>>();
+
+            let mut encodings = pieces.clone();
+            sloth
+                .encode_lanes(&mut encodings, &expanded_ivs, 2, &mut Scratch::default())
+                .unwrap();
+
+            for ((piece, &expanded_iv), encoding) in
+                pieces.iter().zip(&expanded_ivs).zip(&encodings)
+            {
+                let mut expected_encoding = *piece;
+                sloth
+                    .encode(&mut expected_encoding, expanded_iv, 2)
+                    .unwrap();
+                assert_eq!(encoding.to_vec(), expected_encoding.to_vec(), "{}", count);
+            }
+
+            sloth
+                .decode_lanes(&mut encodings, &expanded_ivs, 2, &mut Scratch::default())
+                .unwrap();
+            for (piece, decoding) in pieces.iter().zip(&encodings) {
+                assert_eq!(piece.to_vec(), decoding.to_vec(), "{}", count);
+            }
+        }
+
+        let mut pieces = [[0u8; 4096]; 2];
+        assert_eq!(
+            sloth.decode_lanes(
+                &mut pieces,
+                &[ExpandedIv::from([0u8; 32])],
+                1,
+                &mut Scratch::default()
+            ),
+            Err(DecodeError::LengthMismatch)
+        );
+        assert_eq!(
+            sloth.encode_lanes(
+                &mut pieces,
+                &[ExpandedIv::from([0u8; 32])],
+                1,
+                &mut Scratch::default()
+            ),
+            Err(EncodeError::LengthMismatch)
+        );
+        assert_eq!(
+            sloth.encode_lanes(
+                &mut pieces,
+                &[ExpandedIv::from([0u8; 32]); 2],
+                0,
+                &mut Scratch::default()
+            ),
+            Err(EncodeError::ZeroLayers)
+        );
+    }
+}
@@ -0,0 +1,227 @@
+//! Scalar parts of implementations for 256-bit prime that process several independent numbers at
+//! the same time in lanes of vector registers (see `x86_64_ifma` and `x86_64_avx2`)
+//!
+//! Vectorized implementations only compute powers of numbers in all lanes at once, while picking
+//! the square root, conversion of blocks and chaining are done here, one lane at a time.
+
+use crate::prime_256::{
+    self, add_small, bytes_to_limbs, limbs_to_bytes, permute_outside_field, select_root,
+    select_square, xor_assign, Limbs, BLOCKS_PER_PIECE, BLOCK_SIZE_BYTES, PIECE_SIZE_BYTES,
+};
+
+/// Fully reduces `low + top * 2^256` modulo prime, `top` is below `2^4`
+#[inline(always)]
+pub(crate) fn reduce_full(low: &Limbs, top: u64) -> Limbs {
+    // `2^256 = 189 mod prime`, after overflow result is small and can't overflow again
+    let (mut result, overflow) = add_small(low, top * 189);
+    if overflow {
+        result = add_small(&result, 189).0;
+    }
+    prime_256::reduce_full(&result)
+}
+
+/// Applies sqrt permutation to `data` given `root = data^((prime + 1) / 4)` and `square = root^2`
+/// (both fully reduced), same as sqrt permutation of other implementations
+#[inline(always)]
+pub(crate) fn sqrt_permutation_with_root(data: &mut Limbs, root: &Limbs, square: &Limbs) {
+    if !permute_outside_field(data) {
+        *data = select_root(data, root, square);
+    }
+}
+
+/// Inverts sqrt permutation of `data` given `square = data^2` (fully reduced)
+#[inline(always)]
+pub(crate) fn inverse_sqrt_with_square(data: &mut Limbs, square: &Limbs) {
+    if !permute_outside_field(data) {
+        *data = select_square(data, square);
+    }
+}
+
+/// Sequentially encodes up to `LANES` 4096 byte pieces at the same time, `sqrt_permutation` applies
+/// sqrt permutation to blocks of all lanes at once
+///
+/// Lanes without a piece encode zero blocks, results of which are discarded.
+pub(crate) fn encode_pieces<const LANES: usize>(
+    pieces: &mut [[u8; PIECE_SIZE_BYTES]],
+    expanded_ivs: &[[u8; BLOCK_SIZE_BYTES]],
+    layers: usize,
+    sqrt_permutation: impl Fn(&mut [Limbs; LANES]),
+) {
+    debug_assert!(pieces.len() <= LANES && pieces.len() == expanded_ivs.len());
+
+    // init feedback as expanded IV
+    let mut feedbacks = [[0u64; 4]; LANES];
+    for (feedback, expanded_iv) in feedbacks.iter_mut().zip(expanded_ivs.iter()) {
+        *feedback = bytes_to_limbs(expanded_iv);
+    }
+
+    // apply the block cipher
+    for _ in 0..layers {
+        for block_index in 0..BLOCKS_PER_PIECE {
+            let offset = block_index * BLOCK_SIZE_BYTES;
+
+            // xor blocks with feedbacks
+            let mut blocks = feedbacks;
+            for (block, piece) in blocks.iter_mut().zip(pieces.iter()) {
+                xor_assign(block, &bytes_to_limbs(&piece[offset..][..BLOCK_SIZE_BYTES]));
+            }
+
+            // apply sqrt permutation
+            sqrt_permutation(&mut blocks);
+
+            for (block, piece) in blocks.iter().zip(pieces.iter_mut()) {
+                limbs_to_bytes(block, &mut piece[offset..][..BLOCK_SIZE_BYTES]);
+            }
+
+            // carry forward the feedbacks
+            feedbacks = blocks;
+        }
+    }
+}
+
+/// Decodes up to `LANES` 4096 byte pieces at the same time, `inverse_sqrt` inverts sqrt permutation
+/// of blocks with the same index of all pieces at once
+///
+/// Lanes without a piece decode zero blocks, results of which are discarded.
+pub(crate) fn decode_pieces<const LANES: usize>(
+    pieces: &mut [[u8; PIECE_SIZE_BYTES]],
+    expanded_ivs: &[[u8; BLOCK_SIZE_BYTES]],
+    layers: usize,
+    inverse_sqrt: impl Fn(&mut [Limbs; LANES]),
+) {
+    debug_assert!(pieces.len() <= LANES && pieces.len() == expanded_ivs.len());
+
+    for layer in 0..layers {
+        // Blocks are inverted starting from the end, such that previous blocks are still encoded
+        let mut last_blocks = [[0u64; 4]; LANES];
+        for block_index in (0..BLOCKS_PER_PIECE).rev() {
+            let offset = block_index * BLOCK_SIZE_BYTES;
+
+            let mut blocks = [[0u64; 4]; LANES];
+            for (block, piece) in blocks.iter_mut().zip(pieces.iter()) {
+                *block = bytes_to_limbs(&piece[offset..][..BLOCK_SIZE_BYTES]);
+            }
+
+            inverse_sqrt(&mut blocks);
+
+            for (((block, piece), last_block), expanded_iv) in blocks
+                .iter_mut()
+                .zip(pieces.iter_mut())
+                .zip(last_blocks.iter_mut())
+                .zip(expanded_ivs.iter())
+            {
+                let feedback = if block_index > 0 {
+                    bytes_to_limbs(&piece[offset - BLOCK_SIZE_BYTES..][..BLOCK_SIZE_BYTES])
+                } else if layer != layers - 1 {
+                    // Last block is already decoded, so it can be used as an IV to previous
+                    // iteration
+                    *last_block
+                } else {
+                    // remove the IV (last round)
+                    bytes_to_limbs(expanded_iv)
+                };
+                xor_assign(block, &feedback);
+
+                if block_index == BLOCKS_PER_PIECE - 1 {
+                    *last_block = *block;
+                }
+
+                limbs_to_bytes(block, &mut piece[offset..][..BLOCK_SIZE_BYTES]);
+            }
+        }
+    }
+}
+
+/// Decodes a piece of any length that is a multiple of block size in place, `inverse_sqrt` inverts
+/// sqrt permutation of `LANES` blocks of the same layer at once
+pub(crate) fn decode_piece<const LANES: usize>(
+    piece: &mut [u8],
+    expanded_iv: &[u8; BLOCK_SIZE_BYTES],
+    layers: usize,
+    inverse_sqrt: impl Fn(&mut [Limbs; LANES]),
+) {
+    let blocks = piece.len() / BLOCK_SIZE_BYTES;
+
+    for layer in 0..layers {
+        // Each block only depends on the same and previous blocks of the encoded layer, so blocks
+        // are inverted `LANES` at a time, starting from the end, such that previous blocks are still
+        // encoded
+        let mut last_block = [0u64; 4];
+        let mut chunk_end = blocks;
+        while chunk_end > 0 {
+            let chunk_start = chunk_end.saturating_sub(LANES);
+            let chunk_bytes = &piece[chunk_start * BLOCK_SIZE_BYTES..chunk_end * BLOCK_SIZE_BYTES];
+
+            // Unused lanes of the last chunk are left zero
+            let mut chunk = [[0u64; 4]; LANES];
+            for (block, bytes) in chunk
+                .iter_mut()
+                .zip(chunk_bytes.chunks_exact(BLOCK_SIZE_BYTES))
+            {
+                *block = bytes_to_limbs(bytes);
+            }
+
+            inverse_sqrt(&mut chunk);
+
+            for (index, block) in (chunk_start..chunk_end).zip(chunk.iter_mut()).rev() {
+                let feedback = if index > 0 {
+                    bytes_to_limbs(&piece[(index - 1) * BLOCK_SIZE_BYTES..][..BLOCK_SIZE_BYTES])
+                } else if layer != layers - 1 {
+                    // Last block is already decoded, so it can be used as an IV to previous
+                    // iteration
+                    last_block
+                } else {
+                    // remove the IV (last round)
+                    bytes_to_limbs(expanded_iv)
+                };
+                xor_assign(block, &feedback);
+
+                if index == blocks - 1 {
+                    last_block = *block;
+                }
+            }
+
+            let chunk_bytes =
+                &mut piece[chunk_start * BLOCK_SIZE_BYTES..chunk_end * BLOCK_SIZE_BYTES];
+            for (block, bytes) in chunk
+                .iter()
+                .zip(chunk_bytes.chunks_exact_mut(BLOCK_SIZE_BYTES))
+            {
+                limbs_to_bytes(block, bytes);
+            }
+
+            chunk_end = chunk_start;
+        }
+    }
+}
+
+/// Simple schoolbook multiplication modulo prime used as a reference
+#[cfg(test)]
+pub(crate) fn scalar_mul(a: &Limbs, b: &Limbs) -> Limbs {
+    let mut wide = [0u64; 8];
+    for i in 0..4 {
+        let mut carry = 0u128;
+        for j in 0..4 {
+            let product = a[i] as u128 * b[j] as u128 + wide[i + j] as u128 + carry;
+            wide[i + j] = product as u64;
+            carry = product >> 64;
+        }
+        wide[i + 4] = carry as u64;
+    }
+
+    // Fold upper half multiplied by 189 until number fits into 256 bits
+    let mut low = [wide[0], wide[1], wide[2], wide[3]];
+    let mut high = [wide[4], wide[5], wide[6], wide[7]];
+    while high != [0u64; 4] {
+        let mut carry = 0u128;
+        let mut next_high = [0u64; 4];
+        for i in 0..4 {
+            let sum = low[i] as u128 + high[i] as u128 * 189 + carry;
+            low[i] = sum as u64;
+            carry = sum >> 64;
+        }
+        next_high[0] = carry as u64;
+        high = next_high;
+    }
+    reduce_full(&low, 0)
+}
@@ -0,0 +1,234 @@
+//! Encoding one layer at a time using any other implementation, which allows to change direction
+//! of chaining between layers (see [`Chaining::Alternating`]) and to apply tweaks before each layer
+//! (see [`SlothBackend::encode_tweaked()`])
+//!
+//! The first block of each layer after the first one is chained with the last block in order of
+//! processing (before tweaks are applied), which is passed to the underlying implementation as
+//! expanded IV. Tweaks are applied to blocks at their positions in the piece regardless of order of
+//! processing.
+
+use crate::{Chaining, DecodeError, EncodeError, ExpandedIv, SlothBackend};
+use core::convert::TryInto;
+
+/// Reverses order of blocks in a piece, bytes within blocks stay the same
+fn reverse_blocks(piece: &mut [u8], block_size_bytes: usize) {
+    let blocks = piece.len() / block_size_bytes;
+    for i in 0..blocks / 2 {
+        let (left, right) = piece.split_at_mut((blocks - i - 1) * block_size_bytes);
+        left[i * block_size_bytes..][..block_size_bytes]
+            .swap_with_slice(&mut right[..block_size_bytes]);
+    }
+}
+
+/// Block of a piece at `index`, used as feedback of the first block of the next layer
+fn block<const PRIME_SIZE_BYTES: usize>(
+    piece: &[u8],
+    index: usize,
+) -> ExpandedIv<PRIME_SIZE_BYTES> {
+    let block: [u8; PRIME_SIZE_BYTES] = piece[index * PRIME_SIZE_BYTES..][..PRIME_SIZE_BYTES]
+        .try_into()
+        .expect("Piece consists of whole blocks; qed");
+    block.into()
+}
+
+fn xor(target: &mut [u8], source: &[u8]) {
+    target
+        .iter_mut()
+        .zip(source)
+        .for_each(|(byte, source)| *byte ^= source);
+}
+
+pub(crate) fn encode<Sloth, const PRIME_SIZE_BYTES: usize, const PIECE_SIZE_BYTES: usize>(
+    sloth: &Sloth,
+    piece: &mut [u8],
+    expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+    layers: usize,
+    chaining: Chaining,
+    tweaks: &dyn Fn(usize, &mut [u8]),
+    scratch: &mut Sloth::Scratch,
+) -> Result<(), EncodeError>
+where
+    Sloth: SlothBackend<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES> + ?Sized,
+{
+    if layers == 0 {
+        return Err(EncodeError::ZeroLayers);
+    }
+    if !crate::is_valid_length(piece.len(), PRIME_SIZE_BYTES) {
+        return Err(EncodeError::InvalidLength);
+    }
+    let last_block = piece.len() / PRIME_SIZE_BYTES - 1;
+
+    for layer in 0..layers {
+        let reverse = chaining == Chaining::Alternating && layer % 2 == 1;
+
+        let feedback = if layer == 0 {
+            expanded_iv
+        } else if reverse {
+            block(piece, 0)
+        } else {
+            block(piece, last_block)
+        };
+        tweaks(layer, piece);
+
+        if reverse {
+            reverse_blocks(piece, PRIME_SIZE_BYTES);
+        }
+        sloth.encode_slice(piece, feedback, 1, scratch)?;
+        if reverse {
+            reverse_blocks(piece, PRIME_SIZE_BYTES);
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) fn decode<Sloth, const PRIME_SIZE_BYTES: usize, const PIECE_SIZE_BYTES: usize>(
+    sloth: &Sloth,
+    piece: &mut [u8],
+    expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+    layers: usize,
+    chaining: Chaining,
+    tweaks: &dyn Fn(usize, &mut [u8]),
+    scratch: &mut Sloth::Scratch,
+) -> Result<(), DecodeError>
+where
+    Sloth: SlothBackend<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES> + ?Sized,
+{
+    if layers == 0 {
+        return Err(DecodeError::ZeroLayers);
+    }
+    if !crate::is_valid_length(piece.len(), PRIME_SIZE_BYTES) {
+        return Err(DecodeError::InvalidLength);
+    }
+
+    for layer in (0..layers).rev() {
+        let reverse = chaining == Chaining::Alternating && layer % 2 == 1;
+
+        // Feedback of the first block is only known once the rest of the layer is decoded, so it
+        // is removed afterwards
+        let feedback = if layer == 0 {
+            expanded_iv
+        } else {
+            [0u8; PRIME_SIZE_BYTES].into()
+        };
+
+        if reverse {
+            reverse_blocks(piece, PRIME_SIZE_BYTES);
+        }
+        sloth.decode_slice(piece, feedback, 1, scratch)?;
+        if reverse {
+            reverse_blocks(piece, PRIME_SIZE_BYTES);
+        }
+
+        tweaks(layer, piece);
+
+        if layer != 0 {
+            if reverse {
+                let (rest, last_block) = piece.split_at_mut(piece.len() - PRIME_SIZE_BYTES);
+                xor(last_block, &rest[..PRIME_SIZE_BYTES]);
+            } else {
+                let (first_block, rest) = piece.split_at_mut(PRIME_SIZE_BYTES);
+                xor(first_block, &rest[rest.len() - PRIME_SIZE_BYTES..]);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "portable"))]
+mod tests {
+    use super::*;
+    use crate::prime_256::PRIME_BYTES;
+    use rand::prelude::*;
+
+    type PortableSloth = crate::portable::Sloth<32, 4096>;
+
+    fn random_bytes<const BYTES: usize>() -> [u8; BYTES] {
+        let mut bytes = [0u8; BYTES];
+        rand::thread_rng().fill(&mut bytes[..]);
+        bytes
+    }
+
+    fn tweaks(layer: usize, piece: &mut [u8]) {
+        for (index, block) in piece.chunks_exact_mut(32).enumerate() {
+            block[0] ^= layer as u8;
+            block[1] ^= index as u8;
+        }
+    }
+
+    #[test]
+    fn test_without_tweaks_matches_cbc() {
+        let sloth = PortableSloth::with_prime(PRIME_BYTES);
+        let expanded_iv = ExpandedIv::from(random_bytes());
+        let piece = random_bytes::<4096>();
+
+        let mut encoding = piece;
+        sloth.encode(&mut encoding, expanded_iv, 3).unwrap();
+        let mut layered_encoding = piece;
+        sloth
+            .encode_tweaked(&mut layered_encoding, expanded_iv, 3, &|_, _| {}, &mut ())
+            .unwrap();
+        assert_eq!(layered_encoding, encoding);
+
+        sloth
+            .decode_tweaked(&mut layered_encoding, expanded_iv, 3, &|_, _| {}, &mut ())
+            .unwrap();
+        assert_eq!(layered_encoding, piece);
+    }
+
+    #[test]
+    fn test_tweaks() {
+        let sloth = PortableSloth::with_prime(PRIME_BYTES);
+        let expanded_iv = ExpandedIv::from(random_bytes());
+        let piece = random_bytes::<4096>();
+
+        // Tweaks of the first layer are XORed into piece before encoding
+        let mut encoding = piece;
+        tweaks(0, &mut encoding);
+        sloth.encode(&mut encoding, expanded_iv, 1).unwrap();
+        let mut tweaked_encoding = piece;
+        sloth
+            .encode_tweaked(&mut tweaked_encoding, expanded_iv, 1, &tweaks, &mut ())
+            .unwrap();
+        assert_eq!(tweaked_encoding, encoding);
+
+        for &chaining in &[Chaining::Cbc, Chaining::Alternating] {
+            let mut encoding = piece;
+            encode(
+                &sloth,
+                &mut encoding,
+                expanded_iv,
+                3,
+                chaining,
+                &|_, _| {},
+                &mut (),
+            )
+            .unwrap();
+            let mut tweaked_encoding = piece;
+            encode(
+                &sloth,
+                &mut tweaked_encoding,
+                expanded_iv,
+                3,
+                chaining,
+                &tweaks,
+                &mut (),
+            )
+            .unwrap();
+            assert_ne!(tweaked_encoding, encoding);
+
+            decode(
+                &sloth,
+                &mut tweaked_encoding,
+                expanded_iv,
+                3,
+                chaining,
+                &tweaks,
+                &mut (),
+            )
+            .unwrap();
+            assert_eq!(tweaked_encoding, piece);
+        }
+    }
+}
@@ -1,5 +1,475 @@
+//! Encoder/decoder based on the SLOTH permutation
+//!
+//! Square root permutation is only defined for numbers below prime, so in order for any block of
+//! data to be encodable it is extended to all numbers that fit into the block, see
+//! [`BlockMapping`] for how. Since there are very few such numbers, encoding of random data is not
+//! affected in practice.
+//!
+//! Crate is `no_std` compatible when `std` feature is disabled, in which case only `portable` and
+//! `aarch64` implementations are available.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+use core::fmt;
+
+#[cfg(target_arch = "aarch64")]
+pub mod aarch64;
+#[cfg(any(feature = "portable", feature = "software"))]
+mod addition_chain;
+pub mod alternating;
+#[cfg(all(feature = "portable", feature = "std"))]
+pub mod auto;
+#[cfg(all(target_arch = "x86_64", feature = "std"))]
+mod lanes;
+mod layers;
+#[cfg(feature = "software")]
+pub mod multi_prime;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+#[cfg(feature = "portable")]
+pub mod portable;
+#[cfg(any(
+    feature = "portable",
+    feature = "software",
+    all(target_arch = "x86_64", feature = "std"),
+    target_arch = "aarch64"
+))]
+mod prime_256;
+#[cfg(any(feature = "portable", feature = "software"))]
+mod pseudo_mersenne;
 #[cfg(feature = "software")]
 pub mod software;
-
-#[cfg(target_arch = "x86_64")]
+// Runtime CPU feature detection requires `std`
+#[cfg(all(target_arch = "x86_64", feature = "std"))]
 pub mod x86_64;
+#[cfg(all(target_arch = "x86_64", feature = "std"))]
+pub mod x86_64_avx2;
+#[cfg(all(target_arch = "x86_64", feature = "std"))]
+pub mod x86_64_ifma;
+
+#[cfg(test)]
+mod test_vectors;
+mod types;
+
+pub use types::{Encoding, EncodingKeyHash, ExpandedIv, FromHexError, Piece};
+
+/// Errors that can happen when SLOTH is initialized with invalid prime
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ParametersError {
+    /// Prime doesn't fit into `PRIME_SIZE_BYTES`
+    PrimeTooBig,
+    /// Prime is not `3 mod 4`, which is required for square root to be computed as
+    /// `x^((p + 1) / 4)`
+    PrimeNotThreeModFour,
+    /// Number is not prime
+    NotPrime,
+    /// Number of primes doesn't match number of blocks in a piece
+    PrimeCountMismatch,
+}
+
+impl fmt::Display for ParametersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParametersError::PrimeTooBig => write!(f, "Prime doesn't fit into block size"),
+            ParametersError::PrimeNotThreeModFour => write!(f, "Prime is not 3 mod 4"),
+            ParametersError::NotPrime => write!(f, "Number is not prime"),
+            ParametersError::PrimeCountMismatch => {
+                write!(f, "Number of primes doesn't match number of blocks")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParametersError {}
+
+/// Whether piece of `length` bytes consists of whole blocks, at least two of them (with a single
+/// block, the block itself would be feedback for the next layer, which makes encoding irreversible)
+#[cfg(any(
+    feature = "portable",
+    feature = "software",
+    all(target_arch = "x86_64", feature = "std"),
+    target_arch = "aarch64"
+))]
+const fn is_valid_length(length: usize, block_size_bytes: usize) -> bool {
+    length >= 2 * block_size_bytes && length.is_multiple_of(block_size_bytes)
+}
+
+/// Checks sizes of prime and piece, used in constant context to reject invalid const generic
+/// parameters at compile time
+#[cfg(any(feature = "portable", feature = "software"))]
+const fn assert_valid_sizes(prime_size_bytes: usize, piece_size_bytes: usize) {
+    assert!(prime_size_bytes > 0, "Prime size must not be zero");
+    assert!(
+        is_valid_length(piece_size_bytes, prime_size_bytes),
+        "Piece size must be a multiple of prime size, at least two blocks"
+    );
+}
+
+/// Largest prime `p = 3 mod 4` that fits into `PRIME_SIZE_BYTES` as little-endian bytes (the same
+/// as `software::largest_prime()` computes), known for 256, 512, 1024, 2048 and 4096-bit primes
+pub fn largest_known_prime<const PRIME_SIZE_BYTES: usize>() -> Option<[u8; PRIME_SIZE_BYTES]> {
+    // Largest prime is `2^(PRIME_SIZE_BYTES * 8) - offset`
+    let offset: u16 = match PRIME_SIZE_BYTES {
+        32 => 189,
+        64 => 569,
+        128 => 105,
+        256 => 1557,
+        512 => 2549,
+        _ => {
+            return None;
+        }
+    };
+    let mut prime = [0xffu8; PRIME_SIZE_BYTES];
+    // `2^bits - offset = (2^bits - 1) - (offset - 1)`, no borrow since all bytes are `0xff`
+    let subtrahend = (offset - 1).to_le_bytes();
+    prime[0] -= subtrahend[0];
+    prime[1] -= subtrahend[1];
+    Some(prime)
+}
+
+/// Order in which blocks of each layer are chained
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Chaining {
+    /// CBC from the first block to the last one in every layer, the first block of each layer
+    /// after the first one is chained with the last block of the previous layer
+    #[default]
+    Cbc,
+    /// Same as [`Chaining::Cbc`], but blocks of odd layers are processed from the last block to
+    /// the first one (chained with the first block of the previous layer), see
+    /// [`alternating::Sloth`]
+    ///
+    /// With CBC a block only depends on preceding blocks of the same layer, with alternating
+    /// direction every block depends on every block of the piece after two layers.
+    Alternating,
+}
+
+/// How blocks that square root permutation is not defined for (zero and numbers that are not
+/// smaller than prime) are mapped, such that any block can be encoded and decoding inverts it
+/// exactly
+///
+/// Implementations with a configurable prime (`portable`, `software` and `multi_prime`) accept
+/// any mapping, implementations specialized for 256-bit prime only use [`BlockMapping::Swap`].
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum BlockMapping {
+    /// Prime is mapped to zero and zero to prime, numbers bigger than prime are left as is
+    #[default]
+    Swap,
+    /// Zero, prime and numbers bigger than prime are rotated: zero is mapped to prime, every other
+    /// of them to the next number and the largest number that fits into a block to zero, such that
+    /// none of them (for instance, a block of `0xff` bytes) is left as is
+    Rotate,
+}
+
+/// Errors that can happen during encoding
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum EncodeError {
+    /// Number of layers is zero
+    ZeroLayers,
+    /// Number of pieces doesn't match number of expanded IVs (or nonces) in batch encoding
+    LengthMismatch,
+    /// Piece length is not a multiple of block size, is shorter than two blocks or doesn't match
+    /// encoding length
+    InvalidLength,
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::ZeroLayers => write!(f, "Number of layers must not be zero"),
+            EncodeError::LengthMismatch => {
+                write!(f, "Number of pieces doesn't match number of expanded IVs")
+            }
+            EncodeError::InvalidLength => write!(f, "Invalid piece length"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EncodeError {}
+
+/// Errors that can happen during decoding
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DecodeError {
+    /// Number of layers is zero
+    ZeroLayers,
+    /// Number of encodings doesn't match number of expanded IVs in batch decoding
+    LengthMismatch,
+    /// Encoding length is not a multiple of block size, is shorter than two blocks or doesn't match
+    /// piece length
+    InvalidLength,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::ZeroLayers => write!(f, "Number of layers must not be zero"),
+            DecodeError::LengthMismatch => {
+                write!(
+                    f,
+                    "Number of encodings doesn't match number of expanded IVs"
+                )
+            }
+            DecodeError::InvalidLength => write!(f, "Invalid encoding length"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+/// Common interface implemented by all SLOTH implementations, allows to write code that is generic
+/// over implementation used
+///
+/// `PRIME_SIZE_BYTES` is the size of prime (and block), `PIECE_SIZE_BYTES` is the size of the piece
+/// being encoded, it consists of `PIECE_SIZE_BYTES / PRIME_SIZE_BYTES` blocks.
+pub trait SlothBackend<const PRIME_SIZE_BYTES: usize, const PIECE_SIZE_BYTES: usize> {
+    /// Piece converted into internal representation of the implementation, see
+    /// [`SlothBackend::prepare_piece()`]
+    type PreparedPiece: Clone + fmt::Debug + Send + Sync;
+    /// Reusable working memory of the implementation, once allocated by the first call, encoding
+    /// and decoding with the same scratch doesn't allocate on the heap
+    type Scratch: Default + fmt::Debug + Send;
+
+    /// Prime used by the implementation as little-endian bytes
+    ///
+    /// Implementations with a distinct prime per block (see `multi_prime::Sloth`) return the
+    /// prime of the first block, which is the only one that expanded IV is chained with.
+    fn prime(&self) -> [u8; PRIME_SIZE_BYTES];
+
+    /// Order in which blocks of each layer are chained
+    fn chaining(&self) -> Chaining {
+        Chaining::Cbc
+    }
+
+    /// Mapping of blocks that square root permutation is not defined for
+    fn block_mapping(&self) -> BlockMapping {
+        BlockMapping::Swap
+    }
+
+    /// Sequentially encodes a piece s.t. a minimum amount of wall clock time elapses
+    fn encode(
+        &self,
+        piece: &mut [u8; PIECE_SIZE_BYTES],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+    ) -> Result<(), EncodeError>;
+
+    /// Sequentially decodes an encoding in time << encode time
+    fn decode(
+        &self,
+        piece: &mut [u8; PIECE_SIZE_BYTES],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+    ) -> Result<(), DecodeError>;
+
+    /// Same as [`SlothBackend::encode()`], but reuses provided scratch instead of allocating
+    /// working memory
+    fn encode_with_scratch(
+        &self,
+        piece: &mut [u8; PIECE_SIZE_BYTES],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        scratch: &mut Self::Scratch,
+    ) -> Result<(), EncodeError>;
+
+    /// Same as [`SlothBackend::decode()`], but reuses provided scratch instead of allocating
+    /// working memory
+    fn decode_with_scratch(
+        &self,
+        piece: &mut [u8; PIECE_SIZE_BYTES],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        scratch: &mut Self::Scratch,
+    ) -> Result<(), DecodeError>;
+
+    /// Converts piece into internal representation once, so that it can be encoded many times
+    /// (with different IVs) without converting it again
+    fn prepare_piece(&self, piece: &Piece<PIECE_SIZE_BYTES>) -> Self::PreparedPiece;
+
+    /// Encodes prepared piece into `encoding`, otherwise the same as
+    /// [`SlothBackend::encode_with_scratch()`]
+    fn encode_prepared(
+        &self,
+        piece: &Self::PreparedPiece,
+        encoding: &mut Encoding<PIECE_SIZE_BYTES>,
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        scratch: &mut Self::Scratch,
+    ) -> Result<(), EncodeError>;
+
+    /// Same as [`SlothBackend::encode_with_scratch()`], but for a piece of any length that is a
+    /// multiple of `PRIME_SIZE_BYTES` (at least two blocks), for instance, a large piece allocated
+    /// on the heap in `Vec<u8>`
+    fn encode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        scratch: &mut Self::Scratch,
+    ) -> Result<(), EncodeError>;
+
+    /// Same as [`SlothBackend::decode_with_scratch()`], but for an encoding of any length that is a
+    /// multiple of `PRIME_SIZE_BYTES` (at least two blocks)
+    fn decode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        scratch: &mut Self::Scratch,
+    ) -> Result<(), DecodeError>;
+
+    /// Encodes `piece` into `encoding` of the same length, leaving `piece` unchanged, otherwise the
+    /// same as [`SlothBackend::encode_slice()`]
+    fn encode_into(
+        &self,
+        piece: &[u8],
+        encoding: &mut [u8],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        scratch: &mut Self::Scratch,
+    ) -> Result<(), EncodeError> {
+        if piece.len() != encoding.len() {
+            return Err(EncodeError::InvalidLength);
+        }
+
+        encoding.copy_from_slice(piece);
+        self.encode_slice(encoding, expanded_iv, layers, scratch)
+    }
+
+    /// Decodes `encoding` into `piece` of the same length, leaving `encoding` unchanged, otherwise
+    /// the same as [`SlothBackend::decode_slice()`]
+    fn decode_into(
+        &self,
+        encoding: &[u8],
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        scratch: &mut Self::Scratch,
+    ) -> Result<(), DecodeError> {
+        if encoding.len() != piece.len() {
+            return Err(DecodeError::InvalidLength);
+        }
+
+        piece.copy_from_slice(encoding);
+        self.decode_slice(piece, expanded_iv, layers, scratch)
+    }
+
+    /// Same as [`SlothBackend::encode_slice()`], but before each layer `tweaks` is called with index
+    /// of the layer and XORs tweaks into blocks of the piece (for instance, derived from encoding
+    /// key), such that tweaks are XORed into blocks together with feedback before square root
+    /// permutation
+    ///
+    /// Layers are encoded one at a time, respecting [`SlothBackend::chaining()`].
+    fn encode_tweaked(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        tweaks: &dyn Fn(usize, &mut [u8]),
+        scratch: &mut Self::Scratch,
+    ) -> Result<(), EncodeError> {
+        layers::encode(
+            self,
+            piece,
+            expanded_iv,
+            layers,
+            self.chaining(),
+            tweaks,
+            scratch,
+        )
+    }
+
+    /// Decodes encoding created with [`SlothBackend::encode_tweaked()`], `tweaks` is called the
+    /// same way after each layer is decoded to remove tweaks
+    fn decode_tweaked(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        tweaks: &dyn Fn(usize, &mut [u8]),
+        scratch: &mut Self::Scratch,
+    ) -> Result<(), DecodeError> {
+        layers::decode(
+            self,
+            piece,
+            expanded_iv,
+            layers,
+            self.chaining(),
+            tweaks,
+            scratch,
+        )
+    }
+
+    /// Number of pieces [`SlothBackend::encode_lanes()`] encodes at the same time on one CPU core
+    /// (for instance, in lanes of vector registers), `1` for implementations that encode one piece
+    /// at a time
+    fn lanes(&self) -> usize {
+        1
+    }
+
+    /// Sequentially encodes multiple pieces on one CPU core, each piece is encoded with expanded IV
+    /// at the same index
+    ///
+    /// Implementations with [`SlothBackend::lanes()`] bigger than `1` encode that many pieces at
+    /// the same time, others encode pieces one after another, otherwise the same as
+    /// [`SlothBackend::encode_with_scratch()`].
+    fn encode_lanes(
+        &self,
+        pieces: &mut [[u8; PIECE_SIZE_BYTES]],
+        expanded_ivs: &[ExpandedIv<PRIME_SIZE_BYTES>],
+        layers: usize,
+        scratch: &mut Self::Scratch,
+    ) -> Result<(), EncodeError> {
+        if pieces.len() != expanded_ivs.len() {
+            return Err(EncodeError::LengthMismatch);
+        }
+
+        pieces
+            .iter_mut()
+            .zip(expanded_ivs)
+            .try_for_each(|(piece, &expanded_iv)| {
+                self.encode_with_scratch(piece, expanded_iv, layers, scratch)
+            })
+    }
+
+    /// Sequentially decodes multiple encodings on one CPU core, each encoding is decoded with
+    /// expanded IV at the same index
+    ///
+    /// Implementations with [`SlothBackend::lanes()`] bigger than `1` interleave squarings of that
+    /// many encodings, others decode encodings one after another, otherwise the same as
+    /// [`SlothBackend::decode_with_scratch()`].
+    fn decode_lanes(
+        &self,
+        pieces: &mut [[u8; PIECE_SIZE_BYTES]],
+        expanded_ivs: &[ExpandedIv<PRIME_SIZE_BYTES>],
+        layers: usize,
+        scratch: &mut Self::Scratch,
+    ) -> Result<(), DecodeError> {
+        if pieces.len() != expanded_ivs.len() {
+            return Err(DecodeError::LengthMismatch);
+        }
+
+        pieces
+            .iter_mut()
+            .zip(expanded_ivs)
+            .try_for_each(|(piece, &expanded_iv)| {
+                self.decode_with_scratch(piece, expanded_iv, layers, scratch)
+            })
+    }
+
+    /// Encodes multiple pieces in parallel, each piece is encoded with expanded IV at the same
+    /// index, see [`parallel::ParallelOptions`] for controlling thread pool and chunking
+    #[cfg(feature = "parallel")]
+    fn encode_many(
+        &self,
+        pieces: &mut [[u8; PIECE_SIZE_BYTES]],
+        expanded_ivs: &[ExpandedIv<PRIME_SIZE_BYTES>],
+        layers: usize,
+        options: parallel::ParallelOptions<'_>,
+    ) -> Result<(), EncodeError>
+    where
+        Self: Sync,
+    {
+        parallel::encode_many(self, pieces, expanded_ivs, layers, options)
+    }
+}
@@ -0,0 +1,551 @@
+//! Implementation of Sloth that uses a distinct prime for every block of a piece for additional
+//! ASIC resistance, see [`derive_primes()`] for how primes are chosen
+//!
+//! Arithmetic is done with `rug` (GMP) library, the same way as in [`software`]
+//! implementation.
+use crate::software::{
+    self, piece_to_block_and_feedback, piece_to_first_block_and_feedback, read_blocks,
+    write_blocks, PreparedPiece, Scratch,
+};
+use crate::{
+    BlockMapping, DecodeError, EncodeError, Encoding, ExpandedIv, ParametersError, Piece,
+    SlothBackend,
+};
+use rug::{integer::Order, ops::BitXorFrom, Assign, Integer};
+use sha2::{Digest, Sha256};
+
+/// Hashed together with seed and block index into offset of each prime in [`derive_primes()`], so
+/// the table for the same seed changes with it
+const DOMAIN_SEPARATION_TAG: &[u8] = b"spartan-sloth/block-primes/v1";
+
+/// Derives `count` distinct primes `p = 3 mod 4` of `prime_size_bytes` from `seed`, one for each
+/// block index
+///
+/// Prime at index `i` is the largest prime `p = 3 mod 4` below `2^(prime_size_bytes * 8) - 1 -
+/// offset` that is not used by smaller indices, where `offset` is the first 8 bytes (little-endian)
+/// of SHA-256 of domain separation tag, seed and `i` as little-endian `u64`. All primes are
+/// therefore within `2^64` (plus prime gap) of the largest number that fits into a block, such that
+/// the share of blocks bigger than prime is negligible, the same way as for
+/// [`software::largest_prime()`].
+pub fn derive_primes(seed: &[u8; 32], prime_size_bytes: u32, count: usize) -> Vec<Integer> {
+    let max: Integer = Integer::from(Integer::u_pow_u(2, prime_size_bytes * 8)) - 1;
+
+    let mut primes = Vec::<Integer>::with_capacity(count);
+    for index in 0..count {
+        let hash = Sha256::new()
+            .chain_update(DOMAIN_SEPARATION_TAG)
+            .chain_update(seed)
+            .chain_update((index as u64).to_le_bytes())
+            .finalize();
+        let mut offset = [0u8; 8];
+        offset.copy_from_slice(&hash[..8]);
+
+        let mut prime = max.clone() - u64::from_le_bytes(offset);
+        software::prev_prime(&mut prime);
+        while prime.mod_u(4) != 3 || primes.contains(&prime) {
+            software::prev_prime(&mut prime);
+        }
+        primes.push(prime);
+    }
+
+    primes
+}
+
+/// SLOTH with a distinct prime `p = 3 mod 4` (and precomputed exponent) for every block index of
+/// a piece, such that hardware has to support modular square roots for many primes rather than a
+/// single one
+#[derive(Debug, Clone)]
+pub struct Sloth<const PRIME_SIZE_BYTES: usize, const PIECE_SIZE_BYTES: usize> {
+    /// SLOTH with prime and precomputed exponent for every block index
+    blocks: Vec<software::Sloth<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>>,
+}
+
+impl<const PRIME_SIZE_BYTES: usize, const PIECE_SIZE_BYTES: usize>
+    Sloth<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>
+{
+    /// Initializes SLOTH with primes derived from `seed` with [`derive_primes()`] and computes
+    /// exponents
+    pub fn with_seed(seed: &[u8; 32]) -> Self {
+        let primes = derive_primes(
+            seed,
+            PRIME_SIZE_BYTES as u32,
+            PIECE_SIZE_BYTES / PRIME_SIZE_BYTES,
+        );
+        Self::try_with_primes(primes).expect("Derived primes are valid; qed")
+    }
+
+    /// Initializes SLOTH with explicitly provided primes (one for each block index) and computes
+    /// exponents, returns an error if number of primes doesn't match number of blocks in a piece
+    /// or any of primes is invalid (see [`software::Sloth::try_with_prime()`])
+    ///
+    /// Blocks of pieces longer than `PIECE_SIZE_BYTES` (see [`Sloth::encode_slice()`]) reuse
+    /// primes from the start of the table.
+    pub fn try_with_primes(primes: Vec<Integer>) -> Result<Self, ParametersError> {
+        if primes.len() != PIECE_SIZE_BYTES / PRIME_SIZE_BYTES {
+            return Err(ParametersError::PrimeCountMismatch);
+        }
+
+        let blocks = primes
+            .into_iter()
+            .map(software::Sloth::try_with_prime)
+            .collect::<Result<_, _>>()?;
+        Ok(Self { blocks })
+    }
+
+    /// Use provided mapping of blocks that square root permutation is not defined for instead of
+    /// [`BlockMapping::Swap`], the same for all primes
+    pub fn with_block_mapping(self, block_mapping: BlockMapping) -> Self {
+        Self {
+            blocks: self
+                .blocks
+                .into_iter()
+                .map(|sloth| sloth.with_block_mapping(block_mapping))
+                .collect(),
+        }
+    }
+
+    /// Primes for every block index as little-endian bytes
+    pub fn primes(&self) -> Vec<[u8; PRIME_SIZE_BYTES]> {
+        self.blocks.iter().map(SlothBackend::prime).collect()
+    }
+
+    /// Sequentially encodes a piece s.t. a minimum amount of wall clock time elapses
+    pub fn encode(
+        &self,
+        piece: &mut [u8; PIECE_SIZE_BYTES],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+    ) -> Result<(), EncodeError> {
+        self.encode_with_scratch(piece, expanded_iv, layers, &mut Scratch::default())
+    }
+
+    /// Same as [`Sloth::encode()`], but reuses provided scratch
+    pub fn encode_with_scratch(
+        &self,
+        piece: &mut [u8; PIECE_SIZE_BYTES],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        scratch: &mut Scratch,
+    ) -> Result<(), EncodeError> {
+        self.encode_slice(piece, expanded_iv, layers, scratch)
+    }
+
+    /// Same as [`Sloth::encode_with_scratch()`], but for a piece of any length that is a multiple
+    /// of `PRIME_SIZE_BYTES` (at least two blocks)
+    pub fn encode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        scratch: &mut Scratch,
+    ) -> Result<(), EncodeError> {
+        if layers == 0 {
+            return Err(EncodeError::ZeroLayers);
+        }
+        if !crate::is_valid_length(piece.len(), PRIME_SIZE_BYTES) {
+            return Err(EncodeError::InvalidLength);
+        }
+
+        read_blocks(piece, &mut scratch.blocks, PRIME_SIZE_BYTES);
+        self.encode_blocks(scratch, expanded_iv, layers);
+        write_blocks(&scratch.blocks, piece, PRIME_SIZE_BYTES);
+
+        Ok(())
+    }
+
+    /// Converts piece into GMP big integers, such that it can be encoded multiple times without
+    /// conversion
+    pub fn prepare_piece(&self, piece: &Piece<PIECE_SIZE_BYTES>) -> PreparedPiece {
+        let mut blocks = Vec::new();
+        read_blocks(piece.as_ref(), &mut blocks, PRIME_SIZE_BYTES);
+        PreparedPiece { blocks }
+    }
+
+    /// Encodes prepared piece into `encoding`, otherwise the same as
+    /// [`Sloth::encode_with_scratch()`]
+    pub fn encode_prepared(
+        &self,
+        piece: &PreparedPiece,
+        encoding: &mut Encoding<PIECE_SIZE_BYTES>,
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        scratch: &mut Scratch,
+    ) -> Result<(), EncodeError> {
+        if layers == 0 {
+            return Err(EncodeError::ZeroLayers);
+        }
+
+        scratch.blocks.clone_from(&piece.blocks);
+        self.encode_blocks(scratch, expanded_iv, layers);
+        write_blocks(&scratch.blocks, encoding.as_mut(), PRIME_SIZE_BYTES);
+
+        Ok(())
+    }
+
+    /// Applies the block cipher to blocks in scratch, each block with its own prime
+    fn encode_blocks(
+        &self,
+        scratch: &mut Scratch,
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+    ) {
+        let Scratch {
+            blocks,
+            feedback,
+            sqrt,
+        } = scratch;
+
+        feedback.assign_digits(expanded_iv.as_bytes(), Order::Lsf);
+
+        for _ in 0..layers {
+            for (block, sloth) in blocks.iter_mut().zip(self.blocks.iter().cycle()) {
+                block.bitxor_from(&*feedback);
+                sloth.sqrt_permutation(block, sqrt);
+                feedback.assign(&*block);
+            }
+        }
+    }
+
+    /// Sequentially decodes an encoding in time << encode time
+    pub fn decode(
+        &self,
+        piece: &mut [u8; PIECE_SIZE_BYTES],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+    ) -> Result<(), DecodeError> {
+        self.decode_with_scratch(piece, expanded_iv, layers, &mut Scratch::default())
+    }
+
+    /// Same as [`Sloth::decode()`], but reuses provided scratch
+    pub fn decode_with_scratch(
+        &self,
+        piece: &mut [u8; PIECE_SIZE_BYTES],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        scratch: &mut Scratch,
+    ) -> Result<(), DecodeError> {
+        self.decode_slice(piece, expanded_iv, layers, scratch)
+    }
+
+    /// Same as [`Sloth::decode_with_scratch()`], but for an encoding of any length that is a
+    /// multiple of `PRIME_SIZE_BYTES` (at least two blocks)
+    pub fn decode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        scratch: &mut Scratch,
+    ) -> Result<(), DecodeError> {
+        if layers == 0 {
+            return Err(DecodeError::ZeroLayers);
+        }
+        if !crate::is_valid_length(piece.len(), PRIME_SIZE_BYTES) {
+            return Err(DecodeError::InvalidLength);
+        }
+
+        read_blocks(piece, &mut scratch.blocks, PRIME_SIZE_BYTES);
+        let Scratch {
+            blocks, feedback, ..
+        } = scratch;
+
+        for layer in 0..layers {
+            for i in (1..blocks.len()).rev() {
+                let (block, feedback) = piece_to_block_and_feedback(blocks, i);
+                self.blocks[i % self.blocks.len()].inverse_sqrt(block);
+                block.bitxor_from(feedback);
+            }
+            let (block, feedback) = piece_to_first_block_and_feedback(blocks);
+            self.blocks[0].inverse_sqrt(block);
+            if layer != layers - 1 {
+                block.bitxor_from(feedback);
+            }
+        }
+
+        // remove the IV (last round)
+        feedback.assign_digits(expanded_iv.as_bytes(), Order::Lsf);
+        blocks[0].bitxor_from(&*feedback);
+
+        write_blocks(blocks, piece, PRIME_SIZE_BYTES);
+
+        Ok(())
+    }
+}
+
+impl<const PRIME_SIZE_BYTES: usize, const PIECE_SIZE_BYTES: usize>
+    SlothBackend<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES> for Sloth<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>
+{
+    type PreparedPiece = PreparedPiece;
+    type Scratch = Scratch;
+
+    /// Prime of the first block, which is chained with expanded IV
+    ///
+    /// Codecs derive expanded IV below this prime and compare it with the prime of scheme versions,
+    /// which pin a single prime for all blocks, so they can't tell this implementation apart from
+    /// a single-prime one using the same prime. Primes of [`derive_primes()`] are practically never
+    /// equal to the largest prime of their size, use [`Sloth::primes()`] to check the whole table.
+    fn prime(&self) -> [u8; PRIME_SIZE_BYTES] {
+        self.blocks[0].prime()
+    }
+
+    fn block_mapping(&self) -> BlockMapping {
+        self.blocks[0].block_mapping()
+    }
+
+    fn encode(
+        &self,
+        piece: &mut [u8; PIECE_SIZE_BYTES],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+    ) -> Result<(), EncodeError> {
+        Sloth::encode(self, piece, expanded_iv, layers)
+    }
+
+    fn decode(
+        &self,
+        piece: &mut [u8; PIECE_SIZE_BYTES],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+    ) -> Result<(), DecodeError> {
+        Sloth::decode(self, piece, expanded_iv, layers)
+    }
+
+    fn encode_with_scratch(
+        &self,
+        piece: &mut [u8; PIECE_SIZE_BYTES],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        scratch: &mut Scratch,
+    ) -> Result<(), EncodeError> {
+        Sloth::encode_with_scratch(self, piece, expanded_iv, layers, scratch)
+    }
+
+    fn decode_with_scratch(
+        &self,
+        piece: &mut [u8; PIECE_SIZE_BYTES],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        scratch: &mut Scratch,
+    ) -> Result<(), DecodeError> {
+        Sloth::decode_with_scratch(self, piece, expanded_iv, layers, scratch)
+    }
+
+    fn prepare_piece(&self, piece: &Piece<PIECE_SIZE_BYTES>) -> PreparedPiece {
+        Sloth::prepare_piece(self, piece)
+    }
+
+    fn encode_prepared(
+        &self,
+        piece: &PreparedPiece,
+        encoding: &mut Encoding<PIECE_SIZE_BYTES>,
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        scratch: &mut Scratch,
+    ) -> Result<(), EncodeError> {
+        Sloth::encode_prepared(self, piece, encoding, expanded_iv, layers, scratch)
+    }
+
+    fn encode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        scratch: &mut Scratch,
+    ) -> Result<(), EncodeError> {
+        Sloth::encode_slice(self, piece, expanded_iv, layers, scratch)
+    }
+
+    fn decode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        scratch: &mut Scratch,
+    ) -> Result<(), DecodeError> {
+        Sloth::decode_slice(self, piece, expanded_iv, layers, scratch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::*;
+
+    fn random_bytes<const BYTES: usize>() -> [u8; BYTES] {
+        let mut bytes = [0u8; BYTES];
+        rand::thread_rng().fill(&mut bytes[..]);
+        bytes
+    }
+
+    #[test]
+    fn test_derive_primes() {
+        let seed = [1u8; 32];
+        let primes = derive_primes(&seed, 32, 128);
+
+        assert_eq!(primes, derive_primes(&seed, 32, 128));
+        // Table for fewer blocks is a prefix
+        assert_eq!(derive_primes(&seed, 32, 2), primes[..2]);
+        assert_ne!(derive_primes(&[2u8; 32], 32, 2), primes[..2]);
+
+        for (index, prime) in primes.iter().enumerate() {
+            assert_eq!(prime.mod_u(4), 3);
+            assert_ne!(prime.is_probably_prime(25), rug::integer::IsPrime::No);
+            assert_eq!(prime.significant_bits(), 256);
+            assert!(!primes[..index].contains(prime));
+        }
+
+        // Derivation is frozen, changing it changes existing encodings
+        assert_eq!(
+            primes[0].to_string(),
+            "115792089237316195423570985008687907853269984665640564039451963193422741164783"
+        );
+        assert_eq!(
+            derive_primes(&seed, 64, 1)[0].to_string(),
+            "13407807929942597099574024998205846127479365820592393377723561443721764030073546976801874298166903427690031858186486050853753882811946564325619158617608999"
+        );
+    }
+
+    #[test]
+    fn test_random_piece() {
+        let sloth = Sloth::<32, 4096>::with_seed(&random_bytes());
+        let single_prime = software::Sloth::<32, 4096>::with_prime(software::largest_prime(32));
+        let expanded_iv = ExpandedIv::from(random_bytes());
+        let piece = random_bytes::<4096>();
+        let mut scratch = Scratch::default();
+
+        for layers in 1..=2 {
+            let mut encoding = piece;
+            sloth.encode(&mut encoding, expanded_iv, layers).unwrap();
+            let mut single_prime_encoding = piece;
+            single_prime
+                .encode(&mut single_prime_encoding, expanded_iv, layers)
+                .unwrap();
+            assert_ne!(encoding, single_prime_encoding);
+
+            let mut prepared_encoding = Encoding::from([0u8; 4096]);
+            sloth
+                .encode_prepared(
+                    &sloth.prepare_piece(&Piece::from(piece)),
+                    &mut prepared_encoding,
+                    expanded_iv,
+                    layers,
+                    &mut scratch,
+                )
+                .unwrap();
+            assert_eq!(*prepared_encoding.as_bytes(), encoding);
+
+            sloth
+                .decode_with_scratch(&mut encoding, expanded_iv, layers, &mut scratch)
+                .unwrap();
+            assert_eq!(encoding, piece);
+        }
+
+        let mut encoding = piece;
+        assert_eq!(
+            sloth.encode(&mut encoding, expanded_iv, 0),
+            Err(EncodeError::ZeroLayers)
+        );
+        assert_eq!(
+            sloth.decode(&mut encoding, expanded_iv, 0),
+            Err(DecodeError::ZeroLayers)
+        );
+    }
+
+    #[test]
+    fn test_slice() {
+        let sloth = Sloth::<32, 64>::with_seed(&random_bytes());
+        let expanded_iv = ExpandedIv::from(random_bytes());
+        let mut scratch = Scratch::default();
+
+        // Blocks after the first two reuse primes from the start of the table
+        let piece = random_bytes::<96>();
+        let mut encoding = piece;
+        sloth
+            .encode_slice(&mut encoding, expanded_iv, 2, &mut scratch)
+            .unwrap();
+        assert_ne!(encoding, piece);
+        sloth
+            .decode_slice(&mut encoding, expanded_iv, 2, &mut scratch)
+            .unwrap();
+        assert_eq!(encoding, piece);
+
+        assert_eq!(
+            sloth.encode_slice(&mut encoding[..32], expanded_iv, 1, &mut scratch),
+            Err(EncodeError::InvalidLength)
+        );
+        assert_eq!(
+            sloth.decode_slice(&mut encoding[..33], expanded_iv, 1, &mut scratch),
+            Err(DecodeError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn test_primes() {
+        let seed = [1u8; 32];
+        let sloth = Sloth::<32, 64>::with_seed(&seed);
+        let primes = derive_primes(&seed, 32, 2);
+        assert_eq!(
+            sloth.primes(),
+            primes
+                .iter()
+                .map(|prime| {
+                    let mut bytes = [0u8; 32];
+                    prime.write_digits(&mut bytes, Order::Lsf);
+                    bytes
+                })
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(SlothBackend::<32, 64>::prime(&sloth), sloth.primes()[0]);
+
+        // Prime that is not `3 mod 4`
+        assert_eq!(
+            Sloth::<32, 64>::try_with_primes(vec![primes[0].clone(), Integer::from(13)])
+                .unwrap_err(),
+            ParametersError::PrimeNotThreeModFour
+        );
+
+        // Mapping applies to all primes
+        let rotating = sloth.clone().with_block_mapping(BlockMapping::Rotate);
+        assert_eq!(
+            SlothBackend::<32, 64>::block_mapping(&rotating),
+            BlockMapping::Rotate
+        );
+        let piece = [0xffu8; 64];
+        let mut encoding = piece;
+        rotating
+            .encode(&mut encoding, ExpandedIv::from([0u8; 32]), 2)
+            .unwrap();
+        assert_ne!(encoding, piece);
+        rotating
+            .decode(&mut encoding, ExpandedIv::from([0u8; 32]), 2)
+            .unwrap();
+        assert_eq!(encoding, piece);
+
+        // Too few primes for a piece of two blocks
+        assert_eq!(
+            Sloth::<32, 64>::try_with_primes(vec![primes[0].clone()]).unwrap_err(),
+            ParametersError::PrimeCountMismatch
+        );
+    }
+
+    #[test]
+    fn test_known_piece() {
+        let sloth = Sloth::<32, 4096>::with_seed(&[1u8; 32]);
+        let expanded_iv = ExpandedIv::from([3u8; 32]);
+        let piece = [5u8; 4096];
+
+        let mut encoding = piece;
+        sloth.encode(&mut encoding, expanded_iv, 1).unwrap();
+        let hash = Sha256::digest(encoding)
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<String>();
+        // Encoding is frozen, changing it changes existing encodings
+        assert_eq!(
+            hash,
+            "9feba20d6fe0dc8d357c205205cb57a06fad294b81b222e2bee2dc25eed92a39"
+        );
+
+        sloth.decode(&mut encoding, expanded_iv, 1).unwrap();
+        assert_eq!(encoding, piece);
+    }
+}
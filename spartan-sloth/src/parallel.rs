@@ -0,0 +1,253 @@
+//! Parallel encoding of multiple independent pieces using `rayon`
+//!
+//! Encoding of a single piece is strictly sequential, but different pieces can be encoded at the
+//! same time on different CPU cores.
+
+use crate::{EncodeError, ExpandedIv, SlothBackend};
+use rayon::prelude::*;
+pub use rayon::{ThreadPool, ThreadPoolBuilder};
+
+/// Options of parallel encoding
+#[derive(Debug, Copy, Clone)]
+pub struct ParallelOptions<'a> {
+    /// Thread pool to encode pieces in (use [`ThreadPoolBuilder`] to control number of threads),
+    /// global `rayon` thread pool is used if `None`
+    pub thread_pool: Option<&'a ThreadPool>,
+    /// Number of pieces encoded sequentially by one task, `0` is treated as `1`
+    ///
+    /// For implementations that encode multiple pieces at the same time (see
+    /// [`SlothBackend::lanes()`]), chunk size is rounded up to a multiple of the number of lanes.
+    pub chunk_size: usize,
+}
+
+impl Default for ParallelOptions<'_> {
+    fn default() -> Self {
+        Self {
+            thread_pool: None,
+            chunk_size: 1,
+        }
+    }
+}
+
+/// Encodes multiple pieces in parallel using provided SLOTH implementation, each piece is encoded
+/// with expanded IV at the same index, scratch is reused between pieces encoded by the same thread
+///
+/// Each task encodes its chunk with [`SlothBackend::encode_lanes()`], such that implementations
+/// that encode multiple pieces at the same time use all of their lanes.
+pub fn encode_many<Sloth, const PRIME_SIZE_BYTES: usize, const PIECE_SIZE_BYTES: usize>(
+    sloth: &Sloth,
+    pieces: &mut [[u8; PIECE_SIZE_BYTES]],
+    expanded_ivs: &[ExpandedIv<PRIME_SIZE_BYTES>],
+    layers: usize,
+    options: ParallelOptions<'_>,
+) -> Result<(), EncodeError>
+where
+    Sloth: SlothBackend<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES> + Sync + ?Sized,
+{
+    let lanes = sloth.lanes().max(1);
+    let chunk_size = options.chunk_size.max(1).div_ceil(lanes) * lanes;
+
+    for_each_chunk::<Sloth, _, PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>(
+        pieces,
+        expanded_ivs,
+        layers,
+        ParallelOptions {
+            chunk_size,
+            ..options
+        },
+        |pieces, expanded_ivs, scratch| sloth.encode_lanes(pieces, expanded_ivs, layers, scratch),
+    )
+}
+
+/// Same as [`encode_many()`], but with tweaks applied to every piece, see
+/// [`SlothBackend::encode_tweaked()`]
+pub fn encode_many_tweaked<Sloth, const PRIME_SIZE_BYTES: usize, const PIECE_SIZE_BYTES: usize>(
+    sloth: &Sloth,
+    pieces: &mut [[u8; PIECE_SIZE_BYTES]],
+    expanded_ivs: &[ExpandedIv<PRIME_SIZE_BYTES>],
+    layers: usize,
+    tweaks: &(dyn Fn(usize, &mut [u8]) + Sync),
+    options: ParallelOptions<'_>,
+) -> Result<(), EncodeError>
+where
+    Sloth: SlothBackend<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES> + Sync + ?Sized,
+{
+    for_each_chunk::<Sloth, _, PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>(
+        pieces,
+        expanded_ivs,
+        layers,
+        options,
+        |pieces, expanded_ivs, scratch| {
+            pieces
+                .iter_mut()
+                .zip(expanded_ivs)
+                .try_for_each(|(piece, &expanded_iv)| {
+                    sloth.encode_tweaked(piece, expanded_iv, layers, tweaks, scratch)
+                })
+        },
+    )
+}
+
+fn for_each_chunk<Sloth, Encode, const PRIME_SIZE_BYTES: usize, const PIECE_SIZE_BYTES: usize>(
+    pieces: &mut [[u8; PIECE_SIZE_BYTES]],
+    expanded_ivs: &[ExpandedIv<PRIME_SIZE_BYTES>],
+    layers: usize,
+    options: ParallelOptions<'_>,
+    encode_chunk: Encode,
+) -> Result<(), EncodeError>
+where
+    Sloth: SlothBackend<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES> + ?Sized,
+    Encode: Fn(
+            &mut [[u8; PIECE_SIZE_BYTES]],
+            &[ExpandedIv<PRIME_SIZE_BYTES>],
+            &mut Sloth::Scratch,
+        ) -> Result<(), EncodeError>
+        + Sync,
+{
+    if pieces.len() != expanded_ivs.len() {
+        return Err(EncodeError::LengthMismatch);
+    }
+    if layers == 0 {
+        return Err(EncodeError::ZeroLayers);
+    }
+
+    let chunk_size = options.chunk_size.max(1);
+    let mut encode = || {
+        pieces
+            .par_chunks_mut(chunk_size)
+            .zip(expanded_ivs.par_chunks(chunk_size))
+            .try_for_each_init(
+                Sloth::Scratch::default,
+                |scratch, (pieces, expanded_ivs)| encode_chunk(pieces, expanded_ivs, scratch),
+            )
+    };
+
+    match options.thread_pool {
+        Some(thread_pool) => thread_pool.install(encode),
+        None => encode(),
+    }
+}
+
+#[cfg(all(test, feature = "portable"))]
+mod tests {
+    use super::*;
+    use crate::portable::Sloth;
+    use crate::prime_256::PRIME_BYTES;
+    use rand::prelude::*;
+
+    fn random_bytes<const BYTES: usize>() -> [u8; BYTES] {
+        let mut bytes = [0u8; BYTES];
+        rand::thread_rng().fill(&mut bytes[..]);
+        bytes
+    }
+
+    #[test]
+    fn test_encode_many() {
+        let sloth = Sloth::<32, 4096>::with_prime(PRIME_BYTES);
+        let pieces = (0..5).map(|_| random_bytes()).collect::<Vec<[u8; 4096]>>();
+        let expanded_ivs = (0..5)
+            .map(|_| ExpandedIv::from(random_bytes()))
+            .collect::<Vec<_>>();
+
+        let thread_pool = ThreadPoolBuilder::new().num_threads(2).build().unwrap();
+        for &options in &[
+            ParallelOptions::default(),
+            ParallelOptions {
+                thread_pool: Some(&thread_pool),
+                chunk_size: 2,
+            },
+        ] {
+            let mut encodings = pieces.clone();
+            sloth
+                .encode_many(&mut encodings, &expanded_ivs, 1, options)
+                .unwrap();
+
+            for ((piece, encoding), &expanded_iv) in
+                pieces.iter().zip(&encodings).zip(&expanded_ivs)
+            {
+                let mut expected_encoding = *piece;
+                sloth
+                    .encode(&mut expected_encoding, expanded_iv, 1)
+                    .unwrap();
+                assert_eq!(encoding.to_vec(), expected_encoding.to_vec());
+            }
+        }
+
+        let mut encodings = pieces;
+        assert_eq!(
+            sloth.encode_many(
+                &mut encodings,
+                &expanded_ivs[1..],
+                1,
+                ParallelOptions::default()
+            ),
+            Err(EncodeError::LengthMismatch)
+        );
+        assert_eq!(
+            sloth.encode_many(&mut encodings, &expanded_ivs, 0, ParallelOptions::default()),
+            Err(EncodeError::ZeroLayers)
+        );
+    }
+
+    #[test]
+    fn test_encode_many_lanes() {
+        // Auto implementation encodes multiple pieces at the same time on CPUs that support it
+        let sloth = crate::auto::Sloth::new();
+        let pieces = (0..11).map(|_| random_bytes()).collect::<Vec<[u8; 4096]>>();
+        let expanded_ivs = (0..11)
+            .map(|_| ExpandedIv::from(random_bytes()))
+            .collect::<Vec<_>>();
+
+        for &chunk_size in &[1, 3, 16] {
+            let mut encodings = pieces.clone();
+            sloth
+                .encode_many(
+                    &mut encodings,
+                    &expanded_ivs,
+                    1,
+                    ParallelOptions {
+                        thread_pool: None,
+                        chunk_size,
+                    },
+                )
+                .unwrap();
+
+            for ((piece, encoding), &expanded_iv) in
+                pieces.iter().zip(&encodings).zip(&expanded_ivs)
+            {
+                let mut expected_encoding = *piece;
+                SlothBackend::encode(&sloth, &mut expected_encoding, expanded_iv, 1).unwrap();
+                assert_eq!(encoding.to_vec(), expected_encoding.to_vec());
+            }
+        }
+    }
+
+    #[test]
+    fn test_encode_many_tweaked() {
+        let sloth = Sloth::<32, 4096>::with_prime(PRIME_BYTES);
+        let pieces = (0..3).map(|_| random_bytes()).collect::<Vec<[u8; 4096]>>();
+        let expanded_ivs = (0..3)
+            .map(|_| ExpandedIv::from(random_bytes()))
+            .collect::<Vec<_>>();
+        let tweaks = |layer: usize, piece: &mut [u8]| piece[0] ^= layer as u8 + 1;
+
+        let mut encodings = pieces.clone();
+        encode_many_tweaked(
+            &sloth,
+            &mut encodings,
+            &expanded_ivs,
+            2,
+            &tweaks,
+            ParallelOptions::default(),
+        )
+        .unwrap();
+
+        for ((piece, encoding), &expanded_iv) in pieces.iter().zip(&encodings).zip(&expanded_ivs) {
+            let mut expected_encoding = *piece;
+            sloth
+                .encode_tweaked(&mut expected_encoding, expanded_iv, 2, &tweaks, &mut ())
+                .unwrap();
+            assert_eq!(encoding.to_vec(), expected_encoding.to_vec());
+        }
+    }
+}
@@ -0,0 +1,1108 @@
+//! Pure Rust implementation of Sloth that doesn't depend on GMP
+//!
+//! Blocks are stored as fixed-size arrays of 64-bit limbs (as many as needed for
+//! `PRIME_SIZE_BYTES`), exponentiation and squaring are done using Montgomery multiplication,
+//! exponentiation follows addition chain computed once for the prime.
+//! For prime `2^256 - 189` faster specialized arithmetic is used instead.
+//! Produces exactly the same results as software implementation for the same prime.
+
+use crate::addition_chain::{self, AdditionChain};
+use crate::{
+    prime_256, pseudo_mersenne, BlockMapping, DecodeError, EncodeError, Encoding, ExpandedIv,
+    ParametersError, Piece, SlothBackend,
+};
+use core::convert::TryInto;
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+
+/// Window size (in bits) used for exponentiation in primality test
+const WINDOW_BITS: usize = 4;
+/// Number of Miller-Rabin rounds of primality test, each with a pseudo-random base derived from the
+/// prime, composite number passes all of them with probability of at most `4^-40`, so finding one
+/// that does takes about `2^80` attempts
+const MILLER_RABIN_ROUNDS: usize = 40;
+
+/// Little-endian 64-bit limbs of a number of `PRIME_SIZE_BYTES` (rounded up to whole limbs) with
+/// `EXTRA_LIMBS - 1` more limbs for intermediate results
+///
+/// Stable Rust doesn't allow array length to be computed from const generic parameter, so limbs are
+/// backed by bytes instead, which keeps size of a block proportional to prime size.
+#[derive(Copy, Clone)]
+#[repr(C, align(8))]
+struct Limbs<const PRIME_SIZE_BYTES: usize, const EXTRA_LIMBS: usize = 1> {
+    bytes: [u8; PRIME_SIZE_BYTES],
+    /// Covers rounding up to whole limbs and extra limbs, byte arrays are used such that there is
+    /// no padding between fields
+    extra: [[u8; 8]; EXTRA_LIMBS],
+}
+
+impl<const PRIME_SIZE_BYTES: usize, const EXTRA_LIMBS: usize> Limbs<PRIME_SIZE_BYTES, EXTRA_LIMBS> {
+    const ZERO: Self = Self {
+        bytes: [0; PRIME_SIZE_BYTES],
+        extra: [[0; 8]; EXTRA_LIMBS],
+    };
+    /// Number of limbs, `PRIME_SIZE_BYTES` rounded up to whole limbs and extra limbs
+    const LEN: usize = PRIME_SIZE_BYTES.div_ceil(8) + EXTRA_LIMBS - 1;
+
+    fn from_u64(value: u64) -> Self {
+        let mut limbs = Self::ZERO;
+        limbs[0] = value;
+        limbs
+    }
+}
+
+impl<const PRIME_SIZE_BYTES: usize, const EXTRA_LIMBS: usize> Deref
+    for Limbs<PRIME_SIZE_BYTES, EXTRA_LIMBS>
+{
+    type Target = [u64];
+
+    #[inline(always)]
+    fn deref(&self) -> &[u64] {
+        // SAFETY: struct is aligned to 8 bytes and has no padding before the end of `extra`, which
+        // is at least `LEN * 8` bytes from the start (`EXTRA_LIMBS >= 1` covers rounding up), any
+        // bytes are a valid `u64`
+        unsafe { core::slice::from_raw_parts((self as *const Self).cast::<u64>(), Self::LEN) }
+    }
+}
+
+impl<const PRIME_SIZE_BYTES: usize, const EXTRA_LIMBS: usize> DerefMut
+    for Limbs<PRIME_SIZE_BYTES, EXTRA_LIMBS>
+{
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut [u64] {
+        // SAFETY: see `Deref` implementation
+        unsafe { core::slice::from_raw_parts_mut((self as *mut Self).cast::<u64>(), Self::LEN) }
+    }
+}
+
+impl<const PRIME_SIZE_BYTES: usize, const EXTRA_LIMBS: usize> fmt::Debug
+    for Limbs<PRIME_SIZE_BYTES, EXTRA_LIMBS>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// SplitMix64 pseudo-random number generator, used to derive bases for primality test
+fn split_mix_64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Computes `a + b * c + carry`, returns (low, high) words of the result
+#[inline(always)]
+fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let result = a as u128 + b as u128 * c as u128 + carry as u128;
+    (result as u64, (result >> 64) as u64)
+}
+
+/// Computes `a + b + carry`, returns (sum, carry)
+#[inline(always)]
+fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let result = a as u128 + b as u128 + carry as u128;
+    (result as u64, (result >> 64) as u64)
+}
+
+/// Computes `a - b - borrow`, returns (difference, borrow)
+#[inline(always)]
+fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+    let result = (a as u128).wrapping_sub(b as u128 + borrow as u128);
+    (result as u64, (result >> 127) as u64)
+}
+
+/// `a -= b`, returns borrow
+fn sub_assign(a: &mut [u64], b: &[u64]) -> u64 {
+    let mut borrow = 0;
+    for (a, &b) in a.iter_mut().zip(b.iter()) {
+        let (difference, new_borrow) = sbb(*a, b, borrow);
+        *a = difference;
+        borrow = new_borrow;
+    }
+    borrow
+}
+
+/// `a += b`, returns carry
+fn add_assign(a: &mut [u64], b: &[u64]) -> u64 {
+    let mut carry = 0;
+    for (a, &b) in a.iter_mut().zip(b.iter()) {
+        let (sum, new_carry) = adc(*a, b, carry);
+        *a = sum;
+        carry = new_carry;
+    }
+    carry
+}
+
+/// Whether `a >= b`
+fn greater_or_equal(a: &[u64], b: &[u64]) -> bool {
+    for (a, b) in a.iter().zip(b.iter()).rev() {
+        if a != b {
+            return a > b;
+        }
+    }
+    true
+}
+
+fn is_zero(a: &[u64]) -> bool {
+    a.iter().all(|&limb| limb == 0)
+}
+
+/// `a >>= bits`, for `bits < 64`
+fn shift_right_assign(a: &mut [u64], bits: u32) {
+    if bits == 0 {
+        return;
+    }
+    for i in 0..a.len() {
+        let high = a.get(i + 1).copied().unwrap_or_default();
+        a[i] = (a[i] >> bits) | (high << (64 - bits));
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Sloth<const PRIME_SIZE_BYTES: usize, const PIECE_SIZE_BYTES: usize> {
+    prime: Limbs<PRIME_SIZE_BYTES>,
+    /// Addition chain for exponent `(prime + 1) / 4`
+    exponent_chain: AdditionChain<PRIME_SIZE_BYTES>,
+    /// `-prime^-1 mod 2^64`
+    prime_inv_neg: u64,
+    /// `R^2 mod prime`, used for conversion into Montgomery form
+    r_squared: Limbs<PRIME_SIZE_BYTES>,
+    /// `R mod prime`, which is 1 in Montgomery form
+    one: Limbs<PRIME_SIZE_BYTES>,
+    /// Prime is `2^256 - 189`, for which specialized arithmetic is used instead of Montgomery
+    /// multiplication
+    pseudo_mersenne: bool,
+    block_mapping: BlockMapping,
+}
+
+impl<const PRIME_SIZE_BYTES: usize, const PIECE_SIZE_BYTES: usize>
+    Sloth<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>
+{
+    /// Number of limbs used for one block
+    const LIMBS: usize = PRIME_SIZE_BYTES.div_ceil(8);
+    /// Sizes of prime and piece are checked at compile time when constructor is instantiated
+    const VALID_SIZES: () = crate::assert_valid_sizes(PRIME_SIZE_BYTES, PIECE_SIZE_BYTES);
+
+    /// Initializes SLOTH with a given prime (as little-endian bytes) and computes the exponent
+    ///
+    /// # Panics
+    ///
+    /// Panics if prime is invalid, see [`Sloth::try_with_prime()`] for checked version.
+    pub fn with_prime(prime: [u8; PRIME_SIZE_BYTES]) -> Self {
+        Self::try_with_prime(prime).unwrap_or_else(|error| panic!("Invalid prime: {}", error))
+    }
+
+    /// Initializes SLOTH with a given prime (as little-endian bytes) and computes the exponent,
+    /// returns an error if prime is not `3 mod 4` or is not prime (primality is checked with
+    /// Miller-Rabin test)
+    pub fn try_with_prime(prime: [u8; PRIME_SIZE_BYTES]) -> Result<Self, ParametersError> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_SIZES;
+
+        let pseudo_mersenne = prime[..] == prime_256::PRIME_BYTES[..];
+        let prime = Self::bytes_to_limbs(&prime);
+        // Also guarantees that prime is odd, which Montgomery multiplication relies on
+        if prime[0] % 4 != 3 {
+            return Err(ParametersError::PrimeNotThreeModFour);
+        }
+
+        // `(prime + 1) / 4`, which is `(prime >> 2) + 1` for `prime = 3 mod 4`
+        let mut exponent = prime;
+        shift_right_assign(&mut exponent[..Self::LIMBS], 2);
+        let one = Limbs::<PRIME_SIZE_BYTES>::from_u64(1);
+        add_assign(&mut exponent[..Self::LIMBS], &one[..Self::LIMBS]);
+
+        // Newton iteration doubles number of correct bits of inverse each time
+        let mut prime_inv: u64 = 1;
+        for _ in 0..6 {
+            prime_inv = prime_inv.wrapping_mul(2u64.wrapping_sub(prime[0].wrapping_mul(prime_inv)));
+        }
+
+        let mut sloth = Self {
+            prime,
+            exponent_chain: AdditionChain::new(&exponent[..Self::LIMBS]),
+            prime_inv_neg: prime_inv.wrapping_neg(),
+            r_squared: Limbs::ZERO,
+            one: Limbs::ZERO,
+            pseudo_mersenne,
+            block_mapping: BlockMapping::default(),
+        };
+
+        // `R = 2^(64 * LIMBS)`, so `R mod prime` and `R^2 mod prime` are obtained by doubling
+        // `1` modulo prime
+        let mut r = one;
+        for _ in 0..(Self::LIMBS * 64) {
+            sloth.double_mod(&mut r);
+        }
+        sloth.one = r;
+        for _ in 0..(Self::LIMBS * 64) {
+            sloth.double_mod(&mut r);
+        }
+        sloth.r_squared = r;
+
+        if !sloth.is_probable_prime() {
+            return Err(ParametersError::NotPrime);
+        }
+
+        Ok(sloth)
+    }
+
+    /// Use provided mapping of blocks that square root permutation is not defined for instead of
+    /// [`BlockMapping::Swap`]
+    pub fn with_block_mapping(mut self, block_mapping: BlockMapping) -> Self {
+        self.block_mapping = block_mapping;
+        self
+    }
+
+    /// Miller-Rabin test of the prime, for `prime = 3 mod 4` `prime - 1 = 2 * d` with odd `d`, so
+    /// for every base `base^d` must be `1` or `-1`
+    ///
+    /// Bases are pseudo-random numbers of full prime size seeded with the prime itself rather than
+    /// small fixed numbers, for which composite numbers that pass the test are known to exist.
+    fn is_probable_prime(&self) -> bool {
+        let limbs = Self::LIMBS;
+        let mut d = self.prime;
+        shift_right_assign(&mut d[..limbs], 1);
+        let mut minus_one = self.prime;
+        minus_one[0] -= 1;
+        let one = Limbs::<PRIME_SIZE_BYTES>::from_u64(1);
+
+        let mut state = self.prime[..limbs]
+            .iter()
+            .fold(0u64, |state, &limb| split_mix_64(&mut (state ^ limb)));
+
+        (0..MILLER_RABIN_ROUNDS).all(|_| {
+            let mut base = Limbs::<PRIME_SIZE_BYTES>::ZERO;
+            for limb in base[..limbs].iter_mut() {
+                *limb = split_mix_64(&mut state);
+            }
+            // Reduce modulo prime, bases `0`, `1` and `-1` don't tell anything (which only
+            // realistically happens for tiny primes)
+            let base = self.montgomery_to_normal(&self.normal_to_montgomery(&base));
+            if is_zero(&base[..limbs])
+                || base[..limbs] == one[..limbs]
+                || base[..limbs] == minus_one[..limbs]
+            {
+                return true;
+            }
+
+            let result = self.pow(&base, &d);
+            result[..limbs] == one[..limbs] || result[..limbs] == minus_one[..limbs]
+        })
+    }
+
+    /// Returns expanded IV as an array accepted by specialized arithmetic if it is used
+    fn pseudo_mersenne_iv<'a>(
+        &self,
+        expanded_iv: &'a [u8; PRIME_SIZE_BYTES],
+    ) -> Option<&'a [u8; 32]> {
+        // Specialized arithmetic only supports the default block mapping
+        if self.pseudo_mersenne && self.block_mapping == BlockMapping::Swap {
+            expanded_iv[..].try_into().ok()
+        } else {
+            None
+        }
+    }
+
+    /// Sequentially encodes a 4096 byte piece s.t. a minimum amount of wall clock time elapses
+    pub fn encode(
+        &self,
+        piece: &mut [u8; PIECE_SIZE_BYTES],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+    ) -> Result<(), EncodeError> {
+        self.encode_slice(piece, expanded_iv, layers)
+    }
+
+    /// Same as [`Sloth::encode()`], but for a piece of any length that is a multiple of
+    /// `PRIME_SIZE_BYTES` (at least two blocks)
+    pub fn encode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+    ) -> Result<(), EncodeError> {
+        if layers == 0 {
+            return Err(EncodeError::ZeroLayers);
+        }
+        if !crate::is_valid_length(piece.len(), PRIME_SIZE_BYTES) {
+            return Err(EncodeError::InvalidLength);
+        }
+
+        if let Some(expanded_iv) = self.pseudo_mersenne_iv(expanded_iv.as_bytes()) {
+            pseudo_mersenne::encode(piece, expanded_iv, layers);
+            return Ok(());
+        }
+
+        // init feedback as expanded IV
+        let mut feedback = Self::bytes_to_limbs(expanded_iv.as_bytes());
+
+        // apply the block cipher
+        for _ in 0..layers {
+            for block_bytes in piece.chunks_exact_mut(PRIME_SIZE_BYTES) {
+                let mut block = Self::bytes_to_limbs(block_bytes);
+
+                // xor block with feedback
+                Self::xor_assign(&mut block, &feedback);
+
+                // apply sqrt permutation
+                self.sqrt_permutation(&mut block);
+
+                Self::limbs_to_bytes(&block, block_bytes);
+
+                // carry forward the feedback
+                feedback = block;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sequentially decodes a 4096 byte encoding in time << encode time
+    pub fn decode(
+        &self,
+        piece: &mut [u8; PIECE_SIZE_BYTES],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+    ) -> Result<(), DecodeError> {
+        self.decode_slice(piece, expanded_iv, layers)
+    }
+
+    /// Same as [`Sloth::decode()`], but for an encoding of any length that is a multiple of
+    /// `PRIME_SIZE_BYTES` (at least two blocks)
+    pub fn decode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+    ) -> Result<(), DecodeError> {
+        if layers == 0 {
+            return Err(DecodeError::ZeroLayers);
+        }
+        if !crate::is_valid_length(piece.len(), PRIME_SIZE_BYTES) {
+            return Err(DecodeError::InvalidLength);
+        }
+
+        if let Some(expanded_iv) = self.pseudo_mersenne_iv(expanded_iv.as_bytes()) {
+            pseudo_mersenne::decode(piece, expanded_iv, layers);
+            return Ok(());
+        }
+
+        let blocks = piece.len() / PRIME_SIZE_BYTES;
+
+        for layer in 0..layers {
+            for i in (1..blocks).rev() {
+                let (previous_blocks, blocks) = piece.split_at_mut(i * PRIME_SIZE_BYTES);
+                let block_bytes = &mut blocks[..PRIME_SIZE_BYTES];
+                let feedback = Self::bytes_to_limbs(
+                    &previous_blocks[previous_blocks.len() - PRIME_SIZE_BYTES..],
+                );
+
+                let mut block = Self::bytes_to_limbs(block_bytes);
+                self.inverse_sqrt(&mut block);
+                Self::xor_assign(&mut block, &feedback);
+                Self::limbs_to_bytes(&block, block_bytes);
+            }
+
+            let (first_block_bytes, remainder) = piece.split_at_mut(PRIME_SIZE_BYTES);
+            let mut block = Self::bytes_to_limbs(first_block_bytes);
+            self.inverse_sqrt(&mut block);
+            if layer != layers - 1 {
+                // At this point last block is already decoded, so we can use it as an IV to
+                // previous iteration
+                let iv = Self::bytes_to_limbs(&remainder[remainder.len() - PRIME_SIZE_BYTES..]);
+                Self::xor_assign(&mut block, &iv);
+            }
+            Self::limbs_to_bytes(&block, first_block_bytes);
+        }
+
+        // remove the IV (last round)
+        let mut block = Self::bytes_to_limbs(&piece[..PRIME_SIZE_BYTES]);
+        Self::xor_assign(&mut block, &Self::bytes_to_limbs(expanded_iv.as_bytes()));
+        Self::limbs_to_bytes(&block, &mut piece[..PRIME_SIZE_BYTES]);
+
+        Ok(())
+    }
+
+    /// Computes the modular square root of data, zero and numbers that are not smaller than prime
+    /// are mapped according to [`BlockMapping`]
+    fn sqrt_permutation(&self, data: &mut Limbs<PRIME_SIZE_BYTES>) {
+        let limbs = Self::LIMBS;
+
+        if self.map_outside_field(data) {
+            return;
+        }
+
+        // `root^2` is `data` for quadratic residue and `prime - data` otherwise, so squaring the
+        // root back tells which one it is, the other square root is `prime - root`
+        let data_montgomery = self.normal_to_montgomery(data);
+        let root_montgomery = self.pow_sqrt_exponent(&data_montgomery);
+        let is_quadratic_residue = self.montgomery_mul(&root_montgomery, &root_montgomery)[..limbs]
+            == data_montgomery[..limbs];
+
+        *data = self.montgomery_to_normal(&root_montgomery);
+        if is_quadratic_residue == Self::is_odd(data) {
+            self.negate(data);
+        }
+    }
+
+    /// Inverts the sqrt permutation with a single squaring mod prime
+    fn inverse_sqrt(&self, data: &mut Limbs<PRIME_SIZE_BYTES>) {
+        if self.unmap_outside_field(data) {
+            return;
+        }
+
+        let is_odd = Self::is_odd(data);
+        let data_montgomery = self.normal_to_montgomery(data);
+        *data = self.montgomery_to_normal(&self.montgomery_mul(&data_montgomery, &data_montgomery));
+        if is_odd {
+            self.negate(data);
+        }
+    }
+
+    /// Whether data is zero or not smaller than prime, in which case square root permutation is not
+    /// defined for it
+    fn is_outside_field(&self, data: &Limbs<PRIME_SIZE_BYTES>) -> bool {
+        let limbs = Self::LIMBS;
+        is_zero(&data[..limbs]) || greater_or_equal(&data[..limbs], &self.prime[..limbs])
+    }
+
+    /// Maps zero and numbers that are not smaller than prime according to [`BlockMapping`], returns
+    /// `false` without changing `data` if it is a non-zero number below prime
+    fn map_outside_field(&self, data: &mut Limbs<PRIME_SIZE_BYTES>) -> bool {
+        let limbs = Self::LIMBS;
+
+        if !self.is_outside_field(data) {
+            return false;
+        }
+
+        // Zero is not a quadratic residue according to Jacobi symbol and ends up mapped to prime
+        if is_zero(&data[..limbs]) {
+            *data = self.prime;
+            return true;
+        }
+        match self.block_mapping {
+            BlockMapping::Swap => {
+                if data[..limbs] == self.prime[..limbs] {
+                    *data = Limbs::ZERO;
+                }
+            }
+            BlockMapping::Rotate => {
+                if data[..limbs] == Self::bytes_to_limbs(&[0xff; PRIME_SIZE_BYTES])[..limbs] {
+                    *data = Limbs::ZERO;
+                } else {
+                    add_assign(&mut data[..limbs], &Limbs::<PRIME_SIZE_BYTES>::from_u64(1));
+                }
+            }
+        }
+        true
+    }
+
+    /// Inverts [`Sloth::map_outside_field()`]
+    fn unmap_outside_field(&self, data: &mut Limbs<PRIME_SIZE_BYTES>) -> bool {
+        let limbs = Self::LIMBS;
+
+        if !self.is_outside_field(data) {
+            return false;
+        }
+
+        match self.block_mapping {
+            BlockMapping::Swap => {
+                if is_zero(&data[..limbs]) {
+                    *data = self.prime;
+                } else if data[..limbs] == self.prime[..limbs] {
+                    *data = Limbs::ZERO;
+                }
+            }
+            BlockMapping::Rotate => {
+                if is_zero(&data[..limbs]) {
+                    // The largest number that fits into a block
+                    *data = Self::bytes_to_limbs(&[0xff; PRIME_SIZE_BYTES]);
+                } else if data[..limbs] == self.prime[..limbs] {
+                    *data = Limbs::ZERO;
+                } else {
+                    sub_assign(&mut data[..limbs], &Limbs::<PRIME_SIZE_BYTES>::from_u64(1));
+                }
+            }
+        }
+        true
+    }
+
+    /// Raises data in Montgomery form to the power of `(prime + 1) / 4` modulo prime using addition
+    /// chain, result is in Montgomery form too
+    fn pow_sqrt_exponent(&self, base: &Limbs<PRIME_SIZE_BYTES>) -> Limbs<PRIME_SIZE_BYTES> {
+        // `base, base^3, base^5, ...`
+        let square = self.montgomery_mul(base, base);
+        let mut odd_powers = [*base; addition_chain::ODD_POWERS];
+        for i in 1..odd_powers.len() {
+            odd_powers[i] = self.montgomery_mul(&odd_powers[i - 1], &square);
+        }
+
+        let steps = self.exponent_chain.steps();
+        let mut result = odd_powers[steps[0].odd_power as usize];
+        for step in &steps[1..] {
+            for _ in 0..step.squarings {
+                result = self.montgomery_mul(&result, &result);
+            }
+            result = self.montgomery_mul(&result, &odd_powers[step.odd_power as usize]);
+        }
+        for _ in 0..self.exponent_chain.trailing_squarings() {
+            result = self.montgomery_mul(&result, &result);
+        }
+
+        result
+    }
+
+    /// Raises data to the power of exponent modulo prime, used for primality test
+    fn pow(
+        &self,
+        data: &Limbs<PRIME_SIZE_BYTES>,
+        exponent: &Limbs<PRIME_SIZE_BYTES>,
+    ) -> Limbs<PRIME_SIZE_BYTES> {
+        let base = self.normal_to_montgomery(data);
+
+        // table[i] = base^i
+        let mut table = [Limbs::ZERO; 1 << WINDOW_BITS];
+        table[0] = self.one;
+        for i in 1..table.len() {
+            table[i] = self.montgomery_mul(&table[i - 1], &base);
+        }
+
+        let mut result = self.one;
+        let bits = Self::LIMBS * 64;
+        for window_start in (0..bits).step_by(WINDOW_BITS).rev() {
+            for _ in 0..WINDOW_BITS {
+                result = self.montgomery_mul(&result, &result);
+            }
+            let limb = exponent[window_start / 64];
+            let window = (limb >> (window_start % 64)) as usize & ((1 << WINDOW_BITS) - 1);
+            if window != 0 {
+                result = self.montgomery_mul(&result, &table[window]);
+            }
+        }
+
+        self.montgomery_to_normal(&result)
+    }
+
+    /// Montgomery multiplication `a * b * R^-1 mod prime` (CIOS method), works for any
+    /// `a * b < prime * R`, result is fully reduced
+    fn montgomery_mul(
+        &self,
+        a: &Limbs<PRIME_SIZE_BYTES>,
+        b: &Limbs<PRIME_SIZE_BYTES>,
+    ) -> Limbs<PRIME_SIZE_BYTES> {
+        let limbs = Self::LIMBS;
+        // Slices are taken once, such that limbs are not dereferenced in the loop
+        let (a, b, prime) = (&a[..limbs], &b[..limbs], &self.prime[..limbs]);
+        let mut t_limbs = Limbs::<PRIME_SIZE_BYTES, 3>::ZERO;
+        let t = &mut t_limbs[..];
+
+        for &b_i in b {
+            // t += a * b[i]
+            let mut carry = 0;
+            for j in 0..limbs {
+                let (low, high) = mac(t[j], a[j], b_i, carry);
+                t[j] = low;
+                carry = high;
+            }
+            let (sum, overflow) = adc(t[limbs], carry, 0);
+            t[limbs] = sum;
+            t[limbs + 1] = overflow;
+
+            // t = (t + m * prime) / 2^64
+            let m = t[0].wrapping_mul(self.prime_inv_neg);
+            let (_, mut carry) = mac(t[0], m, prime[0], 0);
+            for j in 1..limbs {
+                let (low, high) = mac(t[j], m, prime[j], carry);
+                t[j - 1] = low;
+                carry = high;
+            }
+            let (sum, overflow) = adc(t[limbs], carry, 0);
+            t[limbs - 1] = sum;
+            t[limbs] = t[limbs + 1] + overflow;
+        }
+
+        let mut result = Limbs::ZERO;
+        result[..limbs].copy_from_slice(&t[..limbs]);
+        if t[limbs] != 0 || greater_or_equal(&result[..limbs], prime) {
+            sub_assign(&mut result[..limbs], prime);
+        }
+        result
+    }
+
+    /// Converts any number below `R` into Montgomery form
+    fn normal_to_montgomery(&self, a: &Limbs<PRIME_SIZE_BYTES>) -> Limbs<PRIME_SIZE_BYTES> {
+        self.montgomery_mul(a, &self.r_squared)
+    }
+
+    /// Converts number from Montgomery form back into normal form
+    fn montgomery_to_normal(&self, a: &Limbs<PRIME_SIZE_BYTES>) -> Limbs<PRIME_SIZE_BYTES> {
+        self.montgomery_mul(a, &Limbs::from_u64(1))
+    }
+
+    /// `a = 2 * a mod prime` for `a < prime`
+    fn double_mod(&self, a: &mut Limbs<PRIME_SIZE_BYTES>) {
+        let limbs = Self::LIMBS;
+        let copy = *a;
+        let carry = add_assign(&mut a[..limbs], &copy[..limbs]);
+        if carry != 0 || greater_or_equal(&a[..limbs], &self.prime[..limbs]) {
+            sub_assign(&mut a[..limbs], &self.prime[..limbs]);
+        }
+    }
+
+    /// `a = prime - a` for `a <= prime`
+    fn negate(&self, a: &mut Limbs<PRIME_SIZE_BYTES>) {
+        let mut result = self.prime;
+        sub_assign(&mut result[..Self::LIMBS], &a[..Self::LIMBS]);
+        *a = result;
+    }
+
+    fn is_odd(a: &Limbs<PRIME_SIZE_BYTES>) -> bool {
+        a[0] & 1 == 1
+    }
+
+    fn xor_assign(a: &mut Limbs<PRIME_SIZE_BYTES>, b: &Limbs<PRIME_SIZE_BYTES>) {
+        for (a, b) in a.iter_mut().zip(b.iter()).take(Self::LIMBS) {
+            *a ^= b;
+        }
+    }
+
+    fn bytes_to_limbs(bytes: &[u8]) -> Limbs<PRIME_SIZE_BYTES> {
+        let mut limbs = Limbs::ZERO;
+        for (limb, bytes) in limbs.iter_mut().zip(bytes.chunks(8)) {
+            let mut limb_bytes = [0u8; 8];
+            limb_bytes[..bytes.len()].copy_from_slice(bytes);
+            *limb = u64::from_le_bytes(limb_bytes);
+        }
+        limbs
+    }
+
+    fn limbs_to_bytes(limbs: &Limbs<PRIME_SIZE_BYTES>, bytes: &mut [u8]) {
+        for (limb, bytes) in limbs.iter().zip(bytes.chunks_mut(8)) {
+            let bytes_len = bytes.len();
+            bytes.copy_from_slice(&limb.to_le_bytes()[..bytes_len]);
+        }
+    }
+}
+
+impl<const PRIME_SIZE_BYTES: usize, const PIECE_SIZE_BYTES: usize>
+    SlothBackend<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES> for Sloth<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>
+{
+    // Blocks are converted to limbs one at a time on the stack, so there is nothing to prepare and
+    // no working memory is needed
+    type PreparedPiece = [u8; PIECE_SIZE_BYTES];
+    type Scratch = ();
+
+    fn prime(&self) -> [u8; PRIME_SIZE_BYTES] {
+        let mut prime = [0u8; PRIME_SIZE_BYTES];
+        Self::limbs_to_bytes(&self.prime, &mut prime);
+        prime
+    }
+
+    fn block_mapping(&self) -> BlockMapping {
+        self.block_mapping
+    }
+
+    fn encode(
+        &self,
+        piece: &mut [u8; PIECE_SIZE_BYTES],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+    ) -> Result<(), EncodeError> {
+        Sloth::encode(self, piece, expanded_iv, layers)
+    }
+
+    fn decode(
+        &self,
+        piece: &mut [u8; PIECE_SIZE_BYTES],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+    ) -> Result<(), DecodeError> {
+        Sloth::decode(self, piece, expanded_iv, layers)
+    }
+
+    fn encode_with_scratch(
+        &self,
+        piece: &mut [u8; PIECE_SIZE_BYTES],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        _scratch: &mut (),
+    ) -> Result<(), EncodeError> {
+        Sloth::encode(self, piece, expanded_iv, layers)
+    }
+
+    fn decode_with_scratch(
+        &self,
+        piece: &mut [u8; PIECE_SIZE_BYTES],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        _scratch: &mut (),
+    ) -> Result<(), DecodeError> {
+        Sloth::decode(self, piece, expanded_iv, layers)
+    }
+
+    fn prepare_piece(&self, piece: &Piece<PIECE_SIZE_BYTES>) -> [u8; PIECE_SIZE_BYTES] {
+        *piece.as_bytes()
+    }
+
+    fn encode_prepared(
+        &self,
+        piece: &[u8; PIECE_SIZE_BYTES],
+        encoding: &mut Encoding<PIECE_SIZE_BYTES>,
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        _scratch: &mut (),
+    ) -> Result<(), EncodeError> {
+        *encoding.as_bytes_mut() = *piece;
+        Sloth::encode(self, encoding.as_bytes_mut(), expanded_iv, layers)
+    }
+
+    fn encode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        _scratch: &mut (),
+    ) -> Result<(), EncodeError> {
+        Sloth::encode_slice(self, piece, expanded_iv, layers)
+    }
+
+    fn decode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<PRIME_SIZE_BYTES>,
+        layers: usize,
+        _scratch: &mut (),
+    ) -> Result<(), DecodeError> {
+        Sloth::decode_slice(self, piece, expanded_iv, layers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_vectors::KNOWN_PIECE_ENCODING;
+    use rand::prelude::*;
+
+    fn random_bytes<const BYTES: usize>() -> [u8; BYTES] {
+        let mut bytes = [0u8; BYTES];
+        rand::thread_rng().fill(&mut bytes[..]);
+        bytes
+    }
+
+    fn largest_prime<const PRIME_SIZE_BYTES: usize>() -> [u8; PRIME_SIZE_BYTES] {
+        crate::largest_known_prime().unwrap()
+    }
+
+    // 256 bits
+    #[test]
+    fn test_random_piece_256_bits() {
+        test_random_piece::<32, 4096>();
+    }
+
+    // 512 bits
+    #[test]
+    fn test_random_piece_512_bits() {
+        test_random_piece::<64, 4096>();
+    }
+
+    // 1024 bits
+    #[test]
+    fn test_random_piece_1024_bits() {
+        test_random_piece::<128, 4096>();
+    }
+
+    // 2048 bits
+    #[test]
+    fn test_random_piece_2048_bits() {
+        test_random_piece::<256, 4096>();
+    }
+
+    // 4096 bits
+    #[test]
+    fn test_random_piece_4096_bits() {
+        test_random_piece::<512, 4096>();
+    }
+
+    fn test_random_piece<const PRIME_SIZE_BYTES: usize, const PIECE_SIZE_BYTES: usize>() {
+        let expanded_iv = ExpandedIv::from(random_bytes());
+        let piece = random_bytes();
+
+        let sloth = Sloth::<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>::with_prime(largest_prime());
+        let layers = PIECE_SIZE_BYTES / PRIME_SIZE_BYTES;
+        let mut encoding = piece;
+        sloth.encode(&mut encoding, expanded_iv, layers).unwrap();
+        let mut decoding = encoding;
+        sloth.decode(&mut decoding, expanded_iv, layers).unwrap();
+
+        assert_eq!(piece.to_vec(), decoding.to_vec());
+    }
+
+    #[test]
+    fn test_known_piece() {
+        let expanded_iv = ExpandedIv::from([3u8; 32]);
+        let piece = [5u8; 4096];
+
+        let sloth = Sloth::with_prime(largest_prime::<32>());
+        let layers = 1;
+        let mut encoding = piece;
+        sloth.encode(&mut encoding, expanded_iv, layers).unwrap();
+        assert_eq!(encoding, KNOWN_PIECE_ENCODING);
+        let mut decoding = encoding;
+        sloth.decode(&mut decoding, expanded_iv, layers).unwrap();
+
+        assert_eq!(piece.to_vec(), decoding.to_vec());
+    }
+
+    #[test]
+    fn test_data_bigger_than_prime() {
+        let sloth = Sloth::<32, 4096>::with_prime(largest_prime());
+        let mut prime_piece = [0u8; 4096];
+        prime_piece[..32].copy_from_slice(&largest_prime::<32>());
+
+        for &piece in &[[0xffu8; 4096], prime_piece, [0u8; 4096]] {
+            for layers in 1..=2 {
+                let mut encoding = piece;
+                sloth
+                    .encode(&mut encoding, ExpandedIv::from([0u8; 32]), layers)
+                    .unwrap();
+                let mut decoding = encoding;
+                sloth
+                    .decode(&mut decoding, ExpandedIv::from([0u8; 32]), layers)
+                    .unwrap();
+
+                assert_eq!(piece.to_vec(), decoding.to_vec());
+            }
+        }
+    }
+
+    #[test]
+    fn test_block_mapping() {
+        let sloth = Sloth::<32, 4096>::with_prime(largest_prime());
+        let rotating = sloth.clone().with_block_mapping(BlockMapping::Rotate);
+        assert_eq!(SlothBackend::block_mapping(&sloth), BlockMapping::Swap);
+        assert_eq!(SlothBackend::block_mapping(&rotating), BlockMapping::Rotate);
+
+        let mut prime_piece = [0u8; 4096];
+        prime_piece[..32].copy_from_slice(&largest_prime::<32>());
+
+        for &piece in &[[0xffu8; 4096], prime_piece, [0u8; 4096]] {
+            for layers in 1..=3 {
+                let mut encoding = piece;
+                rotating
+                    .encode(&mut encoding, ExpandedIv::from([0u8; 32]), layers)
+                    .unwrap();
+                let mut decoding = encoding;
+                rotating
+                    .decode(&mut decoding, ExpandedIv::from([0u8; 32]), layers)
+                    .unwrap();
+                assert_eq!(piece.to_vec(), decoding.to_vec());
+            }
+        }
+
+        // Blocks of `0xff` bytes are only left as is by the default mapping
+        let mut encoding = [0xffu8; 4096];
+        sloth
+            .encode(&mut encoding, ExpandedIv::from([0u8; 32]), 1)
+            .unwrap();
+        let mut rotated_encoding = [0xffu8; 4096];
+        rotating
+            .encode(&mut rotated_encoding, ExpandedIv::from([0u8; 32]), 1)
+            .unwrap();
+        assert_ne!(encoding.to_vec(), rotated_encoding.to_vec());
+
+        // Zero, prime and numbers above prime are rotated
+        let prime = Sloth::<32, 4096>::bytes_to_limbs(&largest_prime::<32>());
+        let max = Sloth::<32, 4096>::bytes_to_limbs(&[0xff; 32]);
+        let mut next_after_prime = prime;
+        next_after_prime[0] += 1;
+        for (block, expected) in [
+            (Limbs::ZERO, prime),
+            (prime, next_after_prime),
+            (max, Limbs::ZERO),
+        ] {
+            let mut mapped = block;
+            rotating.sqrt_permutation(&mut mapped);
+            assert_eq!(mapped[..4], expected[..4]);
+            rotating.inverse_sqrt(&mut mapped);
+            assert_eq!(mapped[..4], block[..4]);
+        }
+
+        // Most of the block is outside of the field with a small prime
+        let mut small_prime = [0u8; 32];
+        small_prime[..16].copy_from_slice(&(u128::MAX >> 1).to_le_bytes());
+        let rotating =
+            Sloth::<32, 4096>::with_prime(small_prime).with_block_mapping(BlockMapping::Rotate);
+        let expanded_iv = ExpandedIv::from(random_bytes());
+        let piece = random_bytes::<4096>();
+        let mut encoding = piece;
+        rotating.encode(&mut encoding, expanded_iv, 2).unwrap();
+        rotating.decode(&mut encoding, expanded_iv, 2).unwrap();
+        assert_eq!(encoding.to_vec(), piece.to_vec());
+    }
+
+    #[test]
+    fn test_invalid_prime() {
+        // 2^255 - 19 is prime, but `1 mod 4`
+        let mut one_mod_four = [0xffu8; 32];
+        one_mod_four[0] = 0xed;
+        one_mod_four[31] = 0x7f;
+        assert_eq!(
+            Sloth::<32, 4096>::try_with_prime(one_mod_four).unwrap_err(),
+            ParametersError::PrimeNotThreeModFour
+        );
+        // 2^256 - 1 is divisible by 3
+        assert_eq!(
+            Sloth::<32, 4096>::try_with_prime([0xff; 32]).unwrap_err(),
+            ParametersError::NotPrime
+        );
+        // 2^256 - 193 is divisible by 3
+        let mut composite = largest_prime::<32>();
+        composite[0] -= 4;
+        assert_eq!(
+            Sloth::<32, 4096>::try_with_prime(composite).unwrap_err(),
+            ParametersError::NotPrime
+        );
+        // 3215031751 is `3 mod 4` and a strong pseudoprime to bases 2, 3, 5 and 7
+        let mut pseudoprime = [0u8; 32];
+        pseudoprime[..8].copy_from_slice(&3215031751u64.to_le_bytes());
+        assert_eq!(
+            Sloth::<32, 4096>::try_with_prime(pseudoprime).unwrap_err(),
+            ParametersError::NotPrime
+        );
+        // 23 is a tiny prime, for which most of pseudo-random bases are reduced to 0, 1 or -1
+        let mut tiny = [0u8; 32];
+        tiny[0] = 23;
+        assert!(Sloth::<32, 4096>::try_with_prime(tiny).is_ok());
+        assert!(Sloth::<64, 4096>::try_with_prime(largest_prime()).is_ok());
+    }
+
+    #[test]
+    fn test_size_follows_prime_size() {
+        assert_eq!(core::mem::size_of::<Limbs<32>>(), 40);
+        assert_eq!(core::mem::size_of::<Limbs<36>>(), 48);
+        assert_eq!(Limbs::<36>::ZERO.len(), 5);
+        assert_eq!(Limbs::<32, 3>::ZERO.len(), 6);
+        assert!(
+            core::mem::size_of::<Sloth<32, 4096>>() * 8 < core::mem::size_of::<Sloth<512, 4096>>()
+        );
+    }
+
+    #[test]
+    fn test_pseudo_mersenne_matches_montgomery() {
+        let specialized = Sloth::<32, 4096>::with_prime(largest_prime());
+        assert!(specialized.pseudo_mersenne);
+        let mut montgomery = specialized.clone();
+        montgomery.pseudo_mersenne = false;
+
+        let mut prime_piece = [0u8; 4096];
+        prime_piece[..32].copy_from_slice(&largest_prime::<32>());
+        for &piece in &[random_bytes(), [0xffu8; 4096], prime_piece, [5u8; 4096]] {
+            let expanded_iv = ExpandedIv::from(random_bytes());
+            for layers in 1..=2 {
+                let mut encoding = piece;
+                specialized
+                    .encode(&mut encoding, expanded_iv, layers)
+                    .unwrap();
+                let mut expected_encoding = piece;
+                montgomery
+                    .encode(&mut expected_encoding, expanded_iv, layers)
+                    .unwrap();
+                assert_eq!(encoding.to_vec(), expected_encoding.to_vec());
+
+                let mut decoding = encoding;
+                specialized
+                    .decode(&mut decoding, expanded_iv, layers)
+                    .unwrap();
+                let mut expected_decoding = encoding;
+                montgomery
+                    .decode(&mut expected_decoding, expanded_iv, layers)
+                    .unwrap();
+                assert_eq!(decoding.to_vec(), expected_decoding.to_vec());
+            }
+        }
+    }
+
+    #[cfg(feature = "software")]
+    #[test]
+    fn test_matches_software() {
+        test_matches_software_with::<32, 4096>();
+        test_matches_software_with::<64, 4096>();
+    }
+
+    #[cfg(feature = "software")]
+    fn test_matches_software_with<const PRIME_SIZE_BYTES: usize, const PIECE_SIZE_BYTES: usize>() {
+        use crate::software;
+
+        let software_sloth = software::Sloth::<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>::with_prime(
+            software::largest_prime(PRIME_SIZE_BYTES as u32),
+        );
+        let sloth = Sloth::<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>::with_prime(largest_prime());
+        assert_eq!(
+            SlothBackend::prime(&sloth),
+            SlothBackend::prime(&software_sloth)
+        );
+        assert_eq!(SlothBackend::prime(&sloth), largest_prime());
+
+        let expanded_iv = ExpandedIv::from(random_bytes());
+        let piece = random_bytes();
+
+        let mut encoding = piece;
+        sloth.encode(&mut encoding, expanded_iv, 2).unwrap();
+        let mut software_encoding = piece;
+        software_sloth
+            .encode(&mut software_encoding, expanded_iv, 2)
+            .unwrap();
+        assert_eq!(encoding.to_vec(), software_encoding.to_vec());
+
+        // Blocks bigger than prime
+        let mut encoding = [0xffu8; PIECE_SIZE_BYTES];
+        sloth.encode(&mut encoding, expanded_iv, 2).unwrap();
+        let mut software_encoding = [0xffu8; PIECE_SIZE_BYTES];
+        software_sloth
+            .encode(&mut software_encoding, expanded_iv, 2)
+            .unwrap();
+        assert_eq!(encoding.to_vec(), software_encoding.to_vec());
+
+        let encoding = [0xffu8; PIECE_SIZE_BYTES];
+        let mut decoding = encoding;
+        sloth.decode(&mut decoding, expanded_iv, 2).unwrap();
+        let mut software_decoding = encoding;
+        software_sloth
+            .decode(&mut software_decoding, expanded_iv, 2)
+            .unwrap();
+        assert_eq!(decoding.to_vec(), software_decoding.to_vec());
+
+        // Piece of different size
+        let piece = (0..PRIME_SIZE_BYTES * 3)
+            .map(|_| rand::random())
+            .collect::<Vec<u8>>();
+        let mut encoding = piece.clone();
+        sloth.encode_slice(&mut encoding, expanded_iv, 2).unwrap();
+        let mut software_encoding = piece.clone();
+        software_sloth
+            .encode_slice(
+                &mut software_encoding,
+                expanded_iv,
+                2,
+                &mut Default::default(),
+            )
+            .unwrap();
+        assert_eq!(encoding, software_encoding);
+        sloth.decode_slice(&mut encoding, expanded_iv, 2).unwrap();
+        assert_eq!(encoding, piece);
+
+        // Blocks bigger than prime with non-default block mapping
+        let sloth = sloth.with_block_mapping(BlockMapping::Rotate);
+        let software_sloth = software_sloth.with_block_mapping(BlockMapping::Rotate);
+        let mut encoding = [0xffu8; PIECE_SIZE_BYTES];
+        sloth.encode(&mut encoding, expanded_iv, 2).unwrap();
+        let mut software_encoding = [0xffu8; PIECE_SIZE_BYTES];
+        software_sloth
+            .encode(&mut software_encoding, expanded_iv, 2)
+            .unwrap();
+        assert_eq!(encoding.to_vec(), software_encoding.to_vec());
+    }
+}
@@ -0,0 +1,333 @@
+//! Parts of implementations specialized for 256-bit prime
+//! 115792089237316195423570985008687907853269984665640564039457584007913129639747
+//!
+//! Prime is `2^256 - 189`, numbers are stored as 4 64-bit limbs. Implementations only differ in how
+//! they multiply and square numbers (see [`Arithmetic`]), while the sqrt permutation, conversion of
+//! blocks and chaining are shared and live here.
+
+/// Number of bytes in one block (and in prime)
+pub(crate) const BLOCK_SIZE_BYTES: usize = 32;
+/// Number of bytes in one piece
+#[cfg(any(all(target_arch = "x86_64", feature = "std"), target_arch = "aarch64"))]
+pub(crate) const PIECE_SIZE_BYTES: usize = 4096;
+/// Number of blocks in one piece
+#[cfg(any(all(target_arch = "x86_64", feature = "std"), target_arch = "aarch64"))]
+pub(crate) const BLOCKS_PER_PIECE: usize = PIECE_SIZE_BYTES / BLOCK_SIZE_BYTES;
+
+/// Field element as little-endian 64-bit limbs
+pub(crate) type Limbs = [u64; 4];
+
+/// Prime `2^256 - 189` as little-endian bytes, the same as [`crate::largest_known_prime()`] returns
+pub(crate) const PRIME_BYTES: [u8; BLOCK_SIZE_BYTES] = [
+    0x43, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+];
+
+/// Prime `2^256 - 189` as little-endian 64-bit limbs
+pub(crate) const PRIME: Limbs = [
+    0xffff_ffff_ffff_ff43,
+    0xffff_ffff_ffff_ffff,
+    0xffff_ffff_ffff_ffff,
+    0xffff_ffff_ffff_ffff,
+];
+
+/// Multiplication and squaring modulo prime, the only part that differs between implementations
+///
+/// Results are below `2^256`, but not necessarily fully reduced.
+pub(crate) trait Arithmetic {
+    /// Multiplies two numbers modulo prime
+    fn mul(a: &Limbs, b: &Limbs) -> Limbs;
+
+    /// Squares a number modulo prime
+    fn sqr(a: &Limbs) -> Limbs;
+}
+
+/// Returns `a + small` and whether addition overflowed `2^256`
+#[inline(always)]
+pub(crate) fn add_small(a: &Limbs, small: u64) -> (Limbs, bool) {
+    let mut result = [0u64; 4];
+    let mut carry = small;
+    for (result, &limb) in result.iter_mut().zip(a.iter()) {
+        let (sum, overflow) = limb.overflowing_add(carry);
+        *result = sum;
+        carry = overflow as u64;
+    }
+    (result, carry == 1)
+}
+
+/// Checks whether number is smaller than prime
+#[inline(always)]
+fn is_below_prime(a: &Limbs) -> bool {
+    !add_small(a, 189).1
+}
+
+/// Fully reduces number below `2^256` modulo prime
+#[inline(always)]
+pub(crate) fn reduce_full(a: &Limbs) -> Limbs {
+    // `a >= prime` if and only if `a + 189 >= 2^256`, in which case `a + 189 - 2^256` is `a - prime`
+    match add_small(a, 189) {
+        (reduced, true) => reduced,
+        (_, false) => *a,
+    }
+}
+
+/// Computes `prime - a` for fully reduced `a`
+#[inline(always)]
+pub(crate) fn negate(a: &Limbs) -> Limbs {
+    let mut result = [0u64; 4];
+    let mut borrow = false;
+    for ((result, &p), &limb) in result.iter_mut().zip(PRIME.iter()).zip(a.iter()) {
+        let (difference, overflow_1) = p.overflowing_sub(limb);
+        let (difference, overflow_2) = difference.overflowing_sub(borrow as u64);
+        *result = difference;
+        borrow = overflow_1 | overflow_2;
+    }
+    result
+}
+
+#[inline(always)]
+fn is_odd(a: &Limbs) -> bool {
+    a[0] & 1 == 1
+}
+
+#[inline(always)]
+fn is_zero(a: &Limbs) -> bool {
+    a.iter().all(|&limb| limb == 0)
+}
+
+#[inline(always)]
+pub(crate) fn xor_assign(a: &mut Limbs, b: &Limbs) {
+    for (a, b) in a.iter_mut().zip(b.iter()) {
+        *a ^= b;
+    }
+}
+
+pub(crate) fn bytes_to_limbs(bytes: &[u8]) -> Limbs {
+    let mut limbs = [0u64; 4];
+    for (limb, bytes) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+        let mut limb_bytes = [0u8; 8];
+        limb_bytes.copy_from_slice(bytes);
+        *limb = u64::from_le_bytes(limb_bytes);
+    }
+    limbs
+}
+
+pub(crate) fn limbs_to_bytes(limbs: &Limbs, bytes: &mut [u8]) {
+    for (limb, bytes) in limbs.iter().zip(bytes.chunks_exact_mut(8)) {
+        bytes.copy_from_slice(&limb.to_le_bytes());
+    }
+}
+
+/// Maps numbers that don't have a square root picked for them: prime is mapped to zero, zero to
+/// prime (zero is not a quadratic residue according to Jacobi symbol) and numbers bigger than prime
+/// are left as is
+///
+/// The mapping is its own inverse, so it is shared by sqrt permutation and its inverse. Returns
+/// `false` without changing `data` if it is a non-zero number below prime.
+#[inline(always)]
+pub(crate) fn permute_outside_field(data: &mut Limbs) -> bool {
+    if is_zero(data) {
+        *data = PRIME;
+        return true;
+    }
+    if !is_below_prime(data) {
+        if *data == PRIME {
+            *data = [0u64; 4];
+        }
+        return true;
+    }
+    false
+}
+
+/// Picks the result of sqrt permutation of non-zero `data` below prime given
+/// `root = data^((prime + 1) / 4)` and `square = root^2` (both fully reduced)
+///
+/// Quadratic residues are mapped to even square root, non-residues are mapped to odd square root of
+/// `prime - data`, which is the same as in software implementation.
+#[inline(always)]
+pub(crate) fn select_root(data: &Limbs, root: &Limbs, square: &Limbs) -> Limbs {
+    // Since `(prime + 1) / 4` is odd, for non-residue `data` this is the negation of square root of
+    // `prime - data`
+    let is_quadratic_residue = square == data;
+
+    if is_quadratic_residue == is_odd(root) {
+        negate(root)
+    } else {
+        *root
+    }
+}
+
+/// Inverts [`select_root()`] given `square = data^2` (fully reduced)
+#[inline(always)]
+pub(crate) fn select_square(data: &Limbs, square: &Limbs) -> Limbs {
+    if is_odd(data) {
+        negate(square)
+    } else {
+        *square
+    }
+}
+
+/// Squares a number modulo prime `n` times
+#[inline(always)]
+fn sqr_n<A: Arithmetic>(a: &Limbs, n: usize) -> Limbs {
+    let mut result = *a;
+    for _ in 0..n {
+        result = A::sqr(&result);
+    }
+    result
+}
+
+/// Raises number to the power of `(prime + 1) / 4 = 2^254 - 47` using fixed addition chain, result
+/// is fully reduced
+///
+/// Exponent consists of 248 ones followed by `010001` in binary.
+fn pow_sqrt_exponent<A: Arithmetic>(x: &Limbs) -> Limbs {
+    // `x_n` is `x^(2^n - 1)`
+    let x2 = A::mul(&A::sqr(x), x);
+    let x3 = A::mul(&A::sqr(&x2), x);
+    let x6 = A::mul(&sqr_n::<A>(&x3, 3), &x3);
+    let x12 = A::mul(&sqr_n::<A>(&x6, 6), &x6);
+    let x24 = A::mul(&sqr_n::<A>(&x12, 12), &x12);
+    let x48 = A::mul(&sqr_n::<A>(&x24, 24), &x24);
+    let x96 = A::mul(&sqr_n::<A>(&x48, 48), &x48);
+    let x192 = A::mul(&sqr_n::<A>(&x96, 96), &x96);
+    let x240 = A::mul(&sqr_n::<A>(&x192, 48), &x48);
+    let x246 = A::mul(&sqr_n::<A>(&x240, 6), &x6);
+    let x248 = A::mul(&sqr_n::<A>(&x246, 2), &x2);
+    // Append `01` and `0001`
+    let result = A::mul(&sqr_n::<A>(&x248, 2), x);
+    let result = A::mul(&sqr_n::<A>(&result, 4), x);
+
+    reduce_full(&result)
+}
+
+/// Computes the modular square root of data, see [`permute_outside_field()`] and [`select_root()`]
+/// for how the root is picked
+pub(crate) fn sqrt_permutation<A: Arithmetic>(data: &mut Limbs) {
+    if permute_outside_field(data) {
+        return;
+    }
+
+    let root = pow_sqrt_exponent::<A>(data);
+    let square = reduce_full(&A::sqr(&root));
+    *data = select_root(data, &root, &square);
+}
+
+/// Inverts the sqrt permutation with a single squaring mod prime
+fn inverse_sqrt<A: Arithmetic>(data: &mut Limbs) {
+    if permute_outside_field(data) {
+        return;
+    }
+
+    let square = reduce_full(&A::sqr(data));
+    *data = select_square(data, &square);
+}
+
+/// Converts a 4096 byte piece into an array of blocks in limbs representation
+#[cfg(any(all(target_arch = "x86_64", feature = "std"), target_arch = "aarch64"))]
+pub(crate) fn piece_to_blocks(piece: &[u8; PIECE_SIZE_BYTES]) -> [Limbs; BLOCKS_PER_PIECE] {
+    let mut blocks = [[0u64; 4]; BLOCKS_PER_PIECE];
+    for (block, bytes) in blocks.iter_mut().zip(piece.chunks_exact(BLOCK_SIZE_BYTES)) {
+        *block = bytes_to_limbs(bytes);
+    }
+    blocks
+}
+
+/// Converts an array of blocks in limbs representation back into 4096 byte piece
+#[cfg(any(all(target_arch = "x86_64", feature = "std"), target_arch = "aarch64"))]
+pub(crate) fn blocks_to_piece(
+    blocks: &[Limbs; BLOCKS_PER_PIECE],
+    piece: &mut [u8; PIECE_SIZE_BYTES],
+) {
+    for (block, bytes) in blocks.iter().zip(piece.chunks_exact_mut(BLOCK_SIZE_BYTES)) {
+        limbs_to_bytes(block, bytes);
+    }
+}
+
+/// Applies the block cipher to blocks of a piece
+#[cfg(any(all(target_arch = "x86_64", feature = "std"), target_arch = "aarch64"))]
+pub(crate) fn encode_blocks<A: Arithmetic>(
+    blocks: &mut [Limbs; BLOCKS_PER_PIECE],
+    expanded_iv: &[u8; BLOCK_SIZE_BYTES],
+    layers: usize,
+) {
+    // init feedback as expanded IV
+    let mut feedback = bytes_to_limbs(expanded_iv);
+
+    for _ in 0..layers {
+        for block in blocks.iter_mut() {
+            // xor block with feedback
+            xor_assign(block, &feedback);
+
+            // apply sqrt permutation
+            sqrt_permutation::<A>(block);
+
+            // carry forward the feedback
+            feedback = *block;
+        }
+    }
+}
+
+/// Applies the block cipher to a piece of any length that is a multiple of block size in place,
+/// blocks are converted to limbs one at a time
+pub(crate) fn encode_bytes<A: Arithmetic>(
+    piece: &mut [u8],
+    expanded_iv: &[u8; BLOCK_SIZE_BYTES],
+    layers: usize,
+) {
+    // init feedback as expanded IV
+    let mut feedback = bytes_to_limbs(expanded_iv);
+
+    for _ in 0..layers {
+        for block_bytes in piece.chunks_exact_mut(BLOCK_SIZE_BYTES) {
+            let mut block = bytes_to_limbs(block_bytes);
+
+            // xor block with feedback
+            xor_assign(&mut block, &feedback);
+
+            // apply sqrt permutation
+            sqrt_permutation::<A>(&mut block);
+
+            limbs_to_bytes(&block, block_bytes);
+
+            // carry forward the feedback
+            feedback = block;
+        }
+    }
+}
+
+/// Decodes a piece of any length that is a multiple of block size in place, see [`encode_bytes()`]
+pub(crate) fn decode_bytes<A: Arithmetic>(
+    piece: &mut [u8],
+    expanded_iv: &[u8; BLOCK_SIZE_BYTES],
+    layers: usize,
+) {
+    let blocks = piece.len() / BLOCK_SIZE_BYTES;
+
+    for layer in 0..layers {
+        for i in (1..blocks).rev() {
+            let (previous, current) = piece.split_at_mut(i * BLOCK_SIZE_BYTES);
+            let mut block = bytes_to_limbs(&current[..BLOCK_SIZE_BYTES]);
+            inverse_sqrt::<A>(&mut block);
+            xor_assign(
+                &mut block,
+                &bytes_to_limbs(&previous[previous.len() - BLOCK_SIZE_BYTES..]),
+            );
+            limbs_to_bytes(&block, &mut current[..BLOCK_SIZE_BYTES]);
+        }
+        let mut block = bytes_to_limbs(&piece[..BLOCK_SIZE_BYTES]);
+        inverse_sqrt::<A>(&mut block);
+        if layer != layers - 1 {
+            // At this point last block is already decoded, so we can use it as an IV to previous
+            // iteration
+            xor_assign(
+                &mut block,
+                &bytes_to_limbs(&piece[piece.len() - BLOCK_SIZE_BYTES..]),
+            );
+        } else {
+            // remove the IV (last round)
+            xor_assign(&mut block, &bytes_to_limbs(expanded_iv));
+        }
+        limbs_to_bytes(&block, &mut piece[..BLOCK_SIZE_BYTES]);
+    }
+}
@@ -0,0 +1,142 @@
+//! Pure Rust arithmetic specialized for 256-bit prime
+//! 115792089237316195423570985008687907853269984665640564039457584007913129639747
+//!
+//! Prime is `2^256 - 189`, so blocks are stored as 4 64-bit limbs and multiplication results are
+//! reduced by folding upper half multiplied by 189 into the lower half, which is much faster than
+//! generic modular arithmetic. Used automatically by `software` and `portable` implementations when
+//! they are initialized with this prime, produces exactly the same results.
+
+use crate::prime_256::{self, add_small, Arithmetic, Limbs, BLOCK_SIZE_BYTES};
+
+/// Multiplies two 256-bit numbers into 512-bit product
+#[inline(always)]
+fn mul_wide(a: &Limbs, b: &Limbs) -> [u64; 8] {
+    let mut wide = [0u64; 8];
+    for (i, &a_i) in a.iter().enumerate() {
+        let mut carry = 0;
+        for (j, &b_j) in b.iter().enumerate() {
+            let result = wide[i + j] as u128 + a_i as u128 * b_j as u128 + carry as u128;
+            wide[i + j] = result as u64;
+            carry = (result >> 64) as u64;
+        }
+        wide[i + 4] = carry;
+    }
+    wide
+}
+
+/// Squares 256-bit number into 512-bit product, cross products are only computed once
+#[inline(always)]
+fn sqr_wide(a: &Limbs) -> [u64; 8] {
+    let mut wide = [0u64; 8];
+    for i in 0..4 {
+        let mut carry = 0;
+        for j in (i + 1)..4 {
+            let result = wide[i + j] as u128 + a[i] as u128 * a[j] as u128 + carry as u128;
+            wide[i + j] = result as u64;
+            carry = (result >> 64) as u64;
+        }
+        wide[i + 4] = carry;
+    }
+
+    // Double cross products (they are below `2^511`, so can't overflow)
+    for i in (1..8).rev() {
+        wide[i] = (wide[i] << 1) | (wide[i - 1] >> 63);
+    }
+    wide[0] <<= 1;
+
+    // Add squares of limbs on the diagonal
+    let mut carry = 0;
+    for (i, &a_i) in a.iter().enumerate() {
+        let square = a_i as u128 * a_i as u128;
+        let low = wide[2 * i] as u128 + (square as u64) as u128 + carry as u128;
+        wide[2 * i] = low as u64;
+        let high = wide[2 * i + 1] as u128 + (square >> 64) + (low >> 64);
+        wide[2 * i + 1] = high as u64;
+        carry = (high >> 64) as u64;
+    }
+    wide
+}
+
+/// Reduces 512-bit number modulo prime, result is below `2^256`, but may not be below prime
+#[inline(always)]
+fn reduce(wide: &[u64; 8]) -> Limbs {
+    // Lower half plus upper half multiplied by 189 (`2^256 mod prime`), top word is at most 190
+    let mut result = [0u64; 4];
+    let mut carry = 0;
+    for (i, result) in result.iter_mut().enumerate() {
+        let sum = wide[i] as u128 + wide[i + 4] as u128 * 189 + carry as u128;
+        *result = sum as u64;
+        carry = (sum >> 64) as u64;
+    }
+
+    // Fold small top word multiplied by 189 back in
+    let (result, overflow) = add_small(&result, carry * 189);
+    // Fold potential carry once more, can't overflow again
+    add_small(&result, overflow as u64 * 189).0
+}
+
+/// Arithmetic in pure Rust with 128-bit intermediate products
+pub(crate) struct PureRust;
+
+impl Arithmetic for PureRust {
+    #[inline(always)]
+    fn mul(a: &Limbs, b: &Limbs) -> Limbs {
+        reduce(&mul_wide(a, b))
+    }
+
+    #[inline(always)]
+    fn sqr(a: &Limbs) -> Limbs {
+        reduce(&sqr_wide(a))
+    }
+}
+
+/// Sequentially encodes a piece (of any size that is a multiple of 32 bytes) in place
+pub(crate) fn encode(piece: &mut [u8], expanded_iv: &[u8; BLOCK_SIZE_BYTES], layers: usize) {
+    prime_256::encode_bytes::<PureRust>(piece, expanded_iv, layers);
+}
+
+/// Decodes a piece (of any size that is a multiple of 32 bytes) in place
+pub(crate) fn decode(piece: &mut [u8], expanded_iv: &[u8; BLOCK_SIZE_BYTES], layers: usize) {
+    prime_256::decode_bytes::<PureRust>(piece, expanded_iv, layers);
+}
+
+#[cfg(all(test, feature = "software"))]
+mod tests {
+    use super::*;
+    use crate::prime_256::{reduce_full, PRIME};
+    use rand::prelude::*;
+    use rug::{integer::Order, Integer};
+
+    fn to_integer(limbs: &[u64]) -> Integer {
+        Integer::from_digits(limbs, Order::Lsf)
+    }
+
+    fn random_limbs() -> Limbs {
+        let mut rng = rand::thread_rng();
+        // Numbers close to `2^256` and prime are interesting for reduction
+        match rng.gen_range(0..4) {
+            0 => [rng.gen(), u64::MAX, u64::MAX, u64::MAX],
+            1 => [rng.gen_range(0..256), 0, 0, rng.gen()],
+            _ => rng.gen(),
+        }
+    }
+
+    #[test]
+    fn test_mul_and_sqr_match_gmp() {
+        let prime = to_integer(&PRIME);
+        let edge_cases = [[0; 4], [u64::MAX; 4], PRIME, [1, 0, 0, 0]];
+
+        for i in 0..10_000 {
+            let (a, b) = match edge_cases.get(i) {
+                Some(&a) => (a, [u64::MAX; 4]),
+                None => (random_limbs(), random_limbs()),
+            };
+            let expected = to_integer(&a) * to_integer(&b) % &prime;
+            assert_eq!(to_integer(&reduce_full(&PureRust::mul(&a, &b))), expected);
+
+            let expected = to_integer(&a).square() % &prime;
+            assert_eq!(to_integer(&reduce_full(&PureRust::sqr(&a))), expected);
+            assert_eq!(sqr_wide(&a), mul_wide(&a, &a));
+        }
+    }
+}
@@ -0,0 +1,524 @@
+//! Known answer vectors shared by tests of different backends
+
+/// Encoding of a piece filled with `5u8` using expanded IV filled with `3u8`, 256-bit prime
+/// 115792089237316195423570985008687907853269984665640564039457584007913129639747 and 1 layer
+pub(crate) const KNOWN_PIECE_ENCODING: [u8; 4096] = [
+    0x97, 0x70, 0x05, 0xd2, 0x7b, 0x0f, 0x2c, 0x8a, 0x8d, 0x22, 0xd9, 0x7e, 0x6c, 0x62, 0x88, 0xdf,
+    0x5f, 0xaf, 0x1c, 0xc2, 0x90, 0x3e, 0x47, 0x67, 0x98, 0x7b, 0x63, 0x62, 0x7e, 0x8b, 0xd2, 0x0b,
+    0x9e, 0x44, 0x12, 0x48, 0x0a, 0xaa, 0x18, 0xe4, 0x3f, 0xaf, 0xc2, 0xb8, 0x30, 0xad, 0x4b, 0x73,
+    0x4a, 0x5b, 0x94, 0xbf, 0x43, 0x5c, 0xfc, 0xc7, 0x9f, 0xf0, 0x64, 0x7a, 0x89, 0x84, 0x8c, 0xb4,
+    0x37, 0xdf, 0xf1, 0xf0, 0x15, 0x5c, 0x38, 0x13, 0x04, 0x3f, 0x84, 0x70, 0x01, 0x75, 0x84, 0x49,
+    0x54, 0x1f, 0x5e, 0x75, 0xf1, 0x00, 0x0f, 0xe2, 0xe4, 0xd1, 0x08, 0xb7, 0xdf, 0x11, 0x7e, 0xba,
+    0x8d, 0xe8, 0xf5, 0x6e, 0x76, 0x42, 0x88, 0xd8, 0xd8, 0x66, 0xde, 0x48, 0x86, 0xd3, 0xf2, 0xd4,
+    0x78, 0x49, 0x7d, 0xa4, 0x9e, 0xaf, 0x80, 0x60, 0x58, 0x64, 0x01, 0x7c, 0x12, 0x17, 0xea, 0xbb,
+    0x8d, 0x6f, 0x93, 0xed, 0x5d, 0xe0, 0xde, 0x4e, 0x6f, 0x11, 0x80, 0x1a, 0x82, 0x15, 0xd3, 0x1c,
+    0x46, 0xf5, 0x5a, 0xa1, 0x28, 0xcc, 0xc0, 0x29, 0x90, 0x01, 0x72, 0xc9, 0xe4, 0xa7, 0x2e, 0x26,
+    0x2e, 0x97, 0x02, 0xbf, 0x43, 0x46, 0x02, 0xc2, 0x55, 0xd0, 0xfc, 0x40, 0x23, 0xb0, 0xee, 0x9c,
+    0x93, 0x42, 0x98, 0xcb, 0x88, 0xc4, 0x34, 0x7a, 0x17, 0x23, 0x09, 0xed, 0x9f, 0x30, 0x88, 0x28,
+    0xa6, 0x93, 0xea, 0x7c, 0xa0, 0x86, 0xa3, 0xee, 0x5e, 0x79, 0x1d, 0x7e, 0xa1, 0xa3, 0xa6, 0x9e,
+    0x24, 0xb3, 0x76, 0x6c, 0x1c, 0x80, 0xd8, 0x6f, 0x9a, 0xd8, 0xb8, 0x80, 0xf2, 0x31, 0xdd, 0x44,
+    0x3b, 0xc3, 0x2a, 0x89, 0xb7, 0x7a, 0x3a, 0xac, 0xc8, 0xcb, 0x6b, 0xd2, 0x35, 0xd7, 0x13, 0x41,
+    0x25, 0x9f, 0xf8, 0x14, 0x86, 0x67, 0xe8, 0x8e, 0x8b, 0x2d, 0xf2, 0xe0, 0xe1, 0xdf, 0x63, 0xef,
+    0x86, 0xd9, 0x2d, 0x17, 0xfe, 0xaf, 0x52, 0x77, 0x83, 0x15, 0x78, 0x65, 0xf9, 0x60, 0x2c, 0xfc,
+    0x38, 0x2b, 0x62, 0x36, 0x87, 0x4c, 0x08, 0x52, 0x1d, 0xf7, 0xe9, 0x7d, 0xd3, 0xa2, 0xde, 0x5d,
+    0x64, 0xc3, 0xb3, 0x6d, 0x2b, 0xc8, 0x48, 0x44, 0xb7, 0xb3, 0x13, 0xda, 0xbf, 0x2c, 0xbf, 0x2c,
+    0xb2, 0x7b, 0xb8, 0xaa, 0xda, 0x7d, 0xe3, 0xd8, 0x3f, 0x85, 0x66, 0x95, 0x58, 0x5d, 0x9d, 0xf1,
+    0xaf, 0x83, 0xf4, 0x88, 0xc2, 0x78, 0x8d, 0xba, 0x79, 0xb7, 0x97, 0x9e, 0x65, 0x69, 0xf2, 0xdd,
+    0x83, 0x80, 0xab, 0x57, 0x44, 0x98, 0xe4, 0xe6, 0x60, 0x9e, 0xdc, 0x7c, 0x47, 0x29, 0x89, 0xfe,
+    0xd9, 0x36, 0xe3, 0x96, 0x52, 0xd3, 0x27, 0x85, 0x04, 0xf1, 0x7d, 0xce, 0xea, 0xc2, 0xe2, 0x82,
+    0x8b, 0xb7, 0xc7, 0x3b, 0xbb, 0xc2, 0xec, 0xa8, 0x51, 0x92, 0xc1, 0x83, 0xba, 0x2d, 0xd8, 0xa3,
+    0xb5, 0xea, 0xdd, 0x27, 0xc4, 0xfc, 0x9d, 0xf7, 0x6e, 0x93, 0xff, 0x8d, 0x88, 0x5d, 0x4a, 0x81,
+    0xa1, 0x67, 0xcf, 0x57, 0x10, 0x64, 0x5e, 0x54, 0x99, 0x4d, 0xee, 0xd0, 0x71, 0x4d, 0x82, 0xe7,
+    0x48, 0xe9, 0x12, 0xc1, 0x22, 0xd3, 0x2d, 0xda, 0xdc, 0xc6, 0x5e, 0x41, 0xbe, 0x5e, 0xd6, 0x01,
+    0x4c, 0xca, 0xc1, 0x14, 0xaf, 0xf3, 0x8f, 0xb5, 0x6f, 0x15, 0xd8, 0xb2, 0xf3, 0x68, 0x73, 0x04,
+    0xf2, 0x32, 0xfb, 0x01, 0x04, 0x92, 0x0c, 0xa4, 0x00, 0x99, 0xde, 0x58, 0x38, 0xda, 0xb7, 0x9e,
+    0x10, 0x33, 0x4a, 0x7a, 0x7a, 0x68, 0x30, 0x6d, 0x91, 0xa9, 0xe8, 0x3c, 0x82, 0x5f, 0xff, 0x82,
+    0x36, 0x30, 0xe4, 0xde, 0x41, 0x7b, 0x94, 0xbc, 0xb0, 0x2f, 0x16, 0xe7, 0xe3, 0xd2, 0x32, 0xd0,
+    0xee, 0x86, 0x72, 0xe6, 0xf4, 0x4d, 0xe1, 0x53, 0xcb, 0x7b, 0xe2, 0x18, 0xe3, 0xaf, 0xba, 0xe1,
+    0x6d, 0x7e, 0x72, 0xe0, 0x95, 0xb2, 0x8b, 0x2a, 0x37, 0xb2, 0x1c, 0x2a, 0x79, 0x6d, 0x7c, 0x86,
+    0xd9, 0x35, 0x1d, 0x6a, 0xa7, 0x94, 0x55, 0x84, 0x4d, 0x92, 0x4d, 0xf4, 0x88, 0x35, 0x2f, 0x0f,
+    0xb6, 0xc1, 0x1e, 0x1e, 0xca, 0xdb, 0x45, 0x2b, 0x83, 0xc8, 0x86, 0xf0, 0x64, 0x00, 0x16, 0x60,
+    0x43, 0x7c, 0x71, 0xae, 0xde, 0x91, 0x31, 0x27, 0x21, 0x36, 0xf0, 0x88, 0x7e, 0x00, 0xf7, 0xa0,
+    0x60, 0xb5, 0xfa, 0x54, 0xbf, 0xd9, 0x17, 0xde, 0x0b, 0xab, 0x62, 0x0e, 0x07, 0x49, 0x8e, 0x31,
+    0xab, 0x9e, 0x44, 0x49, 0xfe, 0x44, 0xe3, 0xea, 0x06, 0xfd, 0xf2, 0x07, 0x11, 0x57, 0x46, 0xb1,
+    0x54, 0x3a, 0x74, 0x90, 0x45, 0x4d, 0x54, 0x5e, 0x0f, 0x2e, 0xfb, 0x2d, 0x16, 0xe0, 0xdb, 0x82,
+    0xcb, 0x07, 0xff, 0x2a, 0x1d, 0x5f, 0x40, 0xa9, 0x80, 0x0b, 0x53, 0x98, 0xf9, 0xe8, 0x5b, 0x4b,
+    0x8e, 0x72, 0x90, 0xb1, 0x1b, 0x05, 0x37, 0x03, 0x39, 0x58, 0xc7, 0xf5, 0xa0, 0x32, 0xe4, 0xbd,
+    0x39, 0x12, 0x62, 0x36, 0x5a, 0xd2, 0x23, 0x61, 0x10, 0xb8, 0x2f, 0x26, 0xad, 0xd4, 0x2b, 0x30,
+    0x97, 0x5e, 0x61, 0xa1, 0xca, 0x5b, 0x76, 0x3e, 0xa5, 0x04, 0x08, 0x5b, 0x96, 0xbf, 0x86, 0x71,
+    0x68, 0x3d, 0xac, 0x18, 0x5f, 0xd5, 0xda, 0x88, 0x6f, 0xd8, 0x8f, 0xf2, 0xe1, 0xc4, 0x24, 0x52,
+    0xc6, 0x4c, 0xbf, 0x87, 0xdf, 0x60, 0x97, 0x29, 0x25, 0x36, 0x76, 0x7f, 0xb7, 0x39, 0x8d, 0xfd,
+    0xcc, 0xa0, 0xf4, 0x00, 0xa1, 0x16, 0xe2, 0xf2, 0x99, 0xdf, 0x17, 0x20, 0xa7, 0x3d, 0xcd, 0xe0,
+    0xc3, 0x39, 0xde, 0xb8, 0xe0, 0x42, 0xdc, 0x02, 0x86, 0xe1, 0x29, 0x86, 0xfd, 0x83, 0xb8, 0xd0,
+    0xdc, 0x21, 0x41, 0xef, 0x08, 0xcf, 0x6c, 0x95, 0xee, 0x4a, 0xe0, 0xd3, 0xdb, 0xd9, 0xa4, 0x69,
+    0x14, 0x71, 0xcf, 0xcf, 0x61, 0x20, 0x7c, 0x00, 0xbe, 0x12, 0x72, 0x04, 0xcc, 0x20, 0x28, 0xfc,
+    0x32, 0x19, 0x58, 0x3e, 0x71, 0x46, 0x66, 0xf3, 0xbf, 0xcb, 0x03, 0x53, 0x83, 0xb8, 0xa3, 0xf3,
+    0x52, 0x7c, 0x73, 0xc4, 0x16, 0x4f, 0x07, 0x0a, 0xa4, 0xd8, 0x2e, 0xcd, 0xb2, 0x6f, 0xe5, 0xfc,
+    0x7a, 0x3d, 0x49, 0x02, 0xe1, 0xae, 0x36, 0x15, 0xc0, 0x2d, 0xb4, 0x53, 0xca, 0x58, 0x9f, 0x00,
+    0x72, 0x8e, 0x2f, 0xf3, 0xcd, 0x4b, 0x5c, 0xf6, 0x28, 0xf8, 0x65, 0xea, 0xa3, 0xc9, 0xd4, 0xc4,
+    0x45, 0xd6, 0x3f, 0xe0, 0x8b, 0xdd, 0x4d, 0x40, 0xe0, 0x22, 0xba, 0xff, 0xc5, 0x87, 0x76, 0x48,
+    0xb0, 0xe5, 0x32, 0x6a, 0x31, 0x6d, 0x14, 0xfa, 0x11, 0x8a, 0x10, 0xc2, 0x69, 0x23, 0xfa, 0x9f,
+    0x67, 0x4e, 0xc3, 0x18, 0x13, 0xf2, 0x87, 0x2d, 0x8b, 0x98, 0xf9, 0x71, 0xbc, 0xbb, 0xe3, 0xe8,
+    0x76, 0xe2, 0x96, 0xcc, 0xde, 0x75, 0xb4, 0x67, 0xcc, 0xcb, 0x79, 0xd3, 0x00, 0xc0, 0xb7, 0xeb,
+    0x98, 0x0c, 0x66, 0x15, 0x63, 0x1c, 0x5d, 0xdc, 0xff, 0x88, 0x3a, 0x35, 0xd8, 0x25, 0xd6, 0x4b,
+    0xba, 0xb2, 0x55, 0x4a, 0xf3, 0xd3, 0x20, 0x37, 0xd0, 0x58, 0xd3, 0xd2, 0xd7, 0xbc, 0xf5, 0xe1,
+    0x0c, 0xcb, 0x57, 0xee, 0xde, 0xf4, 0x43, 0x6f, 0x22, 0xfe, 0xcf, 0xf1, 0x43, 0xcf, 0x71, 0xb2,
+    0x70, 0x28, 0xc9, 0x30, 0xc7, 0xb5, 0x2d, 0xcd, 0x1f, 0x18, 0xbb, 0x6f, 0x9e, 0x04, 0x50, 0xa3,
+    0x1e, 0xc7, 0x89, 0xf0, 0xcd, 0x90, 0xa3, 0xc6, 0x34, 0x2d, 0x58, 0x2c, 0xdf, 0xbb, 0x55, 0x4a,
+    0x52, 0x20, 0xd0, 0x5b, 0xfe, 0xf4, 0xf6, 0x61, 0x84, 0xea, 0x59, 0x3d, 0x83, 0xfd, 0x07, 0xce,
+    0x44, 0x9b, 0x58, 0x90, 0x96, 0x5f, 0xf9, 0xa6, 0xe2, 0x70, 0x02, 0x40, 0x04, 0x5a, 0xcd, 0xd7,
+    0xc2, 0x16, 0x90, 0x22, 0x43, 0x1c, 0xe2, 0xc6, 0x86, 0x37, 0x83, 0x0d, 0xbb, 0x14, 0xf0, 0xc8,
+    0x5c, 0x72, 0x99, 0xad, 0x18, 0x90, 0x5d, 0x23, 0x35, 0x60, 0x04, 0x6b, 0xe5, 0x80, 0xc3, 0x17,
+    0xd8, 0xb7, 0xd1, 0xa2, 0x08, 0x54, 0x72, 0x1c, 0x59, 0xe5, 0x21, 0xfd, 0x17, 0xa2, 0x82, 0xe7,
+    0xd1, 0x03, 0xbc, 0x6a, 0xce, 0xaa, 0x1a, 0x5b, 0xb2, 0x91, 0x53, 0xfa, 0x5e, 0xad, 0x74, 0x9c,
+    0x7d, 0x57, 0xd1, 0xb9, 0xbc, 0xee, 0xec, 0x3f, 0xd8, 0x21, 0x7e, 0x15, 0x69, 0x2c, 0x7f, 0x3a,
+    0x09, 0xa6, 0x27, 0xe9, 0xaf, 0x3f, 0x71, 0xeb, 0xb7, 0x1f, 0x10, 0xf2, 0x49, 0x7d, 0xcf, 0xc1,
+    0x68, 0x34, 0x20, 0xf8, 0xcc, 0x05, 0xd2, 0x9e, 0xee, 0xc0, 0xf7, 0xc2, 0xc9, 0xf5, 0xa9, 0x7a,
+    0x90, 0x61, 0x9a, 0x2b, 0x5c, 0x52, 0x84, 0xbe, 0xfb, 0x11, 0x32, 0x40, 0xa7, 0xe3, 0x3d, 0x98,
+    0x59, 0xcd, 0xd0, 0x3c, 0xb1, 0x63, 0x7f, 0x91, 0x00, 0xb2, 0x87, 0xb2, 0x8c, 0x73, 0xa2, 0x25,
+    0xc3, 0xec, 0xa2, 0x67, 0x70, 0xa2, 0xa4, 0x31, 0x1b, 0x28, 0x08, 0x1d, 0xb0, 0x5f, 0xab, 0x85,
+    0xba, 0x95, 0xdc, 0xc8, 0x04, 0x3f, 0x9d, 0x9b, 0x61, 0x71, 0xf3, 0x84, 0x04, 0x1d, 0x61, 0x5f,
+    0x2e, 0xcc, 0x22, 0x30, 0x56, 0x14, 0xb6, 0x1b, 0xf7, 0x6a, 0x92, 0xdb, 0xba, 0xc7, 0x9b, 0xdc,
+    0xa0, 0xa3, 0x96, 0x13, 0x90, 0xbc, 0x41, 0xc8, 0x32, 0x2d, 0xf5, 0x89, 0x1c, 0xe4, 0x27, 0xa4,
+    0x9d, 0xb5, 0xf5, 0x95, 0x84, 0x23, 0x29, 0x74, 0x99, 0x0f, 0x71, 0x7c, 0x9e, 0xe5, 0x55, 0x1f,
+    0xc5, 0x77, 0x5e, 0x55, 0x7e, 0x52, 0x1c, 0x6d, 0x90, 0x0c, 0xcb, 0xce, 0xd0, 0x71, 0x83, 0xfc,
+    0xfe, 0xb0, 0xce, 0xcf, 0x92, 0x59, 0x81, 0x92, 0xf2, 0x20, 0xb7, 0xbf, 0x6f, 0x83, 0xe2, 0x36,
+    0x04, 0x56, 0xa9, 0x70, 0x22, 0x3e, 0x39, 0x35, 0x73, 0x72, 0x3a, 0xb8, 0xb2, 0xda, 0x5b, 0x35,
+    0x3e, 0x11, 0x0c, 0xe2, 0x7f, 0x6e, 0xbe, 0xc1, 0x0c, 0xe6, 0x1e, 0x70, 0x68, 0x9f, 0x09, 0x9e,
+    0x38, 0xfe, 0x4c, 0xe8, 0xe6, 0xd5, 0x06, 0x0e, 0xea, 0xbe, 0x06, 0x26, 0xab, 0x15, 0x25, 0x72,
+    0x6d, 0xf5, 0xf2, 0x6b, 0xba, 0xde, 0xae, 0xc5, 0x75, 0x0f, 0xff, 0x2a, 0xfc, 0x5e, 0xd0, 0x26,
+    0x14, 0x1e, 0xd2, 0x8e, 0x6f, 0x1f, 0x8f, 0xad, 0xf9, 0xc4, 0xc1, 0xa1, 0x0b, 0x69, 0x62, 0x84,
+    0x52, 0xc6, 0xbe, 0x99, 0x38, 0x31, 0x69, 0xdf, 0x2f, 0x9d, 0x50, 0xc3, 0x55, 0x82, 0x64, 0x0a,
+    0x62, 0x9c, 0x57, 0x43, 0x9e, 0x4b, 0xc8, 0xe0, 0x08, 0xcb, 0x27, 0xa4, 0xdd, 0x83, 0xcb, 0xd7,
+    0x84, 0xb9, 0x71, 0x4b, 0xdd, 0x18, 0x77, 0x68, 0xd8, 0x80, 0xd9, 0xfe, 0x18, 0x7a, 0x75, 0x80,
+    0x07, 0x7e, 0x52, 0xf1, 0x8a, 0x44, 0xce, 0x68, 0x6f, 0x0a, 0xa9, 0x8a, 0x86, 0x66, 0xb4, 0xfe,
+    0x08, 0xd5, 0x06, 0xda, 0xed, 0x4c, 0x36, 0x51, 0x6e, 0x69, 0xb1, 0x8b, 0x99, 0x39, 0xdb, 0x08,
+    0xdc, 0x4e, 0x68, 0xf6, 0x77, 0x1c, 0x22, 0xcc, 0xfa, 0xa2, 0x83, 0x27, 0x1e, 0x34, 0x6c, 0x18,
+    0x08, 0x88, 0x4b, 0x48, 0x19, 0xd0, 0x23, 0xc3, 0x7c, 0x92, 0xb8, 0x6b, 0x15, 0xe1, 0x85, 0x42,
+    0x69, 0xaf, 0x3f, 0x4f, 0x0b, 0x5d, 0x06, 0xf4, 0x98, 0xfb, 0x5f, 0xb0, 0xb5, 0x9a, 0x2e, 0x2c,
+    0x19, 0xd1, 0xc5, 0xf4, 0xe4, 0xe0, 0x60, 0x3d, 0x38, 0x42, 0xbf, 0x82, 0xe4, 0xc6, 0x98, 0x67,
+    0x91, 0xcc, 0xb3, 0x90, 0x52, 0xd1, 0xac, 0x03, 0x65, 0x49, 0xd9, 0xbb, 0xcc, 0xc3, 0xfe, 0x9d,
+    0x31, 0x3c, 0x54, 0x01, 0x1c, 0xa5, 0x24, 0x1f, 0x2b, 0x06, 0x55, 0xd9, 0x2b, 0xfa, 0x0a, 0x10,
+    0xec, 0x2a, 0x03, 0x0a, 0xf4, 0x51, 0x5b, 0xbe, 0xa1, 0x7b, 0x23, 0x8b, 0xa1, 0x57, 0xe7, 0x06,
+    0xf2, 0x02, 0xb0, 0x4d, 0x05, 0x13, 0x1f, 0x86, 0xe0, 0xb1, 0xf6, 0x6f, 0x5e, 0x7e, 0x49, 0x94,
+    0x72, 0x1a, 0xce, 0x07, 0x91, 0x31, 0xd5, 0x51, 0x9b, 0xab, 0x3b, 0x78, 0x38, 0x8c, 0x70, 0xfa,
+    0xab, 0x3b, 0x3d, 0xb0, 0xda, 0x0e, 0xa4, 0xa0, 0x8d, 0x0a, 0x76, 0x93, 0xed, 0x30, 0x92, 0xb3,
+    0x8f, 0x25, 0x96, 0x66, 0xf7, 0x8e, 0xdd, 0xf6, 0xac, 0x1e, 0xae, 0xc2, 0x50, 0x1e, 0x05, 0xd7,
+    0x11, 0x9f, 0xf9, 0x0a, 0xce, 0x5a, 0x2a, 0x3f, 0xb0, 0xa1, 0x90, 0x3f, 0x64, 0x22, 0x52, 0xc2,
+    0x07, 0x4e, 0x11, 0xcc, 0xaa, 0x3e, 0x9e, 0xa2, 0xef, 0x83, 0x8a, 0x43, 0xdf, 0x3f, 0xb1, 0x6a,
+    0x18, 0xb0, 0x65, 0x8d, 0xa8, 0xdd, 0x8b, 0xc3, 0x52, 0x31, 0x18, 0xfa, 0x7f, 0x69, 0xfe, 0x6f,
+    0x00, 0xb6, 0x2d, 0x52, 0x40, 0xc4, 0x56, 0xee, 0x72, 0x9b, 0xba, 0xf0, 0xa5, 0x87, 0xab, 0xf0,
+    0x97, 0x3a, 0x73, 0x3d, 0x9a, 0x62, 0xb1, 0x41, 0x67, 0x67, 0xdc, 0x0f, 0x3c, 0x69, 0x1c, 0x4a,
+    0x76, 0xf3, 0x3d, 0x0f, 0x11, 0x09, 0x0c, 0xeb, 0xf5, 0x0f, 0x96, 0xa3, 0x95, 0x95, 0x19, 0x5c,
+    0xca, 0x33, 0x36, 0x45, 0x87, 0x63, 0x4b, 0x82, 0x80, 0x13, 0x33, 0x27, 0x34, 0x12, 0x9b, 0x22,
+    0x52, 0x3f, 0x80, 0x1b, 0x3c, 0x03, 0x87, 0xdf, 0xce, 0x76, 0x8f, 0xb2, 0x93, 0x5e, 0x98, 0x52,
+    0xdc, 0x6e, 0x64, 0x3a, 0x5d, 0xb8, 0xed, 0xf7, 0xed, 0xe6, 0x90, 0x40, 0x0b, 0x1d, 0xa1, 0x67,
+    0x70, 0x53, 0x75, 0x56, 0x59, 0xd2, 0xdc, 0xd6, 0xda, 0x92, 0x7d, 0x26, 0x1c, 0x31, 0x1a, 0xde,
+    0x8d, 0x9e, 0x92, 0xdc, 0x07, 0xb0, 0x73, 0x82, 0xd3, 0xc0, 0xd3, 0x73, 0xa8, 0x94, 0xca, 0xb1,
+    0xfb, 0xff, 0xe2, 0xf4, 0xfe, 0xa4, 0x62, 0x37, 0xb4, 0x9a, 0x34, 0x74, 0x63, 0x4d, 0xbc, 0x7d,
+    0xc7, 0x14, 0x05, 0x60, 0xea, 0x42, 0xf3, 0xdd, 0xc5, 0x68, 0x9b, 0x4d, 0x8b, 0x51, 0xb5, 0x9a,
+    0xfe, 0x30, 0x4b, 0x90, 0x0e, 0xdd, 0x9b, 0x45, 0x79, 0x55, 0xce, 0xcc, 0x45, 0xa0, 0x17, 0x84,
+    0xce, 0x33, 0x2c, 0x79, 0x57, 0xf7, 0x2b, 0xe3, 0x58, 0x32, 0x31, 0xfa, 0x80, 0x5d, 0xdb, 0xc3,
+    0x08, 0xc9, 0x24, 0x37, 0x97, 0x7b, 0x3d, 0x95, 0xb9, 0x5f, 0xfb, 0xeb, 0x00, 0x93, 0xe9, 0x84,
+    0x22, 0x7c, 0x8e, 0x15, 0x56, 0x38, 0x20, 0xe9, 0x66, 0xf9, 0x2c, 0x1b, 0x36, 0x25, 0xd6, 0xcf,
+    0xfe, 0x4f, 0x8d, 0xfb, 0x42, 0xeb, 0x5c, 0x72, 0x21, 0xe2, 0x83, 0x9e, 0x1e, 0x65, 0x5e, 0x0b,
+    0xe0, 0x79, 0x7a, 0xc8, 0xd1, 0x37, 0xee, 0x83, 0xa8, 0x3b, 0xd7, 0x1d, 0x75, 0xc9, 0x50, 0x9b,
+    0xd7, 0xe5, 0x2d, 0x2c, 0x59, 0x46, 0x0e, 0x56, 0x10, 0xd7, 0xf5, 0xca, 0x00, 0x22, 0x41, 0xb5,
+    0x34, 0x4a, 0x4a, 0xe9, 0x80, 0x4d, 0x24, 0x01, 0xef, 0xbe, 0xbf, 0xb8, 0x1b, 0xcf, 0xd7, 0x9b,
+    0x48, 0xb6, 0x4a, 0xbc, 0x11, 0x7f, 0xf1, 0xa4, 0xca, 0xa8, 0xda, 0xf6, 0xdc, 0xdb, 0xb7, 0xe6,
+    0xc6, 0x5e, 0x2b, 0x08, 0xae, 0xb9, 0xfe, 0x09, 0xef, 0x82, 0x37, 0x38, 0xc2, 0x25, 0x3a, 0x1f,
+    0x43, 0x33, 0x8d, 0xaf, 0x33, 0xee, 0xf9, 0x80, 0x8f, 0xb2, 0x62, 0xfa, 0x81, 0x2d, 0xd5, 0x79,
+    0x30, 0xf9, 0x13, 0xcb, 0x83, 0x2e, 0x10, 0x8a, 0xc2, 0xcb, 0x43, 0x4e, 0x17, 0x11, 0x29, 0xc6,
+    0x1a, 0x23, 0xcd, 0x1a, 0xfc, 0x21, 0x7a, 0x34, 0x63, 0x37, 0x4b, 0x61, 0x8a, 0x5d, 0xfa, 0x62,
+    0x20, 0x6d, 0xaf, 0x93, 0x01, 0xfa, 0xff, 0x45, 0x43, 0x12, 0xe7, 0x92, 0x6c, 0xff, 0xb9, 0xc8,
+    0x02, 0x98, 0x8f, 0xc5, 0x97, 0x3d, 0x45, 0x2b, 0x6f, 0x11, 0x1e, 0x0e, 0x0e, 0x4c, 0x42, 0x13,
+    0x16, 0x8e, 0xff, 0xa9, 0x84, 0x3b, 0x47, 0x4e, 0x06, 0x45, 0xc3, 0xe3, 0xde, 0x5b, 0x78, 0x8a,
+    0x56, 0x38, 0x05, 0xf8, 0x04, 0xfc, 0xae, 0x3c, 0x35, 0xc3, 0x16, 0xa6, 0xa2, 0xdb, 0x9e, 0x47,
+    0xca, 0x29, 0x1b, 0x6e, 0x19, 0xf0, 0x1f, 0x2e, 0x74, 0x6e, 0x29, 0x3a, 0x77, 0x16, 0xf6, 0x65,
+    0x78, 0x1e, 0x27, 0xdc, 0x22, 0x38, 0xb2, 0x6a, 0x89, 0x05, 0x88, 0x77, 0x6e, 0x5b, 0xbd, 0x86,
+    0x83, 0x18, 0x15, 0x80, 0x21, 0x99, 0x75, 0xfc, 0x95, 0xa7, 0x00, 0xba, 0x5f, 0x53, 0x82, 0xab,
+    0xf5, 0x74, 0x02, 0xe2, 0x9b, 0xf0, 0xa2, 0x30, 0xb8, 0x2c, 0xff, 0x61, 0xb6, 0x61, 0x93, 0xf8,
+    0x84, 0x47, 0x07, 0x09, 0x36, 0xac, 0x63, 0xdf, 0xd0, 0x35, 0xaa, 0x7c, 0x40, 0xe6, 0x2f, 0x74,
+    0x58, 0xa2, 0x43, 0x69, 0x2d, 0x37, 0x2a, 0xe8, 0x0e, 0xd4, 0x33, 0x87, 0xe6, 0xf7, 0xce, 0x54,
+    0x96, 0x2b, 0xcd, 0x7e, 0xde, 0x5d, 0xaf, 0xf4, 0x00, 0x1b, 0x0d, 0xf1, 0x5b, 0x36, 0x14, 0x06,
+    0xc6, 0x52, 0x84, 0x48, 0x1c, 0xaa, 0xb4, 0xf7, 0x03, 0x9f, 0x4c, 0x0b, 0x68, 0x88, 0x5c, 0x9d,
+    0x1f, 0x1b, 0x69, 0x7e, 0x2d, 0x24, 0x89, 0x91, 0x98, 0xb7, 0x78, 0xe1, 0x32, 0x81, 0x53, 0xb1,
+    0xb4, 0xa9, 0x4a, 0x09, 0xbc, 0xb1, 0x5f, 0xfe, 0x68, 0x5c, 0x22, 0x4d, 0x71, 0x9d, 0xfe, 0x83,
+    0x6b, 0x18, 0x4e, 0xb0, 0x59, 0xc5, 0x15, 0x3b, 0x3a, 0xe4, 0x84, 0xba, 0xa7, 0x07, 0x42, 0x54,
+    0x11, 0xbe, 0x54, 0x26, 0xa7, 0x0f, 0xab, 0x43, 0x03, 0x9f, 0xc1, 0x4d, 0xcc, 0xe8, 0xd4, 0x57,
+    0x75, 0xe3, 0xc8, 0x3a, 0x0f, 0x95, 0x58, 0x67, 0xcc, 0xf6, 0xf7, 0xdb, 0x27, 0xd5, 0xc8, 0xd6,
+    0x09, 0xed, 0x53, 0xef, 0x2f, 0x3c, 0xe0, 0xdf, 0xa4, 0xd6, 0xfb, 0x16, 0x95, 0xf1, 0x48, 0x02,
+    0x52, 0x03, 0x91, 0xd4, 0xff, 0x3e, 0x02, 0x31, 0x08, 0x76, 0xa4, 0x96, 0x4e, 0xfa, 0xa9, 0x59,
+    0xe6, 0xc1, 0xd7, 0x6b, 0x19, 0x35, 0x60, 0x3e, 0xea, 0x9c, 0x95, 0x38, 0x97, 0x23, 0x7b, 0x08,
+    0x51, 0x4f, 0x77, 0x99, 0x82, 0x72, 0x75, 0xb5, 0x50, 0x90, 0x6b, 0xf9, 0x1b, 0x46, 0xb4, 0xb0,
+    0xee, 0x62, 0x34, 0xb6, 0xa3, 0x9b, 0xee, 0x4c, 0xa9, 0xcb, 0x36, 0x75, 0x83, 0xc6, 0x42, 0x0d,
+    0x19, 0xe1, 0x5e, 0x2e, 0x27, 0xf6, 0xc1, 0x78, 0x4d, 0xff, 0xf4, 0xe0, 0xce, 0xa1, 0xc5, 0xf1,
+    0x37, 0xd1, 0x8a, 0x6c, 0x6e, 0x26, 0x45, 0xa2, 0xd4, 0x5d, 0x12, 0xe5, 0x45, 0x35, 0xdd, 0x58,
+    0xf6, 0x1d, 0xd3, 0xca, 0xe1, 0x13, 0x9b, 0xb0, 0xd3, 0x85, 0xc5, 0xf2, 0xb7, 0xb3, 0x55, 0xf4,
+    0x82, 0xb1, 0xfb, 0xe6, 0x3e, 0x23, 0x54, 0xe0, 0x24, 0x43, 0xb5, 0x4c, 0x0e, 0x49, 0xcc, 0x9b,
+    0xbd, 0xb7, 0x4d, 0xdc, 0xfa, 0xe6, 0xd0, 0x76, 0xa9, 0x3c, 0x16, 0x3c, 0xc4, 0xf9, 0x5c, 0xcf,
+    0x37, 0xd3, 0xd2, 0x1e, 0xc0, 0x51, 0xb1, 0x96, 0x75, 0xe2, 0xb7, 0x7e, 0x79, 0xca, 0x5c, 0xff,
+    0xc3, 0x18, 0xb9, 0x7a, 0x64, 0xfb, 0x91, 0x02, 0xf2, 0x1a, 0xc1, 0x1d, 0x5c, 0x4c, 0x3b, 0x22,
+    0x7b, 0x9f, 0x61, 0xe0, 0x97, 0x90, 0x95, 0x02, 0x48, 0xe4, 0xaa, 0xb5, 0x14, 0xb1, 0xd7, 0x35,
+    0x34, 0x0f, 0x5b, 0xa3, 0x55, 0x05, 0x0a, 0xcb, 0xce, 0xbe, 0x13, 0x8e, 0xa8, 0x5c, 0xaf, 0xb9,
+    0x0a, 0x29, 0x7c, 0x90, 0xb2, 0x6e, 0x1e, 0xf8, 0x00, 0x5a, 0x69, 0x1b, 0xf8, 0x7d, 0xe0, 0x8f,
+    0x1b, 0xf5, 0x80, 0x17, 0x50, 0x3d, 0xef, 0x02, 0x2c, 0x56, 0xba, 0x14, 0x54, 0xaf, 0xb0, 0x6e,
+    0xe3, 0xf3, 0xd9, 0x65, 0xdb, 0x2e, 0x52, 0xbb, 0x31, 0x63, 0x00, 0xa6, 0xd3, 0x7a, 0x4a, 0xbd,
+    0x89, 0x56, 0xff, 0x21, 0xdb, 0x04, 0x95, 0xb8, 0xb4, 0xa0, 0x2c, 0x58, 0xc2, 0x87, 0x68, 0x25,
+    0x01, 0xdb, 0xda, 0xee, 0x44, 0x6f, 0x6d, 0xc4, 0x04, 0xd9, 0x97, 0xea, 0xda, 0xc6, 0x4f, 0xce,
+    0x55, 0x1c, 0x2b, 0x9d, 0x4b, 0xdd, 0x28, 0x7b, 0xc4, 0x80, 0x0f, 0x07, 0x9d, 0x58, 0xff, 0x0d,
+    0x01, 0x4e, 0xe0, 0xff, 0x29, 0x55, 0x91, 0x21, 0x1c, 0x82, 0x9f, 0x6f, 0x3b, 0x37, 0xab, 0x26,
+    0x6e, 0x77, 0xec, 0x88, 0x4c, 0x9d, 0xbf, 0x9f, 0xfc, 0xa7, 0xdd, 0xbf, 0xef, 0x2b, 0x33, 0x3c,
+    0x33, 0x74, 0x72, 0xbf, 0x76, 0x8b, 0xa4, 0x79, 0x20, 0xe2, 0xd6, 0x33, 0xdb, 0x2d, 0x05, 0x23,
+    0x1e, 0xc5, 0xe5, 0x5b, 0xd0, 0xbb, 0x63, 0xc7, 0xcb, 0x04, 0xbf, 0x12, 0x97, 0xe5, 0x5e, 0x5b,
+    0x27, 0x25, 0xa9, 0x28, 0x97, 0xd1, 0x55, 0xa7, 0x00, 0x9e, 0x43, 0xaf, 0xc2, 0x18, 0xf1, 0xb4,
+    0x1e, 0x05, 0xaa, 0xda, 0x38, 0x2f, 0x23, 0xfe, 0x39, 0x86, 0x8e, 0x35, 0x43, 0x88, 0xe1, 0x5b,
+    0xe4, 0xb6, 0xb5, 0xcf, 0x63, 0x74, 0x1d, 0x00, 0x89, 0x24, 0x7c, 0xd6, 0x4b, 0xc2, 0x85, 0x03,
+    0x6c, 0x04, 0x73, 0x63, 0xac, 0x72, 0x0f, 0xbf, 0x9e, 0x59, 0xa6, 0xd9, 0x7d, 0x76, 0xa6, 0xda,
+    0x83, 0xd7, 0x86, 0xba, 0xd1, 0xd0, 0x5a, 0x90, 0xea, 0xf9, 0xf0, 0x61, 0xf3, 0x01, 0x4f, 0xf6,
+    0x54, 0xa0, 0xe8, 0xbb, 0xc3, 0xa2, 0x96, 0x3a, 0xbf, 0xda, 0xba, 0x93, 0x40, 0xd5, 0xda, 0x2a,
+    0xdb, 0xe8, 0x6e, 0xa3, 0x58, 0x45, 0x2a, 0x94, 0xa9, 0xfd, 0xf5, 0xf8, 0x9a, 0xea, 0x1d, 0x0a,
+    0xfd, 0x8d, 0xfb, 0x86, 0x2b, 0xc8, 0xb4, 0x5b, 0xa7, 0x07, 0x4e, 0xed, 0x2d, 0x09, 0x3f, 0x50,
+    0x4b, 0x84, 0x9d, 0x52, 0x8d, 0x72, 0x23, 0x31, 0x33, 0xea, 0x3c, 0x16, 0x61, 0x14, 0xf8, 0xd7,
+    0x44, 0x1e, 0x93, 0x93, 0xc6, 0xb6, 0x0d, 0x6b, 0xc2, 0x79, 0x39, 0x3a, 0x48, 0xb3, 0x94, 0xd8,
+    0x63, 0xca, 0x45, 0x3a, 0x41, 0xda, 0x6e, 0x8b, 0xc6, 0x2d, 0x1b, 0xd9, 0xb5, 0xf7, 0x70, 0xb5,
+    0xea, 0x28, 0x47, 0x05, 0x72, 0xc5, 0x57, 0x71, 0x4f, 0x2c, 0x6f, 0x3c, 0x6c, 0xc7, 0xc6, 0x7e,
+    0xd6, 0xd2, 0x7a, 0x08, 0x90, 0x59, 0xb7, 0x20, 0x26, 0xad, 0x0e, 0xdf, 0x0b, 0x3f, 0x47, 0x17,
+    0xc8, 0xdb, 0xdc, 0x7e, 0xd8, 0xca, 0x7b, 0xbc, 0xee, 0xb2, 0xd7, 0xaf, 0x59, 0x20, 0xdd, 0x96,
+    0x29, 0x68, 0x48, 0x51, 0x19, 0xbd, 0x6f, 0x74, 0xd1, 0x4a, 0x16, 0x0b, 0xef, 0xbc, 0x09, 0xaa,
+    0xa4, 0x7d, 0xa4, 0xa7, 0x92, 0xcb, 0x03, 0x2f, 0x58, 0xcf, 0x9c, 0xfc, 0x29, 0x79, 0xd6, 0xbf,
+    0xa0, 0xb6, 0xc5, 0x77, 0x3c, 0x24, 0xaa, 0xd1, 0x05, 0xfb, 0x86, 0x8e, 0x5f, 0x6f, 0x48, 0x05,
+    0x61, 0xee, 0x94, 0x12, 0xe6, 0xb0, 0x5e, 0x7d, 0xe0, 0x9e, 0x6d, 0x26, 0xd9, 0xdf, 0x89, 0xc9,
+    0xa4, 0x84, 0x5d, 0xcc, 0x82, 0xd3, 0x7a, 0x94, 0x97, 0xb7, 0x1c, 0x57, 0xa4, 0x15, 0x83, 0x6f,
+    0xfb, 0xec, 0x75, 0xf7, 0x1d, 0x5e, 0x42, 0x54, 0x45, 0x2d, 0x0c, 0xe5, 0x9a, 0x57, 0xaa, 0x7f,
+    0x27, 0xf9, 0x55, 0x13, 0xbb, 0x74, 0xc9, 0xd7, 0xec, 0xc4, 0x56, 0x9c, 0x58, 0x96, 0x16, 0xaf,
+    0x5a, 0x7a, 0x3d, 0x19, 0x52, 0x16, 0x96, 0x8d, 0xfc, 0x4b, 0x0c, 0xe6, 0x0a, 0x05, 0x23, 0x1e,
+    0x0e, 0x54, 0x62, 0x6e, 0x6f, 0xbc, 0x92, 0x89, 0xc1, 0xb4, 0xba, 0xf9, 0x26, 0x56, 0x46, 0xe7,
+    0x24, 0xc0, 0xdc, 0x44, 0x9a, 0x8b, 0xb8, 0xcf, 0x56, 0x5c, 0x4b, 0x75, 0x57, 0x26, 0x75, 0x5c,
+    0xe7, 0x99, 0x21, 0x11, 0x55, 0x38, 0xf5, 0xea, 0xf7, 0xb3, 0xab, 0x88, 0xea, 0x8f, 0xb9, 0xf3,
+    0xf5, 0x47, 0xed, 0x75, 0x97, 0x1e, 0xab, 0xb8, 0x4b, 0x63, 0x4a, 0x57, 0x97, 0xb8, 0xb9, 0x09,
+    0x92, 0xcd, 0x89, 0x18, 0x58, 0xcc, 0x7a, 0xd2, 0x97, 0xc7, 0x60, 0xb4, 0x88, 0xe0, 0x56, 0xf5,
+    0x9c, 0x53, 0xbf, 0xff, 0x8c, 0x5a, 0x3a, 0x16, 0x17, 0x51, 0x09, 0x1d, 0xb2, 0x46, 0xe0, 0x48,
+    0x96, 0xaa, 0x90, 0x35, 0x3c, 0x62, 0xfd, 0x6a, 0x39, 0xe2, 0x56, 0x2e, 0x56, 0x37, 0xc9, 0x18,
+    0xc4, 0xa2, 0xe1, 0xa5, 0x39, 0x3d, 0xcd, 0x1d, 0xc1, 0x27, 0x32, 0xf6, 0xd3, 0x19, 0x96, 0xf3,
+    0x15, 0xca, 0x4d, 0x74, 0x5d, 0x50, 0x2f, 0x1a, 0x59, 0x4b, 0x57, 0xe7, 0x7b, 0xf5, 0x0d, 0x77,
+    0xde, 0xb6, 0x22, 0xc2, 0x64, 0xce, 0xa0, 0x47, 0xdb, 0xb8, 0xc6, 0x47, 0x7f, 0xf3, 0x53, 0x71,
+    0x39, 0x85, 0xf4, 0xfe, 0xd9, 0x1b, 0x55, 0xd0, 0xb0, 0x64, 0x13, 0x2b, 0x14, 0x0d, 0x69, 0x31,
+    0x4d, 0xad, 0xb0, 0xa2, 0xf6, 0x2d, 0x55, 0x8c, 0xaa, 0xcc, 0x1e, 0x1c, 0xe5, 0x80, 0xd5, 0x8c,
+    0x19, 0x45, 0x23, 0x9c, 0x2e, 0xd4, 0x89, 0x09, 0xb3, 0x1b, 0x43, 0x26, 0x4f, 0x1a, 0xfc, 0xfb,
+    0x36, 0x07, 0x35, 0xc9, 0x35, 0xb0, 0xb8, 0x6e, 0x89, 0x6f, 0xde, 0x6a, 0xd0, 0x56, 0xc5, 0xd0,
+    0x5d, 0x11, 0x84, 0xe9, 0xa5, 0xf0, 0xd7, 0xf8, 0xe3, 0x72, 0xf4, 0x56, 0xdc, 0xe9, 0x9e, 0x55,
+    0xf1, 0xb4, 0xba, 0xbc, 0x7f, 0xf5, 0xf2, 0x6b, 0xcc, 0x36, 0xf3, 0xe3, 0x28, 0x3c, 0xda, 0x41,
+    0xce, 0x29, 0xed, 0x19, 0x4e, 0x48, 0xdb, 0x5a, 0x9e, 0x39, 0xc7, 0x5e, 0x22, 0x8f, 0x27, 0x25,
+    0x81, 0x9e, 0x03, 0xd1, 0x09, 0x3e, 0x05, 0x63, 0x12, 0x44, 0xfd, 0xe2, 0x44, 0x70, 0xcf, 0xb8,
+    0xc8, 0x26, 0x91, 0x77, 0x34, 0xfe, 0x28, 0x00, 0x75, 0x78, 0x92, 0x66, 0xa4, 0xb4, 0x8d, 0x00,
+    0x4a, 0x99, 0xf9, 0xba, 0x94, 0x70, 0xe8, 0xa0, 0x33, 0x69, 0x75, 0x83, 0x1c, 0x6f, 0x73, 0x10,
+    0xb0, 0xdc, 0xda, 0x93, 0x14, 0x2d, 0xe5, 0x48, 0x1b, 0x1e, 0x6e, 0xe1, 0x43, 0xf7, 0xad, 0x9c,
+    0xf5, 0xce, 0xe6, 0xab, 0x02, 0x8f, 0xed, 0xe5, 0xb3, 0x45, 0xcb, 0xd7, 0x60, 0x73, 0x9f, 0x69,
+    0xe1, 0x57, 0xab, 0x69, 0x4f, 0x45, 0x73, 0xd8, 0x96, 0x33, 0x47, 0x4b, 0x8a, 0xb6, 0x3b, 0x3e,
+    0x55, 0x78, 0xbe, 0x2d, 0xe2, 0x83, 0x73, 0x56, 0x14, 0x3d, 0xea, 0x1c, 0x29, 0xd3, 0x65, 0x48,
+    0xdc, 0x9a, 0x88, 0xa4, 0xe6, 0xfe, 0x07, 0x51, 0x1c, 0x16, 0x57, 0xe2, 0x42, 0xe4, 0xbe, 0x5b,
+    0x39, 0x82, 0x86, 0xe1, 0x57, 0x51, 0xe8, 0x9f, 0x49, 0x36, 0xce, 0xdc, 0x0a, 0xc6, 0x8a, 0x98,
+    0x23, 0xc7, 0x8e, 0x9d, 0x0d, 0xbe, 0x79, 0x47, 0x1f, 0xae, 0x2a, 0xac, 0x66, 0xc2, 0x4f, 0xf6,
+    0x7f, 0x56, 0x32, 0x2a, 0x0e, 0xde, 0x4b, 0x3c, 0x82, 0x75, 0xf3, 0xe0, 0x97, 0xb7, 0x86, 0x65,
+    0xe5, 0x4c, 0x6d, 0xf6, 0xe2, 0x3e, 0x52, 0x6e, 0xe3, 0x2d, 0x2f, 0xfd, 0xfb, 0xc6, 0xf4, 0x11,
+    0x68, 0xef, 0x45, 0xa7, 0xc8, 0xa8, 0xb2, 0xda, 0x20, 0x93, 0x65, 0x20, 0x25, 0x30, 0x0f, 0x9b,
+    0x44, 0x4f, 0x58, 0x68, 0xf7, 0xef, 0xe1, 0x90, 0xec, 0xfe, 0xee, 0x01, 0x70, 0x73, 0x83, 0xcd,
+    0x9e, 0x86, 0xf2, 0x85, 0xfd, 0x16, 0x93, 0x7c, 0x82, 0x26, 0x06, 0x44, 0xdc, 0x33, 0xcc, 0x4a,
+    0xfa, 0x3c, 0x7c, 0xc6, 0x34, 0x0e, 0xfe, 0xbb, 0xd8, 0x40, 0x97, 0x4d, 0xe7, 0x42, 0x75, 0x7f,
+    0x5d, 0x7a, 0x31, 0x58, 0xf7, 0xd7, 0x24, 0x32, 0x6d, 0xc7, 0x14, 0xe8, 0x67, 0x36, 0xbe, 0x45,
+    0x63, 0xdc, 0x7f, 0xf9, 0x15, 0x38, 0xe4, 0xfc, 0x9e, 0xa6, 0x78, 0x61, 0x4b, 0x51, 0xfc, 0x31,
+    0xab, 0x3d, 0xd2, 0x7c, 0xa6, 0xdc, 0x10, 0xf6, 0x89, 0x3c, 0xf5, 0xec, 0x52, 0x2a, 0x4b, 0xa4,
+    0x9f, 0xb3, 0xf9, 0x2f, 0x76, 0x21, 0xc0, 0x44, 0xb9, 0xde, 0xf2, 0xc4, 0xc0, 0xd6, 0x70, 0x15,
+    0xf7, 0x2d, 0x98, 0xe3, 0x6a, 0x92, 0x47, 0x16, 0xd6, 0xc2, 0xbd, 0x59, 0x09, 0xa8, 0xce, 0xad,
+    0x1c, 0xab, 0x55, 0xee, 0x8a, 0x7a, 0xb6, 0x0e, 0xb6, 0x79, 0x1c, 0x47, 0xf2, 0x4f, 0x6f, 0x18,
+    0xff, 0xab, 0x73, 0x24, 0x2c, 0x4c, 0x71, 0x8d, 0xc4, 0x7f, 0x34, 0x61, 0x0b, 0x2e, 0x0b, 0x7f,
+    0x66, 0x7f, 0xe3, 0xbb, 0x4e, 0x93, 0x5c, 0x3f, 0x24, 0x5c, 0x26, 0xa9, 0x57, 0xc0, 0x08, 0x5c,
+    0xd2, 0xb9, 0x88, 0xdd, 0xe4, 0x49, 0xfc, 0xab, 0x8c, 0x4c, 0xed, 0x17, 0x1e, 0x1d, 0xdd, 0x62,
+    0x7c, 0x6f, 0x31, 0x9a, 0x38, 0x3a, 0xca, 0x94, 0x5b, 0xe3, 0x2d, 0x47, 0xef, 0x9a, 0x14, 0xc6,
+    0x76, 0x30, 0x46, 0x62, 0x56, 0x32, 0x85, 0x02, 0x59, 0x22, 0x5e, 0xa3, 0x9b, 0xbf, 0x1c, 0x10,
+    0x34, 0x74, 0x23, 0xd4, 0x0b, 0x92, 0x8c, 0xe3, 0x49, 0x84, 0x12, 0x60, 0xd1, 0x60, 0xf5, 0xe4,
+    0x2e, 0x3d, 0x98, 0x2c, 0x59, 0xe3, 0x92, 0x40, 0xef, 0xda, 0x6f, 0xb4, 0x8a, 0xb8, 0xd4, 0x65,
+    0xd2, 0x8b, 0x62, 0x67, 0x3c, 0xe7, 0x74, 0x87, 0x7f, 0x0f, 0x69, 0x51, 0x4a, 0x87, 0xfb, 0x10,
+    0xec, 0x7f, 0x9f, 0xd0, 0x80, 0x82, 0x3e, 0x2a, 0xa9, 0x11, 0xc9, 0xd3, 0x8a, 0xe1, 0x22, 0x4b,
+    0x5e, 0xdb, 0xbb, 0x8d, 0x7a, 0x29, 0xf1, 0x04, 0xcb, 0xf8, 0xae, 0xe0, 0xe9, 0x09, 0x1e, 0xe7,
+    0x84, 0x8d, 0xa6, 0xce, 0xbd, 0xde, 0x9e, 0x5d, 0x4f, 0x01, 0x6e, 0x96, 0xc6, 0xab, 0x6c, 0x17,
+    0x6c, 0xab, 0x41, 0xb5, 0xbf, 0xf0, 0xcc, 0xb9, 0x0c, 0xe0, 0xe3, 0x7b, 0xfa, 0xc8, 0xbb, 0x8b,
+    0xa7, 0x15, 0x36, 0x9a, 0x45, 0x18, 0x6d, 0x88, 0xa9, 0x26, 0x1e, 0x4e, 0x50, 0x37, 0xcf, 0x28,
+    0x0e, 0x7f, 0xbb, 0xe4, 0xc8, 0xa4, 0x91, 0x00, 0xdb, 0x8e, 0x0f, 0xa8, 0x59, 0x93, 0x8b, 0x15,
+    0x41, 0x39, 0xa9, 0xfb, 0x54, 0x2c, 0x00, 0xdb, 0xe2, 0xd4, 0x03, 0xfb, 0x2c, 0xb1, 0x09, 0xd3,
+    0xf1, 0xf1, 0x1a, 0x8f, 0xe6, 0x9d, 0xae, 0xc6, 0xd1, 0xd5, 0xa6, 0xba, 0x90, 0x5f, 0x12, 0x4c,
+    0xed, 0xa3, 0x15, 0xc0, 0x51, 0x2e, 0x73, 0xfd, 0x14, 0x0c, 0x76, 0xff, 0x01, 0x3a, 0xfd, 0x2a,
+    0xe1, 0x4b, 0xac, 0x5a, 0x76, 0xe8, 0xd9, 0x6c, 0xdc, 0x65, 0x8d, 0xf4, 0x02, 0x59, 0xdb, 0xdf,
+    0xd7, 0xa9, 0xc0, 0xb3, 0x4c, 0x87, 0x81, 0xfb, 0x7f, 0x17, 0xba, 0xbb, 0x7a, 0x43, 0xc0, 0xd1,
+    0xbb, 0x56, 0x02, 0xea, 0xce, 0x23, 0x4f, 0x95, 0xa8, 0x02, 0xb4, 0x70, 0x22, 0xf4, 0xf3, 0x1a,
+    0x35, 0xf9, 0x68, 0xf5, 0xcc, 0xfc, 0x99, 0x53, 0x75, 0xc7, 0x72, 0xac, 0x7f, 0xc3, 0xa8, 0x99,
+    0x6a, 0xec, 0x44, 0x89, 0x54, 0x00, 0x55, 0xf0, 0x74, 0xde, 0xea, 0x8e, 0x06, 0x7d, 0xbf, 0x3b,
+    0xca, 0x6f, 0x81, 0x49, 0x85, 0x23, 0xbb, 0xb9, 0x24, 0x25, 0x82, 0x31, 0xa1, 0xcb, 0xa2, 0xcb,
+    0x9c, 0xf4, 0xe4, 0xef, 0x76, 0x94, 0x25, 0x58, 0x40, 0x15, 0x67, 0x79, 0xf7, 0x3f, 0xed, 0x70,
+    0xfe, 0xb5, 0xb5, 0x46, 0x30, 0x17, 0x54, 0xb3, 0x0e, 0x54, 0xf4, 0x95, 0xd6, 0x31, 0x1a, 0x5e,
+    0x6e, 0xbd, 0x5c, 0x85, 0x06, 0x47, 0x45, 0xda, 0x0e, 0x08, 0xe4, 0x48, 0x73, 0xb9, 0xa9, 0x2c,
+    0xbd, 0x4b, 0x90, 0x87, 0x82, 0xc5, 0x17, 0x90, 0x09, 0xad, 0x3c, 0x7c, 0x7b, 0x5a, 0x1d, 0x24,
+];
+
+/// Same as [`KNOWN_PIECE_ENCODING`], but with 2 layers of alternating direction
+// Only alternating SLOTH tests use it, which run with portable implementation
+#[cfg(feature = "portable")]
+pub(crate) const KNOWN_PIECE_ALTERNATING_ENCODING: [u8; 4096] = [
+    0x1a, 0x4a, 0xa1, 0x51, 0xf9, 0x0f, 0x4c, 0xce, 0x43, 0xa2, 0xe3, 0xb7, 0x92, 0x62, 0x02, 0x14,
+    0xb1, 0x89, 0xda, 0x78, 0x03, 0x2c, 0xf6, 0x75, 0x25, 0x29, 0x6d, 0x5e, 0x20, 0xf8, 0x6d, 0xd1,
+    0xb5, 0x1a, 0xe9, 0xa9, 0x73, 0xd8, 0x9b, 0x62, 0x22, 0x0e, 0x1a, 0x15, 0x05, 0x5d, 0x16, 0x5e,
+    0xbf, 0x24, 0x47, 0x5f, 0x30, 0xc9, 0x55, 0x25, 0x90, 0xe1, 0x4e, 0x8b, 0x61, 0xda, 0x4b, 0xba,
+    0x1a, 0xf3, 0xa5, 0x60, 0x25, 0x40, 0x2d, 0x88, 0xe2, 0xae, 0x70, 0xb9, 0x54, 0x0c, 0x63, 0xf6,
+    0x66, 0x96, 0x1f, 0x6c, 0xbc, 0x35, 0x83, 0x41, 0xc1, 0x06, 0x50, 0x44, 0xda, 0x0e, 0x9b, 0x74,
+    0xa1, 0x97, 0x16, 0x7a, 0xd1, 0xbe, 0x6a, 0xdf, 0x49, 0xb9, 0x41, 0x31, 0x26, 0x4d, 0x57, 0xd3,
+    0xb7, 0xe9, 0x29, 0x30, 0xed, 0xe8, 0x19, 0x58, 0x3e, 0x44, 0xeb, 0x89, 0xf0, 0x77, 0x1c, 0x93,
+    0xc7, 0xac, 0x4d, 0x8d, 0x76, 0xbd, 0x33, 0x87, 0x28, 0x82, 0xc2, 0x6b, 0xe7, 0x56, 0x89, 0xda,
+    0x76, 0x97, 0xc2, 0x05, 0xa7, 0xb0, 0x39, 0x68, 0x0d, 0x8f, 0x2d, 0xa8, 0xab, 0x31, 0x92, 0xb6,
+    0x73, 0x8a, 0x0c, 0x30, 0x6f, 0xb7, 0x4c, 0xc4, 0x2f, 0x9a, 0x74, 0x3b, 0xef, 0x3f, 0x2d, 0xfa,
+    0xa9, 0xd0, 0x5a, 0xda, 0xb5, 0xc3, 0x65, 0x91, 0x7d, 0xeb, 0x61, 0x62, 0xc5, 0x0c, 0x14, 0x95,
+    0x0d, 0xfb, 0x58, 0x99, 0x0a, 0x57, 0x82, 0x19, 0x42, 0x3e, 0xae, 0x3f, 0xc3, 0x53, 0xb0, 0x96,
+    0x3e, 0xe6, 0x9d, 0xca, 0x30, 0xce, 0x15, 0x2b, 0xb1, 0x7d, 0x69, 0x5e, 0xca, 0xcf, 0x4a, 0xc3,
+    0x56, 0x57, 0x8d, 0xed, 0xda, 0xb2, 0xda, 0x80, 0xb3, 0x7a, 0x36, 0x65, 0xc2, 0xac, 0xc3, 0x86,
+    0x4b, 0xb2, 0x9a, 0xb6, 0xd6, 0x0e, 0x3b, 0x93, 0x38, 0x19, 0x98, 0x54, 0x6e, 0xcc, 0x40, 0xec,
+    0x75, 0x27, 0xa7, 0x2b, 0x9a, 0x2b, 0x64, 0x56, 0xbb, 0x93, 0xe6, 0x36, 0xfc, 0x31, 0xe4, 0x07,
+    0x58, 0x69, 0x52, 0x16, 0x3f, 0xcc, 0x84, 0x6b, 0xcd, 0xed, 0xb4, 0x0f, 0x9b, 0x37, 0xec, 0xd1,
+    0xaa, 0xdd, 0xe0, 0x31, 0xfd, 0x37, 0x0d, 0x8b, 0x48, 0x18, 0xc6, 0x2b, 0x40, 0xae, 0xf5, 0x39,
+    0x26, 0x61, 0x9b, 0x59, 0x7a, 0x7e, 0xbc, 0x1c, 0x05, 0xb6, 0x03, 0xaa, 0x55, 0xf0, 0xc4, 0x95,
+    0x27, 0x67, 0x11, 0xdc, 0x7d, 0x11, 0xfd, 0xa2, 0xe9, 0x3c, 0x07, 0x39, 0xbc, 0xa6, 0x97, 0x77,
+    0xf0, 0x69, 0x15, 0x5c, 0x2c, 0xe9, 0x10, 0x0c, 0x8d, 0x16, 0x6e, 0x9e, 0x4a, 0x5c, 0x5e, 0x10,
+    0x51, 0xa7, 0xf8, 0xf6, 0x51, 0xab, 0x41, 0xfc, 0xee, 0x21, 0x91, 0xec, 0x89, 0xe8, 0xd7, 0x77,
+    0xa1, 0x8f, 0xb4, 0x5f, 0x7b, 0x4d, 0xf3, 0x11, 0xb8, 0xd8, 0xab, 0x7b, 0xb7, 0x89, 0x84, 0x5b,
+    0x52, 0x99, 0xbc, 0x95, 0xab, 0x6d, 0x88, 0xed, 0x60, 0xe0, 0x58, 0x6c, 0x84, 0x48, 0xfb, 0xbe,
+    0x54, 0xf7, 0x20, 0xba, 0xc8, 0xf4, 0x05, 0xa9, 0x30, 0x27, 0x90, 0x66, 0x45, 0xff, 0xdc, 0xb7,
+    0x37, 0x9a, 0x6e, 0x61, 0x11, 0x28, 0x24, 0x9b, 0x68, 0x8b, 0x61, 0x62, 0xa0, 0x69, 0x59, 0xe7,
+    0x0e, 0x43, 0xb5, 0x1b, 0xbc, 0xfc, 0xb4, 0x0f, 0xe9, 0x21, 0x19, 0x06, 0x83, 0xbc, 0x71, 0xdb,
+    0xd2, 0x15, 0x93, 0xa8, 0x84, 0xbe, 0xd5, 0x24, 0x85, 0x4b, 0xd7, 0x77, 0xa9, 0x90, 0xb9, 0x5a,
+    0x78, 0xdd, 0xeb, 0xac, 0x57, 0x94, 0x5d, 0xa6, 0x4c, 0x2b, 0x0c, 0x97, 0xae, 0x66, 0x1f, 0x60,
+    0xea, 0x95, 0x08, 0xff, 0xbe, 0xd3, 0xe1, 0x9f, 0xbb, 0x91, 0x8b, 0x53, 0xba, 0x1a, 0xc6, 0x6e,
+    0xdc, 0xc9, 0xdf, 0x3e, 0xf7, 0xaa, 0x94, 0x98, 0x6e, 0x27, 0x56, 0xf8, 0x40, 0xe2, 0xfa, 0x26,
+    0xcc, 0x3c, 0x0a, 0xe4, 0x95, 0xe5, 0xf5, 0x20, 0xe9, 0x9f, 0x31, 0xc5, 0x8b, 0x3f, 0x1a, 0x39,
+    0xb0, 0xcd, 0x49, 0xfc, 0x9d, 0x2d, 0x00, 0x42, 0xdc, 0x67, 0xfc, 0x9b, 0x3a, 0x00, 0x46, 0xd8,
+    0xd6, 0x6d, 0xb4, 0xb7, 0x33, 0xdb, 0x1f, 0x6c, 0xe2, 0x4b, 0xec, 0x32, 0xe9, 0x44, 0x0e, 0xa6,
+    0x8f, 0xf7, 0x2d, 0x30, 0x54, 0x31, 0x16, 0xf6, 0xf7, 0x5b, 0x95, 0x85, 0x87, 0x17, 0xda, 0x46,
+    0x11, 0x81, 0xc7, 0x6e, 0x3c, 0x34, 0x19, 0x9a, 0x29, 0x25, 0x98, 0xb0, 0x92, 0x6f, 0xb2, 0x8b,
+    0xbc, 0xcb, 0xc4, 0x66, 0x85, 0x9b, 0xb4, 0x59, 0x1a, 0xe7, 0x25, 0x29, 0x5c, 0xaf, 0x6b, 0x89,
+    0x1b, 0x9e, 0x3c, 0x02, 0x71, 0x70, 0x7d, 0x82, 0xc2, 0x9c, 0x67, 0x7e, 0x39, 0x4d, 0x61, 0xb6,
+    0xf4, 0x90, 0x69, 0x4b, 0x4f, 0x7d, 0x0d, 0x8b, 0x41, 0xde, 0x9d, 0xec, 0xd7, 0x87, 0x7d, 0x05,
+    0xbc, 0x23, 0x75, 0x54, 0xfe, 0x81, 0x67, 0xa5, 0xda, 0x87, 0x1e, 0x76, 0xba, 0x3c, 0x5a, 0xf0,
+    0xb2, 0x96, 0x56, 0x60, 0x23, 0xee, 0x43, 0x44, 0x4e, 0x4c, 0x06, 0xfa, 0xe9, 0xc5, 0x03, 0xec,
+    0x99, 0xb4, 0x8f, 0x51, 0x81, 0x2a, 0x30, 0xf6, 0x4e, 0xdf, 0xd1, 0x96, 0x91, 0x86, 0x1f, 0x86,
+    0x21, 0x5c, 0xf2, 0xd7, 0xf6, 0x67, 0x6f, 0xe8, 0xfd, 0xf4, 0x88, 0xbd, 0xf5, 0xd6, 0x87, 0x3f,
+    0x30, 0x5c, 0x34, 0x11, 0xd7, 0x7b, 0xb1, 0xef, 0xe0, 0x18, 0xea, 0x11, 0x6f, 0x5a, 0xa6, 0x24,
+    0xa6, 0xf9, 0x72, 0xd1, 0x3b, 0x47, 0x6d, 0x22, 0x20, 0xf2, 0x24, 0x06, 0xf4, 0xe0, 0x71, 0xe0,
+    0xe9, 0x0d, 0x92, 0x24, 0xf6, 0xa5, 0x03, 0xf7, 0x28, 0xef, 0xfa, 0x85, 0xe4, 0x6c, 0x24, 0x10,
+    0x32, 0x4d, 0x92, 0x72, 0xc2, 0x44, 0x01, 0x67, 0x8a, 0x3d, 0xdd, 0x7d, 0x06, 0x9d, 0x5a, 0x74,
+    0x2b, 0xf8, 0x20, 0x52, 0x2a, 0xe5, 0x92, 0xee, 0x24, 0x7b, 0x83, 0x67, 0xb0, 0x1e, 0xc5, 0xd1,
+    0xd9, 0xbf, 0x9c, 0xde, 0x01, 0x9f, 0x57, 0xe1, 0xb0, 0xd3, 0x1b, 0x46, 0xba, 0x5b, 0xeb, 0x61,
+    0x63, 0x3d, 0x14, 0x43, 0x6e, 0xae, 0x01, 0x5a, 0xcd, 0x68, 0xd1, 0x61, 0x9e, 0x12, 0x84, 0x9f,
+    0x7e, 0x32, 0x2b, 0x91, 0x4c, 0xfe, 0xdb, 0x47, 0x5a, 0x69, 0x0e, 0x8c, 0x63, 0x92, 0xdf, 0xd6,
+    0x5c, 0xa9, 0x29, 0x7d, 0x16, 0xb9, 0x7c, 0xb3, 0xe5, 0x94, 0x38, 0xe5, 0x4d, 0x36, 0x6b, 0x17,
+    0xe7, 0xe0, 0x5f, 0x91, 0xc7, 0x7e, 0xc0, 0xfc, 0xbe, 0x9c, 0xde, 0xb5, 0x00, 0x33, 0x96, 0x8f,
+    0xaf, 0xf5, 0xac, 0x89, 0x80, 0x0a, 0x11, 0xaa, 0xb9, 0x4d, 0x6c, 0x23, 0x80, 0xe4, 0xb9, 0xff,
+    0x34, 0x31, 0x62, 0x1c, 0xa7, 0xe0, 0xfe, 0xf5, 0x31, 0x39, 0x17, 0x36, 0x58, 0x0b, 0xa0, 0xd7,
+    0x19, 0x6c, 0xb3, 0xb6, 0x02, 0x1c, 0x8b, 0x08, 0xfc, 0xf0, 0xc5, 0xbb, 0xcc, 0x0d, 0xc2, 0x2a,
+    0x69, 0x58, 0x9d, 0xad, 0x38, 0x28, 0x73, 0xfd, 0xd6, 0xf7, 0xb0, 0x33, 0xbe, 0xd4, 0x9a, 0xc1,
+    0x04, 0x94, 0xce, 0xf6, 0x95, 0xe8, 0xbc, 0x0e, 0xfa, 0x8d, 0xc9, 0x51, 0xe3, 0xe8, 0x9a, 0x44,
+    0xc1, 0xcf, 0x48, 0xd8, 0x24, 0x04, 0x51, 0x97, 0xf8, 0xd5, 0x7e, 0x18, 0x9c, 0x23, 0x41, 0xef,
+    0xfe, 0x51, 0x34, 0x5b, 0x5a, 0x43, 0xe3, 0xbc, 0x13, 0x02, 0xc6, 0x53, 0x56, 0x77, 0x9c, 0x4a,
+    0xb6, 0x7e, 0x3d, 0x27, 0x38, 0xd6, 0xf6, 0xe4, 0x97, 0x5c, 0x29, 0x47, 0xde, 0x5f, 0xd2, 0x0d,
+    0xc5, 0xcd, 0x0a, 0x06, 0x15, 0x78, 0x96, 0xaa, 0x83, 0xee, 0x02, 0x06, 0xae, 0x5c, 0xf9, 0xf6,
+    0x48, 0xac, 0x72, 0x9a, 0x45, 0x1f, 0xda, 0x63, 0x31, 0x87, 0x8a, 0xe6, 0x4e, 0x16, 0x6a, 0xbc,
+    0xc9, 0x7d, 0x82, 0x22, 0x67, 0xd0, 0x9d, 0xec, 0x68, 0x30, 0x35, 0x27, 0xdd, 0xca, 0x94, 0x57,
+    0xbf, 0xa9, 0x97, 0x08, 0x19, 0x94, 0x90, 0xeb, 0xda, 0x30, 0x23, 0xb0, 0x8b, 0xf6, 0x9b, 0xea,
+    0xe3, 0x54, 0xdd, 0x6a, 0xcb, 0x9f, 0x3f, 0xcc, 0x4d, 0x9a, 0xae, 0x2a, 0x53, 0x42, 0x30, 0x33,
+    0x63, 0x3b, 0x89, 0xbd, 0x21, 0xde, 0xb8, 0x86, 0x25, 0x53, 0xf8, 0x53, 0x49, 0xda, 0xb8, 0x89,
+    0x1f, 0xe3, 0xda, 0x3f, 0x3f, 0x93, 0x46, 0x80, 0x3e, 0x57, 0xb7, 0x04, 0x86, 0xa1, 0x8e, 0x3e,
+    0x35, 0x78, 0x93, 0xd1, 0x58, 0xb7, 0xeb, 0x9c, 0x5d, 0x14, 0x41, 0xe0, 0xbe, 0x6b, 0xca, 0x47,
+    0xa7, 0x5a, 0x5d, 0xb2, 0x5e, 0x2a, 0xab, 0xb8, 0xe9, 0x14, 0x7d, 0x72, 0x9b, 0x76, 0x55, 0x32,
+    0x47, 0xaa, 0x8d, 0xa6, 0x1f, 0x6b, 0x18, 0x3d, 0x59, 0x4a, 0x90, 0x36, 0x06, 0x9a, 0x2f, 0x98,
+    0x2a, 0x21, 0x07, 0xe4, 0x2b, 0x14, 0xea, 0x87, 0x3f, 0xb2, 0xd6, 0xcb, 0x19, 0xbe, 0x5c, 0xb5,
+    0xb7, 0xf3, 0xa9, 0x12, 0x15, 0xe1, 0x0f, 0x98, 0x57, 0x4a, 0x71, 0x0c, 0x4a, 0x5f, 0xf6, 0x3e,
+    0xf5, 0x73, 0x7c, 0x9f, 0x47, 0xc5, 0x75, 0x3b, 0x66, 0xff, 0x5a, 0x9e, 0x81, 0xe8, 0x8c, 0x59,
+    0xfd, 0xe7, 0x83, 0xee, 0x97, 0x41, 0xe7, 0xb0, 0x4c, 0x57, 0xd5, 0xeb, 0xde, 0x64, 0x87, 0x6c,
+    0xf1, 0xa2, 0xf6, 0xf1, 0x6f, 0x28, 0x70, 0xfe, 0xf8, 0xc6, 0x4e, 0xf3, 0x69, 0x51, 0xca, 0x29,
+    0x9e, 0xd6, 0x69, 0x6e, 0x91, 0x21, 0x67, 0x56, 0xe9, 0x1c, 0x27, 0xd5, 0x7d, 0x95, 0xee, 0xfb,
+    0x0b, 0xb9, 0xdd, 0x70, 0x0a, 0x12, 0x52, 0xb9, 0x2e, 0x1a, 0xee, 0x83, 0x89, 0x5d, 0x1d, 0xba,
+    0x79, 0x53, 0x3d, 0x1b, 0xf8, 0xfb, 0x70, 0x23, 0xec, 0xd0, 0xdc, 0x06, 0x01, 0x73, 0xc7, 0xb1,
+    0x31, 0xec, 0xa7, 0x81, 0xb1, 0x37, 0xe7, 0xc2, 0xbe, 0x89, 0x8d, 0xa2, 0x5e, 0x6e, 0xc9, 0xf3,
+    0xf2, 0x9f, 0x5f, 0xc6, 0x2e, 0x3c, 0x30, 0xbe, 0x6e, 0xca, 0xbc, 0xbe, 0xde, 0x26, 0xed, 0x3e,
+    0x0c, 0x4b, 0x71, 0x54, 0xe7, 0xc8, 0xba, 0x10, 0xf2, 0xd2, 0x9c, 0x76, 0x54, 0x93, 0xfb, 0x89,
+    0x0b, 0xfe, 0xb5, 0xaf, 0x12, 0xfb, 0xad, 0xa6, 0x04, 0x8e, 0xa5, 0x52, 0x55, 0xc1, 0xbd, 0xa8,
+    0xd9, 0x3f, 0x3c, 0xa9, 0xd4, 0x35, 0x8f, 0xe6, 0x3e, 0x2f, 0x5e, 0xf9, 0xaa, 0xa4, 0x57, 0xb7,
+    0xb7, 0xa9, 0xe6, 0xf1, 0xd1, 0xf2, 0xfa, 0x2e, 0x8c, 0xdf, 0xd0, 0xdf, 0x67, 0x4a, 0xfa, 0xc1,
+    0xa8, 0x48, 0xf7, 0x4a, 0xb3, 0x17, 0x2b, 0x8a, 0x8d, 0x6d, 0xa4, 0xd9, 0x52, 0xc2, 0xb8, 0xab,
+    0x4a, 0x14, 0x62, 0x32, 0x70, 0x50, 0x96, 0xa4, 0xe2, 0xde, 0x8d, 0xb8, 0x56, 0xd8, 0xaa, 0xa2,
+    0x8c, 0x07, 0x32, 0xe3, 0x33, 0xd9, 0xa2, 0xe0, 0xbd, 0x75, 0xe0, 0x0d, 0x7b, 0x6d, 0x5d, 0xaa,
+    0xc7, 0x4d, 0xed, 0x59, 0x94, 0x4b, 0xae, 0x28, 0xb4, 0x08, 0x02, 0x53, 0xd2, 0x82, 0xb8, 0xcb,
+    0x1d, 0xdd, 0x1a, 0x9d, 0x89, 0x4e, 0xf1, 0x60, 0xed, 0xfc, 0x1b, 0xf3, 0xcc, 0x04, 0xf3, 0x2f,
+    0xa5, 0x68, 0x3f, 0x48, 0x93, 0x02, 0xa7, 0x89, 0xf3, 0x8c, 0x5c, 0x65, 0x00, 0x3f, 0x1d, 0xf0,
+    0xa8, 0x0c, 0xc2, 0xd0, 0xd2, 0x96, 0x23, 0x41, 0x3e, 0x84, 0x5b, 0xf2, 0x96, 0xa6, 0xce, 0x82,
+    0x1a, 0xff, 0x0a, 0x35, 0x50, 0x5f, 0x74, 0x7a, 0xed, 0xe1, 0x35, 0xc4, 0x28, 0xa5, 0x60, 0x26,
+    0x61, 0x47, 0xb1, 0x8f, 0x90, 0x7b, 0x39, 0xd6, 0xc5, 0x15, 0xbc, 0x05, 0x5a, 0x4f, 0xb7, 0xc4,
+    0x8a, 0x0d, 0x97, 0xf4, 0x3c, 0x08, 0x6e, 0x0b, 0x38, 0x2b, 0xaa, 0x3b, 0x0f, 0x8e, 0x61, 0x6f,
+    0xaa, 0xa8, 0x1e, 0x54, 0x1e, 0x94, 0x69, 0xd5, 0xc0, 0x44, 0x6c, 0x22, 0x15, 0x8b, 0xa3, 0x4b,
+    0xd5, 0xec, 0x2d, 0x70, 0xa1, 0xa0, 0x28, 0x65, 0xd8, 0x2c, 0xf0, 0xba, 0x65, 0x87, 0xc2, 0x00,
+    0x94, 0x0e, 0x34, 0xf2, 0xa1, 0x88, 0x6f, 0x44, 0xec, 0xc5, 0x7c, 0x58, 0x3f, 0x11, 0xd4, 0x7c,
+    0xb2, 0x71, 0x27, 0x2b, 0xef, 0x4c, 0x68, 0xab, 0xc4, 0x05, 0x26, 0xb4, 0x22, 0x54, 0xd3, 0x23,
+    0x93, 0x87, 0x58, 0x6e, 0x7d, 0x6b, 0x89, 0xa9, 0x17, 0x6c, 0xc3, 0x4d, 0x3f, 0x1b, 0xc5, 0x7b,
+    0xaa, 0xcc, 0x5b, 0xfe, 0xa3, 0x24, 0x87, 0x7a, 0x25, 0xa4, 0x71, 0xd7, 0xba, 0x04, 0x30, 0xc8,
+    0x81, 0x86, 0x19, 0x98, 0x1e, 0xde, 0x92, 0xd0, 0x12, 0xf0, 0x46, 0x91, 0x07, 0x32, 0x97, 0x0d,
+    0xe0, 0xcd, 0x30, 0x62, 0x35, 0x9f, 0xef, 0xf0, 0xe5, 0x15, 0x7d, 0x66, 0x56, 0x05, 0xf0, 0x15,
+    0x9d, 0x73, 0xcc, 0x88, 0x9a, 0xb9, 0x87, 0xd0, 0x24, 0x9c, 0xef, 0x7d, 0x4e, 0x27, 0x06, 0xb0,
+    0x0c, 0xbb, 0xb4, 0x6c, 0xa9, 0x58, 0x11, 0x23, 0x24, 0x47, 0x57, 0x1f, 0x25, 0x88, 0x28, 0x57,
+    0x66, 0x26, 0x12, 0x2d, 0xbf, 0x22, 0x20, 0xee, 0x60, 0x30, 0x3f, 0x29, 0x52, 0xcd, 0x5e, 0x6f,
+    0x6d, 0xb8, 0x92, 0x1c, 0xf4, 0xba, 0xca, 0x99, 0x2d, 0x9f, 0x60, 0x6f, 0x18, 0x16, 0x1e, 0xfa,
+    0x6c, 0x97, 0x79, 0xa8, 0x68, 0x1c, 0x3f, 0xb3, 0x4e, 0xf5, 0x4b, 0xa2, 0x70, 0xe5, 0x6f, 0x32,
+    0x0d, 0xf1, 0xbf, 0x89, 0xab, 0x58, 0x95, 0x92, 0xb4, 0x21, 0x47, 0xe0, 0xc2, 0x0b, 0xa0, 0xf2,
+    0x90, 0xbb, 0xdb, 0x65, 0x1e, 0x3c, 0x54, 0xf8, 0xd3, 0xc2, 0x7b, 0xe1, 0xce, 0x19, 0x8a, 0x83,
+    0xf2, 0xa8, 0xfd, 0xc3, 0x05, 0xb3, 0xfe, 0x96, 0x84, 0x96, 0x2b, 0x8b, 0xef, 0xec, 0x46, 0x03,
+    0x58, 0x7f, 0xb0, 0x4a, 0xba, 0xd3, 0xb9, 0x16, 0xc1, 0x3b, 0xaa, 0x1d, 0x22, 0x2e, 0xac, 0x25,
+    0x30, 0x62, 0x83, 0xbb, 0xc7, 0x32, 0x79, 0x5e, 0x78, 0x08, 0x1d, 0x43, 0xbf, 0x1b, 0x1e, 0x29,
+    0xae, 0x8d, 0xb5, 0xec, 0x0f, 0xc7, 0x28, 0x02, 0xeb, 0x49, 0x25, 0x1f, 0x45, 0x98, 0xd1, 0x52,
+    0x81, 0xd7, 0xbe, 0x8c, 0xf8, 0xce, 0x9e, 0xfe, 0x2d, 0xe9, 0x58, 0xd5, 0x3e, 0x0b, 0x05, 0x76,
+    0x8e, 0x5f, 0x1d, 0x18, 0xf4, 0x75, 0x5f, 0x8e, 0xe3, 0xcf, 0xab, 0x81, 0xe6, 0x66, 0xea, 0xfb,
+    0x12, 0xa3, 0x9b, 0xe2, 0x4b, 0xfa, 0xec, 0x06, 0x7f, 0xad, 0x2c, 0x0e, 0x72, 0x8b, 0x1b, 0x24,
+    0x48, 0x91, 0x04, 0xc1, 0x14, 0xf0, 0xa7, 0xe7, 0x40, 0x79, 0xa0, 0xf0, 0x05, 0xbf, 0x7b, 0x2b,
+    0x70, 0x14, 0xed, 0xbd, 0x9a, 0x91, 0x84, 0x79, 0x25, 0xa1, 0xc4, 0xda, 0x60, 0x13, 0xd5, 0x0f,
+    0x7d, 0x7e, 0x05, 0x87, 0xc2, 0x32, 0x77, 0x80, 0xb8, 0x6c, 0x3f, 0xb3, 0x06, 0x33, 0xfd, 0x89,
+    0x8c, 0x9e, 0xb1, 0xb5, 0x34, 0x80, 0xc0, 0x8b, 0x7f, 0x3b, 0x2a, 0xdf, 0x90, 0xeb, 0x5e, 0x8c,
+    0xe8, 0x64, 0x22, 0x5b, 0xcf, 0xa6, 0x16, 0xbc, 0x79, 0xd0, 0x6a, 0xec, 0xa0, 0xf0, 0xbd, 0x5a,
+    0x66, 0xd1, 0x63, 0x0e, 0xfb, 0xdf, 0x38, 0x0a, 0x93, 0x71, 0x0f, 0xf9, 0xc6, 0xd2, 0xec, 0xbf,
+    0x6f, 0x41, 0x98, 0xb0, 0xd1, 0xf5, 0x04, 0xff, 0x0d, 0x5c, 0x68, 0x86, 0x28, 0x4b, 0x19, 0xf3,
+    0xa6, 0xfb, 0xab, 0xd3, 0x24, 0x06, 0xa9, 0x17, 0x6b, 0xcc, 0x08, 0xf9, 0x07, 0xa4, 0x47, 0x64,
+    0x69, 0x3e, 0x49, 0x44, 0xd5, 0xb2, 0x2b, 0xe0, 0xe1, 0x3e, 0xeb, 0xbf, 0x43, 0x63, 0x97, 0x71,
+    0xf7, 0x9e, 0xe5, 0x24, 0xa4, 0xc3, 0x25, 0x2e, 0x54, 0x99, 0x7d, 0xd0, 0xf8, 0x86, 0xf9, 0xee,
+    0xe8, 0x8c, 0xeb, 0xa0, 0x6c, 0x4e, 0x7d, 0x15, 0xa1, 0x57, 0x34, 0x66, 0x70, 0xc4, 0x0e, 0xa5,
+    0x6e, 0x44, 0x2b, 0xcf, 0xce, 0x8c, 0x95, 0xae, 0xe6, 0xce, 0x59, 0xff, 0xfc, 0x97, 0xaa, 0x51,
+    0xdb, 0xdf, 0x1c, 0x3a, 0x03, 0x7d, 0xec, 0xf0, 0x14, 0xc3, 0xc4, 0x52, 0x11, 0x04, 0x8d, 0x9f,
+    0x1b, 0x7d, 0x84, 0x7d, 0xa7, 0x14, 0xe7, 0xdd, 0xaf, 0x71, 0xb0, 0x9f, 0x52, 0x15, 0xb2, 0x26,
+    0x4f, 0x81, 0xff, 0xb3, 0x77, 0x8a, 0x56, 0x4f, 0xc9, 0x90, 0x52, 0x37, 0x4f, 0xd3, 0x60, 0x85,
+    0xba, 0xc0, 0xfb, 0x21, 0x32, 0xcd, 0xcb, 0x37, 0x45, 0x15, 0x32, 0x3f, 0x8c, 0xc9, 0x52, 0xe0,
+    0xba, 0x5e, 0x02, 0x52, 0x3f, 0x1b, 0xd8, 0xcc, 0x60, 0x28, 0x1d, 0x96, 0x39, 0x56, 0x4f, 0x9d,
+    0xfa, 0xfc, 0x4e, 0x78, 0xd1, 0x41, 0x0c, 0xa4, 0xdd, 0xa1, 0xdb, 0x10, 0x6f, 0x4d, 0x5d, 0x0f,
+    0xf0, 0xbd, 0x58, 0xee, 0x2e, 0x0f, 0x7b, 0x9d, 0xcb, 0x9b, 0x67, 0x2b, 0x89, 0x38, 0x19, 0x70,
+    0x28, 0x91, 0xaf, 0xb5, 0xf0, 0xd5, 0xe0, 0x72, 0x5f, 0xa0, 0xb8, 0x51, 0xb0, 0xf8, 0x65, 0x87,
+    0xd6, 0xf9, 0xa1, 0xdf, 0x89, 0xff, 0xd0, 0x7a, 0xbd, 0xb4, 0x55, 0x48, 0x57, 0xac, 0x80, 0x90,
+    0x9e, 0xdf, 0x97, 0xf4, 0x72, 0xba, 0xa7, 0x7c, 0x3d, 0x48, 0xa8, 0x17, 0xcb, 0xb8, 0xad, 0x9e,
+    0x47, 0x87, 0x4f, 0xdc, 0x30, 0x17, 0xb0, 0xc3, 0x01, 0x49, 0x5d, 0x96, 0xa2, 0x5c, 0x7d, 0xcd,
+    0x8b, 0xd1, 0xdb, 0xda, 0xc4, 0xf3, 0xcf, 0xb2, 0x79, 0x84, 0x72, 0x1e, 0x78, 0xa3, 0xd0, 0xa6,
+    0xef, 0x98, 0xdc, 0xe5, 0xed, 0x31, 0x42, 0x5b, 0xdb, 0x36, 0xc4, 0x57, 0x71, 0x6f, 0x5e, 0x64,
+    0xd1, 0x1a, 0xae, 0x1e, 0xb3, 0x0d, 0xce, 0xd8, 0xc1, 0xc4, 0xae, 0x5a, 0xe3, 0x10, 0xa8, 0xcc,
+    0x6e, 0x96, 0xc4, 0x98, 0xa8, 0x5a, 0x6d, 0xb9, 0x3a, 0xf1, 0x83, 0xd1, 0xb3, 0xbc, 0x26, 0x0e,
+    0x67, 0xf0, 0x35, 0xfe, 0x34, 0xda, 0x09, 0x57, 0x8b, 0x8e, 0x7c, 0x89, 0x1b, 0x39, 0xb7, 0x0a,
+    0x84, 0xb0, 0x66, 0xbf, 0x18, 0xe4, 0xcc, 0x5b, 0x00, 0x99, 0x03, 0x16, 0xbe, 0x67, 0x45, 0xe2,
+    0x6d, 0xc6, 0x7a, 0xf1, 0xa1, 0xd9, 0x5a, 0x42, 0x8d, 0x3a, 0x93, 0x2e, 0x2a, 0xf1, 0xe9, 0x3e,
+    0x43, 0xc3, 0xfa, 0x65, 0x47, 0x97, 0x86, 0xa0, 0xe2, 0x34, 0xd2, 0xec, 0x78, 0x36, 0x0a, 0x52,
+    0xef, 0x61, 0x72, 0x13, 0x51, 0x61, 0x57, 0x4d, 0x0c, 0x8c, 0x25, 0x81, 0x1c, 0x63, 0x36, 0x78,
+    0x7e, 0xd4, 0xe4, 0x7c, 0x1d, 0x15, 0xcc, 0x9b, 0x04, 0xd8, 0x97, 0x6d, 0x70, 0xc3, 0xdb, 0xc0,
+    0xf0, 0xff, 0x86, 0x6f, 0x4b, 0xe3, 0xdb, 0x97, 0x96, 0xd8, 0x48, 0xae, 0x5f, 0xd0, 0xfb, 0xf3,
+    0x6e, 0xd2, 0xba, 0x32, 0x4d, 0x03, 0xf7, 0x40, 0x68, 0x17, 0x56, 0xbe, 0x88, 0xba, 0x43, 0x13,
+    0x10, 0xf3, 0x6f, 0x6c, 0x61, 0xd3, 0xfb, 0xf8, 0x4a, 0x37, 0x97, 0xf6, 0x67, 0xc7, 0xa2, 0x50,
+    0x5d, 0x0c, 0x1b, 0xb0, 0x55, 0x0e, 0xfb, 0x65, 0x38, 0x89, 0xa4, 0x86, 0xad, 0x04, 0x4c, 0x89,
+    0xc4, 0x14, 0x50, 0x2e, 0x05, 0x7a, 0x74, 0x4a, 0x40, 0x28, 0xb3, 0xad, 0xe0, 0xb7, 0x1c, 0xf4,
+    0x43, 0x2c, 0xcd, 0xd0, 0xf0, 0xb4, 0x0f, 0x8c, 0xe5, 0x1d, 0x68, 0x5b, 0x8d, 0x2c, 0x54, 0x5d,
+    0x38, 0x3f, 0xcd, 0x3d, 0x30, 0x91, 0x59, 0x98, 0x35, 0xfc, 0xff, 0x3e, 0xcb, 0xa4, 0x13, 0x67,
+    0x68, 0x89, 0x81, 0xf0, 0x5d, 0x78, 0x4a, 0xea, 0x92, 0x5a, 0x5d, 0x68, 0x33, 0xac, 0x7c, 0x87,
+    0x50, 0x05, 0xe8, 0xf0, 0x60, 0x77, 0x73, 0xc6, 0xae, 0xfd, 0xb5, 0x79, 0x0a, 0x12, 0x06, 0xa5,
+    0xf4, 0x70, 0x56, 0xda, 0xa7, 0xa9, 0x9f, 0xc5, 0xc0, 0xae, 0x2b, 0x8e, 0x4c, 0xb1, 0x4a, 0x66,
+    0xdb, 0x5d, 0x02, 0xfa, 0xf4, 0x8a, 0x9f, 0xf3, 0x08, 0xe0, 0x4d, 0xff, 0xd4, 0xa4, 0x4c, 0xaa,
+    0xce, 0x0d, 0x4c, 0xa0, 0x8c, 0x86, 0x50, 0xc5, 0x0f, 0x08, 0x6e, 0x7e, 0xc0, 0x46, 0xef, 0x95,
+    0x5c, 0x1d, 0xf5, 0x08, 0x96, 0xe8, 0x1a, 0xe4, 0x0b, 0x50, 0xb6, 0xf3, 0x92, 0x83, 0xa5, 0x18,
+    0x80, 0xaa, 0x53, 0xaa, 0x57, 0xbd, 0xac, 0x15, 0xe1, 0x1b, 0xb4, 0x98, 0x35, 0x55, 0xae, 0xde,
+    0xc8, 0xf8, 0x9b, 0xa2, 0x80, 0x83, 0x96, 0xe6, 0x67, 0x3a, 0xc8, 0xc0, 0x01, 0x5f, 0x6d, 0x8f,
+    0x5a, 0xae, 0x92, 0xa3, 0x4e, 0x78, 0xa4, 0xd1, 0xf6, 0x96, 0x96, 0x31, 0x94, 0x3f, 0xbc, 0x8c,
+    0x1e, 0xe0, 0xee, 0xe9, 0x77, 0x02, 0x1c, 0xb6, 0xb5, 0xdb, 0xfc, 0xd0, 0x6b, 0xe1, 0x92, 0x9f,
+    0x61, 0x8f, 0x5b, 0xef, 0x44, 0xc2, 0x09, 0x35, 0x1b, 0xcb, 0x67, 0x2f, 0x43, 0xef, 0xe4, 0x77,
+    0xd6, 0x65, 0xdd, 0xb4, 0x2a, 0xff, 0x20, 0x76, 0xcb, 0xd1, 0xe8, 0xf2, 0x61, 0x29, 0x66, 0x12,
+    0xbc, 0xae, 0x39, 0xe8, 0xb7, 0x26, 0x14, 0x36, 0xf0, 0x09, 0xe9, 0x41, 0xa1, 0xd0, 0x1a, 0x40,
+    0x05, 0xfb, 0xc8, 0x0c, 0x5c, 0x43, 0xc2, 0x46, 0x2d, 0xd9, 0x10, 0x00, 0x65, 0x34, 0x71, 0x8d,
+    0x96, 0x05, 0xa1, 0x5d, 0xa0, 0xff, 0xe5, 0xc2, 0xe4, 0xc1, 0x23, 0xab, 0x85, 0xf6, 0x95, 0x97,
+    0x55, 0xe8, 0x5c, 0x95, 0x0c, 0xb0, 0x91, 0x44, 0x9b, 0xa4, 0x16, 0xdc, 0x99, 0xbf, 0x7d, 0x1e,
+    0x37, 0x61, 0x55, 0x27, 0x14, 0x06, 0x56, 0xc2, 0x52, 0xba, 0x09, 0x66, 0xc9, 0xa7, 0xd8, 0xe8,
+    0x63, 0x0d, 0x55, 0x9a, 0x97, 0xfd, 0xee, 0x48, 0x48, 0x31, 0x52, 0x3e, 0x4e, 0x1e, 0x96, 0x96,
+    0xdc, 0x0e, 0xc4, 0x18, 0xaf, 0xba, 0xed, 0xa0, 0x53, 0x5b, 0xe3, 0x31, 0x0e, 0x13, 0xe2, 0x7b,
+    0xb1, 0x8a, 0x43, 0xa6, 0xf2, 0x68, 0x02, 0x00, 0x89, 0xbb, 0xc7, 0x18, 0x06, 0x12, 0x81, 0x48,
+    0x5d, 0x65, 0x39, 0xfe, 0xc6, 0x95, 0x72, 0x82, 0xff, 0xb8, 0xe3, 0x01, 0x19, 0x7e, 0xed, 0x65,
+    0xd5, 0x71, 0x72, 0xf8, 0xdf, 0x30, 0xef, 0xec, 0x46, 0xaf, 0xf0, 0x46, 0x7c, 0x93, 0x39, 0x86,
+    0x7f, 0xd4, 0x34, 0xcf, 0xa6, 0x10, 0xc2, 0x4b, 0x9b, 0xc9, 0xf6, 0xd4, 0xf7, 0xe7, 0xed, 0xb8,
+    0xa1, 0xd3, 0xda, 0x84, 0x29, 0xa5, 0x57, 0xc9, 0x98, 0x0c, 0x5e, 0x7c, 0x4a, 0xac, 0xe4, 0x35,
+    0x70, 0x89, 0xba, 0x70, 0x0d, 0x70, 0x1b, 0xcc, 0xae, 0x7b, 0xba, 0x30, 0x65, 0x66, 0x55, 0xbe,
+    0x13, 0x5a, 0x00, 0x87, 0xf2, 0x22, 0x80, 0x7f, 0x7a, 0xd0, 0xd1, 0x46, 0xcd, 0x3e, 0x1c, 0x03,
+    0x39, 0x0b, 0xb7, 0xeb, 0x1c, 0x88, 0xa8, 0xfc, 0x88, 0x50, 0xab, 0x5d, 0x4e, 0x6d, 0x5c, 0x0e,
+    0xb6, 0xa6, 0xb4, 0x67, 0xec, 0xcb, 0x71, 0x83, 0x10, 0x7b, 0x5b, 0xff, 0xfb, 0x78, 0xcd, 0xd8,
+    0xc2, 0x12, 0x5f, 0xa8, 0x17, 0x7b, 0x79, 0x75, 0xb4, 0x44, 0x76, 0xc2, 0x2a, 0x20, 0x73, 0xd3,
+    0x2c, 0xc4, 0x60, 0xcb, 0x48, 0x87, 0x66, 0x07, 0xac, 0x10, 0x35, 0x27, 0xb9, 0x6b, 0x57, 0x03,
+    0x43, 0x22, 0x65, 0x07, 0x3f, 0xb6, 0x77, 0x84, 0x7c, 0x1b, 0xee, 0x21, 0x04, 0x30, 0x13, 0x74,
+    0xaa, 0xef, 0x33, 0xfd, 0x16, 0x82, 0xce, 0xaa, 0x3b, 0x4b, 0x04, 0x6c, 0x03, 0x92, 0xbc, 0xcb,
+    0x2d, 0x55, 0x57, 0xc2, 0x09, 0x28, 0x8c, 0x8a, 0x17, 0x47, 0x71, 0x41, 0x33, 0xfb, 0xeb, 0xda,
+    0x14, 0x6b, 0x6b, 0xe9, 0xe0, 0x63, 0xf7, 0xae, 0x5a, 0x46, 0xb1, 0xc1, 0x9c, 0xd1, 0x68, 0x49,
+    0x12, 0x6d, 0x04, 0x15, 0x78, 0xb4, 0x9f, 0x1f, 0x97, 0x78, 0x8e, 0x5b, 0x88, 0xf0, 0xfb, 0x89,
+    0x8a, 0x44, 0x26, 0x21, 0x2a, 0xcd, 0x92, 0xd5, 0xad, 0x3b, 0xcb, 0x8c, 0x55, 0xaa, 0x28, 0x71,
+    0x54, 0x96, 0xe6, 0x76, 0xb6, 0x78, 0x5e, 0x17, 0x1c, 0x7b, 0xb4, 0x27, 0x23, 0x1b, 0x15, 0x6d,
+    0x5f, 0x6b, 0xc9, 0xa6, 0x91, 0x3c, 0x5d, 0xc2, 0x6e, 0x33, 0x4e, 0x90, 0x5e, 0xe2, 0xfc, 0x53,
+    0xa9, 0x95, 0x48, 0x6c, 0x3d, 0xd6, 0x00, 0x78, 0x8c, 0x6b, 0x54, 0x44, 0x56, 0x71, 0x0d, 0xe9,
+    0x2a, 0xa6, 0x30, 0x15, 0xa4, 0xea, 0xf4, 0xc6, 0x7b, 0xdc, 0x07, 0x50, 0xac, 0x0b, 0x50, 0x15,
+    0x64, 0x13, 0x6b, 0xa3, 0x99, 0xdf, 0x57, 0x6c, 0x3f, 0x07, 0x88, 0xbc, 0xca, 0x08, 0x60, 0xc8,
+    0x53, 0x6f, 0x33, 0xf4, 0x3a, 0x93, 0xc1, 0xa4, 0xec, 0x25, 0xcd, 0xac, 0x80, 0x61, 0xd3, 0xb2,
+    0x43, 0x96, 0x0c, 0xe6, 0xa6, 0x70, 0x8e, 0x8a, 0x13, 0x43, 0x6f, 0xb3, 0x83, 0xaf, 0x67, 0x3b,
+    0x1e, 0xbb, 0xcb, 0xcc, 0xc8, 0x56, 0xae, 0x07, 0xc3, 0x6e, 0xd5, 0x0c, 0x57, 0xaa, 0x20, 0x58,
+    0xdb, 0x27, 0x18, 0xde, 0x21, 0x5d, 0x4d, 0xa7, 0x7a, 0xe5, 0xe0, 0x76, 0xd5, 0x74, 0x20, 0x32,
+    0xd0, 0xa3, 0xcb, 0x78, 0x27, 0x4f, 0xdf, 0xfa, 0x2c, 0x1e, 0x03, 0x0f, 0x92, 0x55, 0x12, 0xbb,
+    0x43, 0x07, 0x4c, 0x5d, 0xd5, 0xa2, 0x01, 0xc6, 0x1a, 0x62, 0x88, 0x57, 0x14, 0x47, 0x13, 0x2d,
+    0xab, 0x7b, 0xc1, 0x40, 0xb8, 0xe1, 0xda, 0x2d, 0x05, 0x91, 0xec, 0x2a, 0xa8, 0x9a, 0x83, 0x6f,
+    0xa1, 0x39, 0x2e, 0x1b, 0x6f, 0xe8, 0xf2, 0x89, 0xba, 0x6d, 0xda, 0x86, 0xc4, 0x70, 0x97, 0x94,
+    0xd7, 0x8c, 0xf5, 0x95, 0x2a, 0xa3, 0x9c, 0x37, 0x1a, 0x86, 0x7f, 0xa9, 0x87, 0xed, 0xb1, 0xec,
+    0xd3, 0xd2, 0x0d, 0x65, 0xd2, 0x9d, 0x39, 0x79, 0x29, 0x13, 0x20, 0x07, 0xc8, 0x9f, 0xd2, 0xc7,
+    0xd6, 0x3f, 0xf0, 0x9b, 0x7c, 0x47, 0x95, 0x7a, 0x73, 0xde, 0xb1, 0xc7, 0xcc, 0xce, 0xd0, 0x34,
+    0xd6, 0x07, 0x1d, 0xe5, 0x7b, 0x41, 0x71, 0x68, 0xef, 0x67, 0x23, 0x92, 0xc7, 0xc1, 0x36, 0x77,
+    0x36, 0x43, 0x5a, 0x47, 0xbb, 0xa6, 0xeb, 0x78, 0x4b, 0xe3, 0xec, 0x22, 0xa4, 0xd9, 0x92, 0x68,
+    0x09, 0xb3, 0xd7, 0xf2, 0xd5, 0xdb, 0x29, 0xf7, 0x5a, 0xd3, 0xad, 0x8f, 0x39, 0xa0, 0x30, 0x14,
+    0x73, 0x97, 0x4e, 0xba, 0x4e, 0x14, 0x06, 0x97, 0x35, 0x18, 0x5a, 0x95, 0xbf, 0xcd, 0xc9, 0xf4,
+    0xf5, 0xbd, 0xb8, 0xa4, 0x19, 0xe5, 0x53, 0xbe, 0x6a, 0xf8, 0x21, 0xff, 0x96, 0x26, 0x45, 0xe4,
+    0xd8, 0x2d, 0x15, 0x6e, 0xcb, 0x8c, 0x53, 0x7c, 0x2d, 0xa1, 0xd7, 0x4a, 0xce, 0x9d, 0x85, 0xc1,
+    0x3b, 0xc7, 0x09, 0x40, 0x4e, 0xdf, 0x1a, 0x8e, 0x94, 0xbc, 0x7d, 0x7b, 0x8a, 0x67, 0xcb, 0xe5,
+    0x9d, 0xc1, 0xeb, 0x95, 0x6f, 0x17, 0xa5, 0x1e, 0x3d, 0x37, 0x55, 0x4b, 0xdc, 0x4c, 0x7d, 0x58,
+    0x43, 0x49, 0x4a, 0x92, 0x43, 0xf2, 0x63, 0x64, 0xb7, 0xa1, 0x96, 0x04, 0x9a, 0x4b, 0x09, 0x66,
+    0x3d, 0x4c, 0x6b, 0x9f, 0xef, 0xd4, 0x95, 0x3f, 0xc7, 0x69, 0xa3, 0xc0, 0x57, 0x16, 0xef, 0xa1,
+    0x80, 0x9b, 0x1d, 0xa4, 0x12, 0x5d, 0x3c, 0x97, 0x2b, 0x47, 0xa5, 0x40, 0x16, 0xd1, 0x1e, 0x72,
+    0x75, 0x29, 0xb6, 0xb6, 0x8c, 0x07, 0xea, 0xcd, 0x38, 0x5c, 0x0e, 0xae, 0x5e, 0x3f, 0x2d, 0x57,
+    0x31, 0xf4, 0xbb, 0xd6, 0x2c, 0x81, 0x53, 0x57, 0x8e, 0xd1, 0x54, 0x6f, 0x96, 0x27, 0x9e, 0x73,
+    0x7f, 0x89, 0xad, 0x3d, 0x42, 0x2f, 0xe4, 0xa0, 0xa1, 0x3b, 0x62, 0x26, 0x89, 0xc4, 0x6e, 0xf6,
+    0xd5, 0x2e, 0x99, 0xcf, 0xcf, 0x26, 0x6f, 0xa2, 0xf7, 0xa2, 0x48, 0xc8, 0x6b, 0xfd, 0x07, 0xa2,
+    0xe0, 0x57, 0x2f, 0x8a, 0x48, 0x93, 0x02, 0x48, 0x96, 0x67, 0xe6, 0x00, 0x6d, 0x65, 0x01, 0xc0,
+    0xf6, 0x2f, 0x84, 0xf9, 0xed, 0x80, 0x52, 0xbb, 0xe5, 0x48, 0x35, 0x62, 0x24, 0xae, 0x74, 0x28,
+    0x96, 0xdc, 0x49, 0xb2, 0x61, 0xb6, 0xfe, 0xb0, 0x88, 0x51, 0x26, 0x07, 0xcc, 0x8d, 0xb9, 0xb4,
+    0x3c, 0x11, 0x1c, 0xd0, 0x9b, 0xec, 0x42, 0xf4, 0xa8, 0x72, 0x15, 0xfe, 0x1f, 0x18, 0x5e, 0x34,
+    0x45, 0xc9, 0x85, 0xd1, 0xc9, 0xc5, 0x50, 0xe2, 0x65, 0x64, 0x5d, 0x9a, 0x1e, 0x61, 0xe4, 0x72,
+    0x42, 0x54, 0x6e, 0x2e, 0x99, 0x9b, 0x7f, 0xf6, 0x39, 0x7c, 0xcd, 0xcf, 0x1d, 0x7f, 0x10, 0x0c,
+    0xb8, 0x19, 0xa5, 0x8e, 0x24, 0xd4, 0xfd, 0x0e, 0xf2, 0x41, 0xb0, 0x5b, 0x4e, 0xa4, 0xa0, 0x8c,
+    0xb0, 0xe9, 0xc5, 0x38, 0x5a, 0x7f, 0xb5, 0x11, 0x41, 0x6c, 0x09, 0x06, 0x87, 0xff, 0x1b, 0xf0,
+    0x15, 0x39, 0xc7, 0x17, 0xeb, 0x4f, 0x5b, 0x0e, 0x28, 0xb8, 0x79, 0x19, 0x1b, 0x50, 0xd3, 0x69,
+    0xae, 0x35, 0xa0, 0x23, 0x53, 0xa2, 0x8f, 0x5e, 0xe2, 0xca, 0xef, 0xf7, 0x96, 0x8d, 0xb8, 0xf6,
+    0x23, 0x9b, 0x93, 0x57, 0x0e, 0x33, 0x90, 0x59, 0xb5, 0x02, 0x2b, 0xae, 0xda, 0x1f, 0x19, 0x51,
+    0x0e, 0xf8, 0x6f, 0xb7, 0xc3, 0xcf, 0x2d, 0x64, 0x34, 0xb7, 0x06, 0xf9, 0xa3, 0xe9, 0xef, 0x52,
+    0x5a, 0x96, 0xc0, 0xc1, 0x91, 0x07, 0xd8, 0x62, 0x46, 0x1a, 0x70, 0x76, 0x7e, 0x45, 0x52, 0x95,
+    0x19, 0xb6, 0x5c, 0x15, 0x39, 0x91, 0xe4, 0x97, 0xcd, 0x7a, 0xf9, 0xb0, 0xc3, 0xef, 0xf4, 0xf4,
+    0x2a, 0x0d, 0x6f, 0x2d, 0x8c, 0xb8, 0x5c, 0x8e, 0x39, 0xd1, 0xa2, 0x93, 0xb2, 0x0b, 0x48, 0x8a,
+    0xa7, 0x06, 0xd1, 0xc2, 0x5e, 0x72, 0xf5, 0xee, 0xef, 0x21, 0x24, 0xf4, 0xcd, 0x1c, 0x6d, 0x98,
+    0x9a, 0x65, 0x52, 0xd6, 0x67, 0xed, 0xfd, 0x0b, 0x22, 0x44, 0x5e, 0x0e, 0xd0, 0x04, 0xd1, 0x3c,
+    0xf2, 0xff, 0xea, 0x79, 0x86, 0xa9, 0xda, 0xee, 0x5e, 0x10, 0xb1, 0xf8, 0x53, 0x8a, 0x87, 0x1f,
+    0xf1, 0x92, 0x47, 0xde, 0x15, 0x50, 0xf5, 0x3f, 0x83, 0xe1, 0x61, 0x50, 0x7b, 0xea, 0xad, 0x06,
+    0x04, 0xea, 0x63, 0x64, 0x2b, 0xbd, 0xdb, 0x35, 0x1f, 0x11, 0x09, 0x49, 0x90, 0x51, 0xf5, 0x58,
+    0x97, 0xe0, 0xcd, 0xaf, 0x30, 0x1a, 0xef, 0xb0, 0x7f, 0xc3, 0x9b, 0xac, 0xd6, 0x07, 0xe6, 0x8d,
+    0x4e, 0x1b, 0x17, 0x37, 0xc0, 0xe5, 0xf5, 0x66, 0x7f, 0x47, 0x14, 0xd2, 0x4a, 0x2d, 0x72, 0x8b,
+    0x1d, 0x6e, 0x3a, 0x67, 0xd0, 0x6c, 0xc9, 0xa6, 0xd2, 0x71, 0x7c, 0xc7, 0xb6, 0x21, 0xac, 0x11,
+    0x1e, 0xf2, 0x66, 0xdb, 0x6a, 0xdb, 0x88, 0x9e, 0xa2, 0x07, 0x81, 0xf8, 0xcf, 0x13, 0x8d, 0x8e,
+    0xfd, 0x22, 0x73, 0xd6, 0x0a, 0xbc, 0xa1, 0xcb, 0xcb, 0xfb, 0xc0, 0x3d, 0x6c, 0xd4, 0x35, 0x8e,
+    0x6e, 0x0e, 0x15, 0xa1, 0xb5, 0x04, 0xa2, 0xde, 0xff, 0xa4, 0x97, 0xf7, 0xf2, 0x0a, 0xd3, 0x8b,
+    0x0e, 0xfa, 0x77, 0xa8, 0x19, 0x1e, 0x4b, 0xce, 0x52, 0x74, 0x07, 0x45, 0x26, 0x77, 0xfe, 0x57,
+    0x7d, 0xe0, 0x59, 0xb3, 0xbc, 0xaf, 0xba, 0x19, 0xc3, 0xc6, 0xbf, 0x8f, 0x26, 0x8f, 0x77, 0x8e,
+    0xc1, 0xf5, 0xda, 0x8e, 0x75, 0xfa, 0x4f, 0x42, 0x08, 0x0a, 0xd1, 0x32, 0x43, 0xee, 0xd0, 0x32,
+    0x15, 0x38, 0x76, 0x39, 0x6a, 0x45, 0x6c, 0xe9, 0x24, 0x66, 0x58, 0x3a, 0xac, 0x81, 0x14, 0x6a,
+    0xff, 0xde, 0x41, 0xef, 0x7e, 0xdd, 0xbc, 0x04, 0xf6, 0x0a, 0x63, 0xc5, 0x35, 0xa5, 0x9e, 0x10,
+];
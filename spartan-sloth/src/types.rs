@@ -0,0 +1,244 @@
+//! Strongly-typed wrappers around byte arrays, such that for instance genesis piece and encoding or
+//! encoding key hash and expanded IV can't be accidentally swapped
+
+use core::array::TryFromSliceError;
+use core::convert::{TryFrom, TryInto};
+use core::fmt;
+use core::str::FromStr;
+
+/// Errors that can happen when parsing hex string
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FromHexError {
+    /// String length doesn't match twice the number of bytes
+    InvalidLength,
+    /// String contains character that is not a hex digit
+    InvalidCharacter,
+}
+
+impl fmt::Display for FromHexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FromHexError::InvalidLength => write!(f, "Invalid hex string length"),
+            FromHexError::InvalidCharacter => write!(f, "Invalid hex character"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FromHexError {}
+
+fn write_hex(bytes: &[u8], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    bytes.iter().try_for_each(|byte| write!(f, "{:02x}", byte))
+}
+
+fn parse_hex(s: &str, bytes: &mut [u8]) -> Result<(), FromHexError> {
+    if s.len() != bytes.len() * 2 {
+        return Err(FromHexError::InvalidLength);
+    }
+
+    let digit = |c: u8| match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(FromHexError::InvalidCharacter),
+    };
+    for (byte, pair) in bytes.iter_mut().zip(s.as_bytes().chunks_exact(2)) {
+        *byte = (digit(pair[0])? << 4) | digit(pair[1])?;
+    }
+
+    Ok(())
+}
+
+/// Common conversions: from/into byte array, `AsRef<[u8]>`/`AsMut<[u8]>`, `TryFrom<&[u8]>` and hex
+/// `Debug`/`Display`/`FromStr`
+macro_rules! impl_bytes {
+    ([$($generics:tt)*] $name:ident $(<$parameter:ident>)?, $size:expr) => {
+        impl<$($generics)*> $name$(<$parameter>)? {
+            /// Bytes as array
+            pub fn as_bytes(&self) -> &[u8; $size] {
+                &self.0
+            }
+
+            /// Bytes as mutable array
+            pub fn as_bytes_mut(&mut self) -> &mut [u8; $size] {
+                &mut self.0
+            }
+        }
+
+        impl<$($generics)*> From<[u8; $size]> for $name$(<$parameter>)? {
+            fn from(bytes: [u8; $size]) -> Self {
+                Self(bytes)
+            }
+        }
+
+        impl<$($generics)*> From<$name$(<$parameter>)?> for [u8; $size] {
+            fn from(value: $name$(<$parameter>)?) -> Self {
+                value.0
+            }
+        }
+
+        impl<$($generics)*> TryFrom<&[u8]> for $name$(<$parameter>)? {
+            type Error = TryFromSliceError;
+
+            fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+                bytes.try_into().map(Self)
+            }
+        }
+
+        impl<$($generics)*> AsRef<[u8]> for $name$(<$parameter>)? {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl<$($generics)*> AsMut<[u8]> for $name$(<$parameter>)? {
+            fn as_mut(&mut self) -> &mut [u8] {
+                &mut self.0
+            }
+        }
+
+        impl<$($generics)*> fmt::Debug for $name$(<$parameter>)? {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}(", stringify!($name))?;
+                write_hex(&self.0, f)?;
+                write!(f, ")")
+            }
+        }
+
+        impl<$($generics)*> fmt::Display for $name$(<$parameter>)? {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write_hex(&self.0, f)
+            }
+        }
+
+        impl<$($generics)*> FromStr for $name$(<$parameter>)? {
+            type Err = FromHexError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut bytes = [0u8; $size];
+                parse_hex(s, &mut bytes)?;
+                Ok(Self(bytes))
+            }
+        }
+    };
+}
+
+/// Piece of data before encoding (for instance, genesis piece)
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct Piece<const PIECE_SIZE_BYTES: usize = 4096>([u8; PIECE_SIZE_BYTES]);
+
+impl_bytes!([const PIECE_SIZE_BYTES: usize] Piece<PIECE_SIZE_BYTES>, PIECE_SIZE_BYTES);
+
+/// Encoding of a piece
+///
+/// Comparison takes the same time regardless of where encodings differ, such that checking
+/// encoding received from untrusted party doesn't leak its contents through timing.
+#[derive(Copy, Clone, Eq)]
+#[repr(transparent)]
+pub struct Encoding<const PIECE_SIZE_BYTES: usize = 4096>([u8; PIECE_SIZE_BYTES]);
+
+impl_bytes!([const PIECE_SIZE_BYTES: usize] Encoding<PIECE_SIZE_BYTES>, PIECE_SIZE_BYTES);
+
+impl<const PIECE_SIZE_BYTES: usize> PartialEq for Encoding<PIECE_SIZE_BYTES> {
+    fn eq(&self, other: &Self) -> bool {
+        let difference = self
+            .0
+            .iter()
+            .zip(other.0.iter())
+            .fold(0, |difference, (a, b)| difference | (a ^ b));
+        // Prevents compiler from short-circuiting the loop above
+        core::hint::black_box(difference) == 0
+    }
+}
+
+impl<const PIECE_SIZE_BYTES: usize> Encoding<PIECE_SIZE_BYTES> {
+    /// Views encodings as byte arrays, for instance, to encode pieces in place with
+    /// [`SlothBackend::encode_many()`](crate::SlothBackend::encode_many())
+    pub fn slice_as_bytes_mut(encodings: &mut [Self]) -> &mut [[u8; PIECE_SIZE_BYTES]] {
+        // SAFETY: `Encoding` is `#[repr(transparent)]` wrapper around byte array
+        unsafe { core::slice::from_raw_parts_mut(encodings.as_mut_ptr().cast(), encodings.len()) }
+    }
+}
+
+/// Hash of encoding key, combined with nonce into [`ExpandedIv`]
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct EncodingKeyHash([u8; 32]);
+
+impl_bytes!([] EncodingKeyHash, 32);
+
+/// IV of the first layer of encoding, one block of `PRIME_SIZE_BYTES`
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct ExpandedIv<const PRIME_SIZE_BYTES: usize = 32>([u8; PRIME_SIZE_BYTES]);
+
+impl_bytes!([const PRIME_SIZE_BYTES: usize] ExpandedIv<PRIME_SIZE_BYTES>, PRIME_SIZE_BYTES);
+
+impl<const PRIME_SIZE_BYTES: usize> ExpandedIv<PRIME_SIZE_BYTES> {
+    /// Views expanded IVs as byte arrays, for instance, to pass them to implementations that take
+    /// byte arrays
+    pub fn slice_as_bytes(expanded_ivs: &[Self]) -> &[[u8; PRIME_SIZE_BYTES]] {
+        // SAFETY: `ExpandedIv` is `#[repr(transparent)]` wrapper around byte array
+        unsafe { core::slice::from_raw_parts(expanded_ivs.as_ptr().cast(), expanded_ivs.len()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::*;
+
+    #[test]
+    fn test_conversions() {
+        let mut bytes = [0u8; 64];
+        rand::thread_rng().fill(&mut bytes[..]);
+        let encoding = Encoding::from(bytes);
+        assert_eq!(encoding.as_ref(), &bytes[..]);
+        assert_eq!(<[u8; 64]>::from(encoding), bytes);
+        assert_eq!(Encoding::try_from(&bytes[..]).unwrap(), encoding);
+        assert!(Encoding::<64>::try_from(&bytes[1..]).is_err());
+
+        let hex = encoding.to_string();
+        assert_eq!(hex.len(), 128);
+        assert_eq!(hex.parse::<Encoding<64>>().unwrap(), encoding);
+        assert_eq!(
+            hex.to_uppercase().parse::<Encoding<64>>().unwrap(),
+            encoding
+        );
+        assert_eq!(format!("{:?}", encoding), format!("Encoding({})", hex));
+        assert_eq!(
+            hex[1..].parse::<Encoding<64>>(),
+            Err(FromHexError::InvalidLength)
+        );
+        assert_eq!(
+            format!("g{}", &hex[1..]).parse::<Encoding<64>>(),
+            Err(FromHexError::InvalidCharacter)
+        );
+
+        let encoding_key_hash = EncodingKeyHash::from([0x0f; 32]);
+        assert_eq!(encoding_key_hash.to_string(), "0f".repeat(32));
+
+        let expanded_ivs = [ExpandedIv::from([1u8; 32]), ExpandedIv::from([2u8; 32])];
+        assert_eq!(
+            ExpandedIv::slice_as_bytes(&expanded_ivs),
+            &[[1u8; 32], [2u8; 32]]
+        );
+    }
+
+    #[test]
+    fn test_encoding_eq() {
+        let encoding = Encoding::from([5u8; 4096]);
+        for position in [0, 1000, 4095] {
+            let mut other = encoding;
+            other.as_mut()[position] ^= 1;
+            assert_ne!(encoding, other);
+        }
+        assert_eq!(encoding, Encoding::from([5u8; 4096]));
+
+        let mut encodings = [encoding; 2];
+        Encoding::slice_as_bytes_mut(&mut encodings)[1][0] = 0;
+        assert_eq!(encodings[0], encoding);
+        assert_eq!(encodings[1].as_bytes()[0], 0);
+    }
+}
@@ -1,29 +1,660 @@
 //! Optimized implementation of Sloth in x86-64 assembly for 256-bit prime
 //! 115792089237316195423570985008687907853269984665640564039457584007913129639747
+//!
+//! Prime is `2^256 - 189`, so multiplication results are reduced by folding upper half multiplied
+//! by 189 into the lower half. Multiplication and squaring use MULX/ADCX/ADOX instructions and
+//! require CPU with BMI2 and ADX extensions.
 
-#[derive(Debug)]
-pub struct DataBiggerThanPrime;
+use crate::prime_256::{
+    self, blocks_to_piece, piece_to_blocks, Arithmetic, Limbs, BLOCKS_PER_PIECE, BLOCK_SIZE_BYTES,
+    PRIME_BYTES,
+};
+use crate::{DecodeError, EncodeError, Encoding, ExpandedIv, Piece, SlothBackend};
+use std::arch::asm;
+
+/// Multiplies two 256-bit numbers into 512-bit product
+#[inline(always)]
+fn mul_wide(a: &Limbs, b: &Limbs) -> [u64; 8] {
+    let mut wide = [0u64; 8];
+    // SAFETY: Pointers are valid for the whole duration of the block, `Sloth::new()` ensures CPU
+    // supports BMI2 and ADX
+    unsafe {
+        asm!(
+            // a * b[0] -> columns 0..4 in r0..r4
+            "mov rdx, qword ptr [{b}]",
+            "mulx {r1}, {r0}, qword ptr [{a}]",
+            "mulx {r2}, {lo}, qword ptr [{a} + 8]",
+            "add {r1}, {lo}",
+            "mulx {r3}, {lo}, qword ptr [{a} + 16]",
+            "adc {r2}, {lo}",
+            "mulx {r4}, {lo}, qword ptr [{a} + 24]",
+            "adc {r3}, {lo}",
+            "adc {r4}, 0",
+            "mov qword ptr [{wide}], {r0}",
+            // a * b[1] -> columns 1..5 in r1, r2, r3, r4, r0
+            "mov rdx, qword ptr [{b} + 8]",
+            "xor {zero:e}, {zero:e}",
+            "mulx {hi}, {lo}, qword ptr [{a}]",
+            "adcx {r1}, {lo}",
+            "adox {r2}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{a} + 8]",
+            "adcx {r2}, {lo}",
+            "adox {r3}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{a} + 16]",
+            "adcx {r3}, {lo}",
+            "adox {r4}, {hi}",
+            "mulx {r0}, {lo}, qword ptr [{a} + 24]",
+            "adcx {r4}, {lo}",
+            "adox {r0}, {zero}",
+            "adcx {r0}, {zero}",
+            "mov qword ptr [{wide} + 8], {r1}",
+            // a * b[2] -> columns 2..6 in r2, r3, r4, r0, r1
+            "mov rdx, qword ptr [{b} + 16]",
+            "xor {zero:e}, {zero:e}",
+            "mulx {hi}, {lo}, qword ptr [{a}]",
+            "adcx {r2}, {lo}",
+            "adox {r3}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{a} + 8]",
+            "adcx {r3}, {lo}",
+            "adox {r4}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{a} + 16]",
+            "adcx {r4}, {lo}",
+            "adox {r0}, {hi}",
+            "mulx {r1}, {lo}, qword ptr [{a} + 24]",
+            "adcx {r0}, {lo}",
+            "adox {r1}, {zero}",
+            "adcx {r1}, {zero}",
+            "mov qword ptr [{wide} + 16], {r2}",
+            // a * b[3] -> columns 3..7 in r3, r4, r0, r1, r2
+            "mov rdx, qword ptr [{b} + 24]",
+            "xor {zero:e}, {zero:e}",
+            "mulx {hi}, {lo}, qword ptr [{a}]",
+            "adcx {r3}, {lo}",
+            "adox {r4}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{a} + 8]",
+            "adcx {r4}, {lo}",
+            "adox {r0}, {hi}",
+            "mulx {hi}, {lo}, qword ptr [{a} + 16]",
+            "adcx {r0}, {lo}",
+            "adox {r1}, {hi}",
+            "mulx {r2}, {lo}, qword ptr [{a} + 24]",
+            "adcx {r1}, {lo}",
+            "adox {r2}, {zero}",
+            "adcx {r2}, {zero}",
+            "mov qword ptr [{wide} + 24], {r3}",
+            "mov qword ptr [{wide} + 32], {r4}",
+            "mov qword ptr [{wide} + 40], {r0}",
+            "mov qword ptr [{wide} + 48], {r1}",
+            "mov qword ptr [{wide} + 56], {r2}",
+            a = in(reg) a.as_ptr(),
+            b = in(reg) b.as_ptr(),
+            wide = in(reg) wide.as_mut_ptr(),
+            r0 = out(reg) _,
+            r1 = out(reg) _,
+            r2 = out(reg) _,
+            r3 = out(reg) _,
+            r4 = out(reg) _,
+            lo = out(reg) _,
+            hi = out(reg) _,
+            zero = out(reg) _,
+            out("rdx") _,
+            options(nostack),
+        );
+    }
+    wide
+}
+
+/// Squares a number modulo prime (result is not necessarily fully reduced)
+///
+/// This is the hottest function, so unlike multiplication reduction is done in the same block
+/// without storing 512-bit intermediate result.
+#[inline(always)]
+fn sqr(a: &Limbs) -> Limbs {
+    let mut result = [0u64; 4];
+    // SAFETY: Pointers are valid for the whole duration of the block, `Sloth::new()` ensures CPU
+    // supports BMI2 and ADX
+    unsafe {
+        asm!(
+            // Cross products a[i] * a[j], i < j -> columns 1..6 in t1..t6, t7 is used as zero
+            "mov rdx, qword ptr [{a}]",
+            "mulx {t2}, {t1}, qword ptr [{a} + 8]",
+            "mulx {t3}, {lo}, qword ptr [{a} + 16]",
+            "add {t2}, {lo}",
+            "mulx {t4}, {lo}, qword ptr [{a} + 24]",
+            "adc {t3}, {lo}",
+            "adc {t4}, 0",
+            "mov rdx, qword ptr [{a} + 8]",
+            "xor {t7:e}, {t7:e}",
+            "mulx {hi}, {lo}, qword ptr [{a} + 16]",
+            "adcx {t3}, {lo}",
+            "adox {t4}, {hi}",
+            "mulx {t5}, {lo}, qword ptr [{a} + 24]",
+            "adcx {t4}, {lo}",
+            "adox {t5}, {t7}",
+            "adcx {t5}, {t7}",
+            "mov rdx, qword ptr [{a} + 16]",
+            "mulx {t6}, {lo}, qword ptr [{a} + 24]",
+            "add {t5}, {lo}",
+            "adc {t6}, 0",
+            // Double cross products -> columns 1..7
+            "xor {t7:e}, {t7:e}",
+            "add {t1}, {t1}",
+            "adc {t2}, {t2}",
+            "adc {t3}, {t3}",
+            "adc {t4}, {t4}",
+            "adc {t5}, {t5}",
+            "adc {t6}, {t6}",
+            "adc {t7}, 0",
+            // Add squares a[i] * a[i]
+            "mov rdx, qword ptr [{a}]",
+            "mulx {hi}, {lo}, rdx",
+            "mov qword ptr [{result}], {lo}",
+            "add {t1}, {hi}",
+            "mov rdx, qword ptr [{a} + 8]",
+            "mulx {hi}, {lo}, rdx",
+            "adc {t2}, {lo}",
+            "adc {t3}, {hi}",
+            "mov rdx, qword ptr [{a} + 16]",
+            "mulx {hi}, {lo}, rdx",
+            "adc {t4}, {lo}",
+            "adc {t5}, {hi}",
+            "mov rdx, qword ptr [{a} + 24]",
+            "mulx {hi}, {lo}, rdx",
+            "adc {t6}, {lo}",
+            "adc {t7}, {hi}",
+            // Upper half multiplied by 189 (`2^256 mod prime`): lower words in t4..t7, upper words
+            // in lo, hi, a, c4
+            "mov rdx, 189",
+            "mulx {lo}, {t4}, {t4}",
+            "mulx {hi}, {t5}, {t5}",
+            "mulx {a}, {t6}, {t6}",
+            "mulx {c4}, {t7}, {t7}",
+            "add {t5}, {lo}",
+            "adc {t6}, {hi}",
+            "adc {t7}, {a}",
+            "adc {c4}, 0",
+            // Add lower half
+            "add {t4}, qword ptr [{result}]",
+            "adc {t5}, {t1}",
+            "adc {t6}, {t2}",
+            "adc {t7}, {t3}",
+            "adc {c4}, 0",
+            // Fold small top word multiplied by 189 back in
+            "imul {c4}, {c4}, 189",
+            "add {t4}, {c4}",
+            "adc {t5}, 0",
+            "adc {t6}, 0",
+            "adc {t7}, 0",
+            // Fold potential carry once more, can't overflow again
+            "sbb {c4}, {c4}",
+            "and {c4}, 189",
+            "add {t4}, {c4}",
+            "adc {t5}, 0",
+            "adc {t6}, 0",
+            "adc {t7}, 0",
+            "mov qword ptr [{result}], {t4}",
+            "mov qword ptr [{result} + 8], {t5}",
+            "mov qword ptr [{result} + 16], {t6}",
+            "mov qword ptr [{result} + 24], {t7}",
+            a = inout(reg) a.as_ptr() => _,
+            result = in(reg) result.as_mut_ptr(),
+            t1 = out(reg) _,
+            t2 = out(reg) _,
+            t3 = out(reg) _,
+            t4 = out(reg) _,
+            t5 = out(reg) _,
+            t6 = out(reg) _,
+            t7 = out(reg) _,
+            lo = out(reg) _,
+            hi = out(reg) _,
+            c4 = out(reg) _,
+            out("rdx") _,
+            options(nostack),
+        );
+    }
+    result
+}
+
+/// Reduces 512-bit number modulo prime, result is below `2^256`, but may not be below prime
+#[inline(always)]
+fn reduce(wide: &[u64; 8]) -> Limbs {
+    let mut result = [0u64; 4];
+    // SAFETY: Pointers are valid for the whole duration of the block, `Sloth::new()` ensures CPU
+    // supports BMI2 and ADX
+    unsafe {
+        asm!(
+            // Upper half multiplied by 189 (`2^256 mod prime`): lower words in h0..h3, upper words
+            // in c1..c4
+            "mov rdx, 189",
+            "mov {h0}, qword ptr [{wide} + 32]",
+            "mov {h1}, qword ptr [{wide} + 40]",
+            "mov {h2}, qword ptr [{wide} + 48]",
+            "mov {h3}, qword ptr [{wide} + 56]",
+            "mulx {c1}, {h0}, {h0}",
+            "mulx {c2}, {h1}, {h1}",
+            "mulx {c3}, {h2}, {h2}",
+            "mulx {c4}, {h3}, {h3}",
+            // Combine upper words (OF chain) and add lower half (CF chain)
+            "xor {zero:e}, {zero:e}",
+            "adox {h1}, {c1}",
+            "adcx {h0}, qword ptr [{wide}]",
+            "adox {h2}, {c2}",
+            "adcx {h1}, qword ptr [{wide} + 8]",
+            "adox {h3}, {c3}",
+            "adcx {h2}, qword ptr [{wide} + 16]",
+            "adox {c4}, {zero}",
+            "adcx {h3}, qword ptr [{wide} + 24]",
+            "adcx {c4}, {zero}",
+            // Fold small top word multiplied by 189 back in
+            "imul {c4}, {c4}, 189",
+            "add {h0}, {c4}",
+            "adc {h1}, 0",
+            "adc {h2}, 0",
+            "adc {h3}, 0",
+            // Fold potential carry once more, can't overflow again
+            "sbb {c4}, {c4}",
+            "and {c4}, 189",
+            "add {h0}, {c4}",
+            "adc {h1}, 0",
+            "adc {h2}, 0",
+            "adc {h3}, 0",
+            "mov qword ptr [{result}], {h0}",
+            "mov qword ptr [{result} + 8], {h1}",
+            "mov qword ptr [{result} + 16], {h2}",
+            "mov qword ptr [{result} + 24], {h3}",
+            wide = in(reg) wide.as_ptr(),
+            result = in(reg) result.as_mut_ptr(),
+            h0 = out(reg) _,
+            h1 = out(reg) _,
+            h2 = out(reg) _,
+            h3 = out(reg) _,
+            c1 = out(reg) _,
+            c2 = out(reg) _,
+            c3 = out(reg) _,
+            c4 = out(reg) _,
+            zero = out(reg) _,
+            out("rdx") _,
+            options(nostack),
+        );
+    }
+    result
+}
+
+/// Multiplies two numbers modulo prime (result is not necessarily fully reduced)
+#[inline(always)]
+fn mul(a: &Limbs, b: &Limbs) -> Limbs {
+    reduce(&mul_wide(a, b))
+}
+
+/// Arithmetic with MULX/ADCX/ADOX instructions, requires CPU with BMI2 and ADX extensions
+struct Bmi2Adx;
+
+impl Arithmetic for Bmi2Adx {
+    #[inline(always)]
+    fn mul(a: &Limbs, b: &Limbs) -> Limbs {
+        mul(a, b)
+    }
+
+    #[inline(always)]
+    fn sqr(a: &Limbs) -> Limbs {
+        sqr(a)
+    }
+}
+
+/// Piece converted into limbs, see [`Sloth::prepare_piece()`]
+#[derive(Debug, Clone)]
+pub struct PreparedPiece {
+    blocks: [Limbs; BLOCKS_PER_PIECE],
+}
 
 #[derive(Debug, Clone)]
 pub struct Sloth {}
 
 impl Sloth {
+    /// Initializes SLOTH for 256-bit prime
+    ///
+    /// Panics if CPU doesn't support BMI2 and ADX extensions, check [`Sloth::is_supported()`]
+    /// first if unsure.
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        todo!()
+        assert!(
+            Self::is_supported(),
+            "Optimized x86-64 SLOTH requires CPU with BMI2 and ADX support"
+        );
+
+        Self {}
+    }
+
+    /// Whether current CPU supports instructions necessary for this implementation
+    pub fn is_supported() -> bool {
+        is_x86_feature_detected!("bmi2") && is_x86_feature_detected!("adx")
     }
 
     /// Sequentially encodes a 4096 byte piece
     pub fn encode(
         &self,
-        _piece: &mut [u8; 4096],
-        _expanded_iv: [u8; 32],
-    ) -> Result<(), DataBiggerThanPrime> {
-        todo!()
+        piece: &mut [u8; 4096],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+    ) -> Result<(), EncodeError> {
+        if layers == 0 {
+            return Err(EncodeError::ZeroLayers);
+        }
+
+        let mut blocks = piece_to_blocks(piece);
+        prime_256::encode_blocks::<Bmi2Adx>(&mut blocks, expanded_iv.as_bytes(), layers);
+        blocks_to_piece(&blocks, piece);
+
+        Ok(())
+    }
+
+    /// Converts piece into limbs, such that it can be encoded multiple times without conversion
+    pub fn prepare_piece(&self, piece: &Piece<4096>) -> PreparedPiece {
+        PreparedPiece {
+            blocks: piece_to_blocks(piece.as_bytes()),
+        }
+    }
+
+    /// Encodes prepared piece into `encoding`, otherwise the same as [`Sloth::encode()`]
+    pub fn encode_prepared(
+        &self,
+        piece: &PreparedPiece,
+        encoding: &mut Encoding<4096>,
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+    ) -> Result<(), EncodeError> {
+        if layers == 0 {
+            return Err(EncodeError::ZeroLayers);
+        }
+
+        let mut blocks = piece.blocks;
+        prime_256::encode_blocks::<Bmi2Adx>(&mut blocks, expanded_iv.as_bytes(), layers);
+        blocks_to_piece(&blocks, encoding.as_bytes_mut());
+
+        Ok(())
+    }
+
+    /// Same as [`Sloth::encode()`], but for a piece of any length that is a multiple of 32 bytes
+    /// (at least two blocks)
+    pub fn encode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+    ) -> Result<(), EncodeError> {
+        if layers == 0 {
+            return Err(EncodeError::ZeroLayers);
+        }
+        if !crate::is_valid_length(piece.len(), BLOCK_SIZE_BYTES) {
+            return Err(EncodeError::InvalidLength);
+        }
+
+        prime_256::encode_bytes::<Bmi2Adx>(piece, expanded_iv.as_bytes(), layers);
+
+        Ok(())
     }
 
     /// Decodes a 4096 byte encoding in time << encode time
-    pub fn decode(&self, _piece: &mut [u8; 4096], _expanded_iv: [u8; 32]) {
-        todo!()
+    pub fn decode(
+        &self,
+        piece: &mut [u8; 4096],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+    ) -> Result<(), DecodeError> {
+        self.decode_slice(piece, expanded_iv, layers)
+    }
+
+    /// Same as [`Sloth::decode()`], but for an encoding of any length that is a multiple of 32
+    /// bytes (at least two blocks)
+    pub fn decode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+    ) -> Result<(), DecodeError> {
+        if layers == 0 {
+            return Err(DecodeError::ZeroLayers);
+        }
+        if !crate::is_valid_length(piece.len(), BLOCK_SIZE_BYTES) {
+            return Err(DecodeError::InvalidLength);
+        }
+
+        prime_256::decode_bytes::<Bmi2Adx>(piece, expanded_iv.as_bytes(), layers);
+
+        Ok(())
+    }
+}
+
+impl SlothBackend<32, 4096> for Sloth {
+    type PreparedPiece = PreparedPiece;
+    // Blocks are kept on the stack, so no working memory is needed
+    type Scratch = ();
+
+    fn prime(&self) -> [u8; 32] {
+        PRIME_BYTES
+    }
+
+    fn encode(
+        &self,
+        piece: &mut [u8; 4096],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+    ) -> Result<(), EncodeError> {
+        Sloth::encode(self, piece, expanded_iv, layers)
+    }
+
+    fn decode(
+        &self,
+        piece: &mut [u8; 4096],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+    ) -> Result<(), DecodeError> {
+        Sloth::decode(self, piece, expanded_iv, layers)
+    }
+
+    fn encode_with_scratch(
+        &self,
+        piece: &mut [u8; 4096],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+        _scratch: &mut (),
+    ) -> Result<(), EncodeError> {
+        Sloth::encode(self, piece, expanded_iv, layers)
+    }
+
+    fn decode_with_scratch(
+        &self,
+        piece: &mut [u8; 4096],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+        _scratch: &mut (),
+    ) -> Result<(), DecodeError> {
+        Sloth::decode(self, piece, expanded_iv, layers)
+    }
+
+    fn prepare_piece(&self, piece: &Piece<4096>) -> PreparedPiece {
+        Sloth::prepare_piece(self, piece)
+    }
+
+    fn encode_prepared(
+        &self,
+        piece: &PreparedPiece,
+        encoding: &mut Encoding<4096>,
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+        _scratch: &mut (),
+    ) -> Result<(), EncodeError> {
+        Sloth::encode_prepared(self, piece, encoding, expanded_iv, layers)
+    }
+
+    fn encode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+        _scratch: &mut (),
+    ) -> Result<(), EncodeError> {
+        Sloth::encode_slice(self, piece, expanded_iv, layers)
+    }
+
+    fn decode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+        _scratch: &mut (),
+    ) -> Result<(), DecodeError> {
+        Sloth::decode_slice(self, piece, expanded_iv, layers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prime_256::PRIME;
+    use crate::test_vectors::KNOWN_PIECE_ENCODING;
+    use rand::prelude::*;
+
+    fn random_bytes<const BYTES: usize>() -> [u8; BYTES] {
+        let mut bytes = [0u8; BYTES];
+        rand::thread_rng().fill(&mut bytes[..]);
+        bytes
+    }
+
+    #[test]
+    fn test_random_piece() {
+        let expanded_iv = ExpandedIv::from(random_bytes());
+        let piece = random_bytes();
+
+        let sloth = Sloth::new();
+        let mut encoding = piece;
+        sloth.encode(&mut encoding, expanded_iv, 1).unwrap();
+        let mut decoding = encoding;
+        sloth.decode(&mut decoding, expanded_iv, 1).unwrap();
+
+        assert_eq!(piece.to_vec(), decoding.to_vec());
+    }
+
+    #[test]
+    fn test_known_piece() {
+        let expanded_iv = ExpandedIv::from([3u8; 32]);
+        let piece = [5u8; 4096];
+
+        let sloth = Sloth::new();
+        let mut encoding = piece;
+        sloth.encode(&mut encoding, expanded_iv, 1).unwrap();
+        assert_eq!(encoding, KNOWN_PIECE_ENCODING);
+        let mut decoding = encoding;
+        sloth.decode(&mut decoding, expanded_iv, 1).unwrap();
+
+        assert_eq!(piece.to_vec(), decoding.to_vec());
+    }
+
+    #[test]
+    fn test_data_bigger_than_prime() {
+        let sloth = Sloth::new();
+
+        let mut prime_piece = [0u8; 4096];
+        prime_piece[..32].copy_from_slice(&PRIME_BYTES);
+
+        for &piece in &[[0xffu8; 4096], prime_piece, [0u8; 4096]] {
+            for layers in 1..=3 {
+                let mut encoding = piece;
+                sloth
+                    .encode(&mut encoding, ExpandedIv::from([0u8; 32]), layers)
+                    .unwrap();
+                let mut decoding = encoding;
+                sloth
+                    .decode(&mut decoding, ExpandedIv::from([0u8; 32]), layers)
+                    .unwrap();
+
+                assert_eq!(piece.to_vec(), decoding.to_vec());
+            }
+        }
+
+        // Prime and zero are swapped, numbers above prime are not changed
+        let mut block = PRIME;
+        prime_256::sqrt_permutation::<Bmi2Adx>(&mut block);
+        assert_eq!(block, [0u64; 4]);
+        let mut block = [u64::MAX; 4];
+        prime_256::sqrt_permutation::<Bmi2Adx>(&mut block);
+        assert_eq!(block, [u64::MAX; 4]);
+    }
+
+    #[test]
+    fn test_slice() {
+        let sloth = Sloth::new();
+        let expanded_iv = ExpandedIv::from(random_bytes());
+
+        // With a single layer the first 4096 bytes are encoded the same way as a 4096 byte piece
+        let piece = (0..1 << 20).map(|_| rand::random()).collect::<Vec<u8>>();
+        let mut encoding = piece.clone();
+        sloth.encode_slice(&mut encoding, expanded_iv, 1).unwrap();
+        let mut expected_encoding = [0u8; 4096];
+        expected_encoding.copy_from_slice(&piece[..4096]);
+        sloth
+            .encode(&mut expected_encoding, expanded_iv, 1)
+            .unwrap();
+        assert_eq!(encoding[..4096], expected_encoding[..]);
+
+        let mut decoding = encoding;
+        sloth.decode_slice(&mut decoding, expanded_iv, 1).unwrap();
+        assert!(decoding == piece);
+
+        for &length in &[0, 32, 4095] {
+            assert_eq!(
+                sloth.encode_slice(&mut vec![0u8; length], expanded_iv, 1),
+                Err(EncodeError::InvalidLength)
+            );
+            assert_eq!(
+                sloth.decode_slice(&mut vec![0u8; length], expanded_iv, 1),
+                Err(DecodeError::InvalidLength)
+            );
+        }
+    }
+
+    #[cfg(feature = "software")]
+    #[test]
+    fn test_matches_software() {
+        use crate::software;
+
+        let software_sloth = software::Sloth::<32, 4096>::with_prime(software::largest_prime(32));
+        let sloth = Sloth::new();
+        assert_eq!(
+            SlothBackend::prime(&sloth),
+            SlothBackend::prime(&software_sloth)
+        );
+
+        for layers in 1..=3 {
+            let expanded_iv = ExpandedIv::from(random_bytes());
+            let piece = random_bytes();
+
+            let mut encoding = piece;
+            sloth.encode(&mut encoding, expanded_iv, layers).unwrap();
+            let mut software_encoding = piece;
+            software_sloth
+                .encode(&mut software_encoding, expanded_iv, layers)
+                .unwrap();
+            assert_eq!(encoding.to_vec(), software_encoding.to_vec());
+
+            let mut decoding = encoding;
+            sloth.decode(&mut decoding, expanded_iv, layers).unwrap();
+            let mut software_decoding = encoding;
+            software_sloth
+                .decode(&mut software_decoding, expanded_iv, layers)
+                .unwrap();
+            assert_eq!(decoding.to_vec(), software_decoding.to_vec());
+        }
+
+        // Blocks bigger than prime and carries through all limbs
+        let expanded_iv = ExpandedIv::from([0xffu8; 32]);
+        let encoding = [0xffu8; 4096];
+        let mut decoding = encoding;
+        sloth.decode(&mut decoding, expanded_iv, 1).unwrap();
+        let mut software_decoding = encoding;
+        software_sloth
+            .decode(&mut software_decoding, expanded_iv, 1)
+            .unwrap();
+        assert_eq!(decoding.to_vec(), software_decoding.to_vec());
     }
 }
@@ -0,0 +1,594 @@
+//! Implementation of Sloth for 256-bit prime
+//! 115792089237316195423570985008687907853269984665640564039457584007913129639747
+//! that encodes 8 independent pieces at the same time using AVX-512 IFMA
+//!
+//! Encoding of one piece is strictly sequential, so instead of making one encoding faster, each of
+//! 8 pieces occupies its own 64-bit lane of 512-bit vectors. Numbers are stored as five 52-bit
+//! limbs, multiplication uses `VPMADD52LUQ`/`VPMADD52HUQ` instructions and results are reduced by
+//! folding upper half multiplied by `2^260 mod prime = 189 * 16` into the lower half. Results are
+//! kept partially reduced (below `2^260`) during exponentiation and fully reduced per lane at the
+//! end, so encodings are identical to those produced by other implementations.
+//!
+//! Decoding of a single piece is vectorized instead: blocks of one layer only depend on encoded
+//! blocks of the same layer, so 8 of them are inverted at the same time.
+
+use crate::lanes;
+use crate::prime_256::{Limbs, BLOCK_SIZE_BYTES, PIECE_SIZE_BYTES};
+use crate::{DecodeError, EncodeError, ExpandedIv};
+use std::arch::x86_64::*;
+
+/// Number of pieces encoded at the same time
+pub const LANES: usize = 8;
+/// Number of 52-bit limbs in one vectorized number
+const VECTOR_LIMBS: usize = 5;
+/// Mask of the lower 52 bits
+const LIMB_MASK: u64 = (1 << 52) - 1;
+/// `2^260 mod prime`
+const FOLD: u64 = 189 << 4;
+
+/// 8 numbers below `2^260`, `i`-th vector contains `i`-th 52-bit limb of each lane
+type Vectors = [__m512i; VECTOR_LIMBS];
+
+/// Propagates carry from `from` into `to`, leaving 52 bits in `from`
+#[inline]
+#[target_feature(enable = "avx512f")]
+fn carry(from: &mut __m512i, to: &mut __m512i) {
+    *to = _mm512_add_epi64(*to, _mm512_srli_epi64::<52>(*from));
+    *from = _mm512_and_si512(*from, _mm512_set1_epi64(LIMB_MASK as i64));
+}
+
+/// Reduces columns of a product (each column below `2^57`) into numbers below `2^260` with 52-bit
+/// limbs
+#[inline]
+#[target_feature(enable = "avx512f,avx512ifma")]
+fn reduce(mut columns: [__m512i; 2 * VECTOR_LIMBS]) -> Vectors {
+    let fold = _mm512_set1_epi64(FOLD as i64);
+
+    // Upper half must consist of 52-bit limbs to be used in multiplication, there is no carry out
+    // of the last column since upper half alone is below `2^520`
+    for i in VECTOR_LIMBS..(2 * VECTOR_LIMBS - 1) {
+        let (lower, upper) = columns.split_at_mut(i + 1);
+        carry(&mut lower[i], &mut upper[0]);
+    }
+
+    // Fold upper half multiplied by `2^260 mod prime` into lower half, `top` is the 6th limb
+    let mut result = [columns[0], columns[1], columns[2], columns[3], columns[4]];
+    let mut top = _mm512_setzero_si512();
+    for i in 0..VECTOR_LIMBS {
+        let high = columns[VECTOR_LIMBS + i];
+        result[i] = _mm512_madd52lo_epu64(result[i], high, fold);
+        if i + 1 < VECTOR_LIMBS {
+            result[i + 1] = _mm512_madd52hi_epu64(result[i + 1], high, fold);
+        } else {
+            top = _mm512_madd52hi_epu64(top, high, fold);
+        }
+    }
+
+    // Normalize limbs and fold 6th limb (which is below `2^13` now), result is below
+    // `2^260 + 2^25`
+    for i in 0..(VECTOR_LIMBS - 1) {
+        let (lower, upper) = result.split_at_mut(i + 1);
+        carry(&mut lower[i], &mut upper[0]);
+    }
+    carry(&mut result[VECTOR_LIMBS - 1], &mut top);
+    result[0] = _mm512_madd52lo_epu64(result[0], top, fold);
+
+    // Normalize limbs again, if there is a carry out of the last limb, remaining number is below
+    // `2^25` and folding carry can't overflow the first limb
+    let mut top = _mm512_setzero_si512();
+    for i in 0..(VECTOR_LIMBS - 1) {
+        let (lower, upper) = result.split_at_mut(i + 1);
+        carry(&mut lower[i], &mut upper[0]);
+    }
+    carry(&mut result[VECTOR_LIMBS - 1], &mut top);
+    result[0] = _mm512_madd52lo_epu64(result[0], top, fold);
+
+    result
+}
+
+/// Multiplies numbers in each lane modulo prime, result is below `2^260`
+#[inline]
+#[target_feature(enable = "avx512f,avx512ifma")]
+fn mul(a: &Vectors, b: &Vectors) -> Vectors {
+    let mut columns = [_mm512_setzero_si512(); 2 * VECTOR_LIMBS];
+    for i in 0..VECTOR_LIMBS {
+        for j in 0..VECTOR_LIMBS {
+            columns[i + j] = _mm512_madd52lo_epu64(columns[i + j], a[i], b[j]);
+            columns[i + j + 1] = _mm512_madd52hi_epu64(columns[i + j + 1], a[i], b[j]);
+        }
+    }
+
+    reduce(columns)
+}
+
+/// Squares numbers in each lane modulo prime, result is below `2^260`
+#[inline]
+#[target_feature(enable = "avx512f,avx512ifma")]
+fn sqr(a: &Vectors) -> Vectors {
+    // Products of different limbs are computed once and doubled
+    let mut columns = [_mm512_setzero_si512(); 2 * VECTOR_LIMBS];
+    for i in 0..VECTOR_LIMBS {
+        for j in (i + 1)..VECTOR_LIMBS {
+            columns[i + j] = _mm512_madd52lo_epu64(columns[i + j], a[i], a[j]);
+            columns[i + j + 1] = _mm512_madd52hi_epu64(columns[i + j + 1], a[i], a[j]);
+        }
+    }
+    for column in columns.iter_mut() {
+        *column = _mm512_add_epi64(*column, *column);
+    }
+    for i in 0..VECTOR_LIMBS {
+        columns[2 * i] = _mm512_madd52lo_epu64(columns[2 * i], a[i], a[i]);
+        columns[2 * i + 1] = _mm512_madd52hi_epu64(columns[2 * i + 1], a[i], a[i]);
+    }
+
+    reduce(columns)
+}
+
+#[inline]
+#[target_feature(enable = "avx512f,avx512ifma")]
+fn sqr_n(a: &Vectors, n: usize) -> Vectors {
+    let mut result = sqr(a);
+    for _ in 1..n {
+        result = sqr(&result);
+    }
+    result
+}
+
+/// Raises numbers in each lane to the power of `(prime + 1) / 4 = 2^254 - 47` using the same
+/// addition chain as optimized x86-64 implementation, result is below `2^260`
+#[target_feature(enable = "avx512f,avx512ifma")]
+fn pow_sqrt_exponent(x: &Vectors) -> Vectors {
+    // `x_n` is `x^(2^n - 1)`
+    let x2 = mul(&sqr(x), x);
+    let x3 = mul(&sqr(&x2), x);
+    let x6 = mul(&sqr_n(&x3, 3), &x3);
+    let x12 = mul(&sqr_n(&x6, 6), &x6);
+    let x24 = mul(&sqr_n(&x12, 12), &x12);
+    let x48 = mul(&sqr_n(&x24, 24), &x24);
+    let x96 = mul(&sqr_n(&x48, 48), &x48);
+    let x192 = mul(&sqr_n(&x96, 96), &x96);
+    let x240 = mul(&sqr_n(&x192, 48), &x48);
+    let x246 = mul(&sqr_n(&x240, 6), &x6);
+    let x248 = mul(&sqr_n(&x246, 2), &x2);
+    // Append `01` and `0001`
+    let result = mul(&sqr_n(&x248, 2), x);
+    mul(&sqr_n(&result, 4), x)
+}
+
+/// Converts numbers of all lanes into vectors of 52-bit limbs
+#[inline]
+#[target_feature(enable = "avx512f")]
+fn to_vectors(numbers: &[Limbs; LANES]) -> Vectors {
+    let mut limbs = [[0u64; LANES]; VECTOR_LIMBS];
+    for (lane, a) in numbers.iter().enumerate() {
+        limbs[0][lane] = a[0] & LIMB_MASK;
+        limbs[1][lane] = ((a[0] >> 52) | (a[1] << 12)) & LIMB_MASK;
+        limbs[2][lane] = ((a[1] >> 40) | (a[2] << 24)) & LIMB_MASK;
+        limbs[3][lane] = ((a[2] >> 28) | (a[3] << 36)) & LIMB_MASK;
+        limbs[4][lane] = a[3] >> 16;
+    }
+
+    let mut vectors = [_mm512_setzero_si512(); VECTOR_LIMBS];
+    for (vector, limbs) in vectors.iter_mut().zip(limbs.iter()) {
+        // SAFETY: `limbs` is exactly 512 bits long
+        *vector = unsafe { _mm512_loadu_si512(limbs.as_ptr() as *const _) };
+    }
+    vectors
+}
+
+/// Converts vectors of 52-bit limbs back into fully reduced numbers of each lane
+#[inline]
+#[target_feature(enable = "avx512f")]
+fn from_vectors(vectors: &Vectors) -> [Limbs; LANES] {
+    let mut limbs = [[0u64; LANES]; VECTOR_LIMBS];
+    for (limbs, vector) in limbs.iter_mut().zip(vectors.iter()) {
+        // SAFETY: `limbs` is exactly 512 bits long
+        unsafe { _mm512_storeu_si512(limbs.as_mut_ptr() as *mut _, *vector) };
+    }
+
+    let mut numbers = [[0u64; 4]; LANES];
+    for (lane, number) in numbers.iter_mut().enumerate() {
+        let a = [
+            limbs[0][lane],
+            limbs[1][lane],
+            limbs[2][lane],
+            limbs[3][lane],
+            limbs[4][lane],
+        ];
+        let low = [
+            a[0] | (a[1] << 52),
+            (a[1] >> 12) | (a[2] << 40),
+            (a[2] >> 24) | (a[3] << 28),
+            (a[3] >> 36) | (a[4] << 16),
+        ];
+        *number = lanes::reduce_full(&low, a[4] >> 48);
+    }
+    numbers
+}
+
+/// Applies sqrt permutation to numbers in all lanes at once, same as sqrt permutation of other
+/// implementations
+#[target_feature(enable = "avx512f,avx512ifma")]
+fn sqrt_permutation(data: &mut [Limbs; LANES]) {
+    let roots_vectors = pow_sqrt_exponent(&to_vectors(data));
+    let roots = from_vectors(&roots_vectors);
+    let squares = from_vectors(&sqr(&roots_vectors));
+
+    for ((data, root), square) in data.iter_mut().zip(roots.iter()).zip(squares.iter()) {
+        lanes::sqrt_permutation_with_root(data, root, square);
+    }
+}
+
+/// Inverts sqrt permutation of numbers in all lanes at once
+#[target_feature(enable = "avx512f,avx512ifma")]
+fn inverse_sqrt(data: &mut [Limbs; LANES]) {
+    let squares = from_vectors(&sqr(&to_vectors(data)));
+
+    for (data, square) in data.iter_mut().zip(squares.iter()) {
+        lanes::inverse_sqrt_with_square(data, square);
+    }
+}
+
+/// SLOTH that encodes [`LANES`] pieces at the same time, encodings are decoded by any other
+/// implementation for 256-bit prime (including this one, which decodes a single piece at a time)
+#[derive(Debug, Clone)]
+pub struct Sloth {}
+
+impl Sloth {
+    /// Initializes SLOTH for 256-bit prime
+    ///
+    /// Panics if CPU doesn't support AVX-512F and AVX-512 IFMA extensions, check
+    /// [`Sloth::is_supported()`] first if unsure.
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        assert!(
+            Self::is_supported(),
+            "AVX-512 IFMA SLOTH requires CPU with AVX-512F and AVX-512 IFMA support"
+        );
+
+        Self {}
+    }
+
+    /// Whether current CPU supports instructions necessary for this implementation
+    pub fn is_supported() -> bool {
+        is_x86_feature_detected!("avx512f") && is_x86_feature_detected!("avx512ifma")
+    }
+
+    /// Sequentially encodes [`LANES`] 4096 byte pieces at the same time, each piece is encoded
+    /// with expanded IV at the same index
+    pub fn encode_lanes(
+        &self,
+        pieces: &mut [[u8; 4096]; LANES],
+        expanded_ivs: [ExpandedIv<32>; LANES],
+        layers: usize,
+    ) -> Result<(), EncodeError> {
+        self.encode_up_to_lanes(pieces, ExpandedIv::slice_as_bytes(&expanded_ivs), layers)
+    }
+
+    /// Same as [`Sloth::encode_lanes()`], but for up to [`LANES`] pieces, used for the remainder of
+    /// a batch
+    pub(crate) fn encode_up_to_lanes(
+        &self,
+        pieces: &mut [[u8; PIECE_SIZE_BYTES]],
+        expanded_ivs: &[[u8; BLOCK_SIZE_BYTES]],
+        layers: usize,
+    ) -> Result<(), EncodeError> {
+        if pieces.len() > LANES || pieces.len() != expanded_ivs.len() {
+            return Err(EncodeError::LengthMismatch);
+        }
+        if layers == 0 {
+            return Err(EncodeError::ZeroLayers);
+        }
+
+        lanes::encode_pieces(pieces, expanded_ivs, layers, |blocks| {
+            // SAFETY: `Sloth::new()` ensures CPU supports AVX-512F and AVX-512 IFMA
+            unsafe { sqrt_permutation(blocks) }
+        });
+
+        Ok(())
+    }
+
+    /// Decodes [`LANES`] 4096 byte encodings at the same time (blocks with the same index of all
+    /// encodings are inverted at once), each encoding is decoded with expanded IV at the same index
+    pub fn decode_lanes(
+        &self,
+        pieces: &mut [[u8; 4096]; LANES],
+        expanded_ivs: [ExpandedIv<32>; LANES],
+        layers: usize,
+    ) -> Result<(), DecodeError> {
+        self.decode_up_to_lanes(pieces, ExpandedIv::slice_as_bytes(&expanded_ivs), layers)
+    }
+
+    /// Same as [`Sloth::decode_lanes()`], but for up to [`LANES`] encodings, used for the remainder
+    /// of a batch
+    pub(crate) fn decode_up_to_lanes(
+        &self,
+        pieces: &mut [[u8; PIECE_SIZE_BYTES]],
+        expanded_ivs: &[[u8; BLOCK_SIZE_BYTES]],
+        layers: usize,
+    ) -> Result<(), DecodeError> {
+        if pieces.len() > LANES || pieces.len() != expanded_ivs.len() {
+            return Err(DecodeError::LengthMismatch);
+        }
+        if layers == 0 {
+            return Err(DecodeError::ZeroLayers);
+        }
+
+        lanes::decode_pieces(pieces, expanded_ivs, layers, |blocks| {
+            // SAFETY: `Sloth::new()` ensures CPU supports AVX-512F and AVX-512 IFMA
+            unsafe { inverse_sqrt(blocks) }
+        });
+
+        Ok(())
+    }
+
+    /// Decodes a 4096 byte encoding, [`LANES`] blocks of each layer are inverted at the same time
+    pub fn decode(
+        &self,
+        piece: &mut [u8; 4096],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+    ) -> Result<(), DecodeError> {
+        self.decode_slice(piece, expanded_iv, layers)
+    }
+
+    /// Same as [`Sloth::decode()`], but for an encoding of any length that is a multiple of 32
+    /// bytes (at least two blocks)
+    pub fn decode_slice(
+        &self,
+        piece: &mut [u8],
+        expanded_iv: ExpandedIv<32>,
+        layers: usize,
+    ) -> Result<(), DecodeError> {
+        if layers == 0 {
+            return Err(DecodeError::ZeroLayers);
+        }
+        if !crate::is_valid_length(piece.len(), BLOCK_SIZE_BYTES) {
+            return Err(DecodeError::InvalidLength);
+        }
+
+        lanes::decode_piece(piece, expanded_iv.as_bytes(), layers, |blocks| {
+            // SAFETY: `Sloth::new()` ensures CPU supports AVX-512F and AVX-512 IFMA
+            unsafe { inverse_sqrt(blocks) }
+        });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lanes::{reduce_full, scalar_mul};
+    use crate::prime_256::{limbs_to_bytes, negate, PRIME};
+    use crate::test_vectors::KNOWN_PIECE_ENCODING;
+    use rand::prelude::*;
+
+    fn random_bytes<const BYTES: usize>() -> [u8; BYTES] {
+        let mut bytes = [0u8; BYTES];
+        rand::thread_rng().fill(&mut bytes[..]);
+        bytes
+    }
+
+    #[test]
+    fn test_known_piece() {
+        if !Sloth::is_supported() {
+            return;
+        }
+        let sloth = Sloth::new();
+
+        let mut pieces = [[5u8; 4096]; LANES];
+        sloth
+            .encode_lanes(&mut pieces, [ExpandedIv::from([3u8; 32]); LANES], 1)
+            .unwrap();
+
+        for encoding in pieces.iter() {
+            assert_eq!(encoding.to_vec(), KNOWN_PIECE_ENCODING.to_vec());
+        }
+    }
+
+    #[test]
+    fn test_matches_x86_64() {
+        if !Sloth::is_supported() || !crate::x86_64::Sloth::is_supported() {
+            return;
+        }
+        let sloth = Sloth::new();
+        let x86_64_sloth = crate::x86_64::Sloth::new();
+
+        let mut prime_piece = [0u8; 4096];
+        limbs_to_bytes(&PRIME, &mut prime_piece[..32]);
+        let mut pieces = [[0u8; 4096]; LANES];
+        for piece in pieces.iter_mut() {
+            *piece = random_bytes();
+        }
+        // Blocks bigger than prime, prime and zero
+        pieces[1] = [0xff; 4096];
+        pieces[2] = prime_piece;
+        pieces[3] = [0; 4096];
+        let mut expanded_ivs = [ExpandedIv::from([0u8; 32]); LANES];
+        for expanded_iv in expanded_ivs.iter_mut().skip(1) {
+            *expanded_iv = ExpandedIv::from(random_bytes());
+        }
+
+        for layers in 1..=2 {
+            let mut encodings = pieces;
+            sloth
+                .encode_lanes(&mut encodings, expanded_ivs, layers)
+                .unwrap();
+
+            for ((piece, &expanded_iv), encoding) in
+                pieces.iter().zip(expanded_ivs.iter()).zip(encodings.iter())
+            {
+                let mut expected_encoding = *piece;
+                x86_64_sloth
+                    .encode(&mut expected_encoding, expanded_iv, layers)
+                    .unwrap();
+                assert_eq!(encoding.to_vec(), expected_encoding.to_vec());
+            }
+        }
+
+        assert_eq!(
+            sloth.encode_lanes(&mut pieces, expanded_ivs, 0),
+            Err(EncodeError::ZeroLayers)
+        );
+    }
+
+    #[test]
+    fn test_decode_matches_x86_64() {
+        if !Sloth::is_supported() || !crate::x86_64::Sloth::is_supported() {
+            return;
+        }
+        let sloth = Sloth::new();
+        let x86_64_sloth = crate::x86_64::Sloth::new();
+
+        let mut prime_piece = [0u8; 4096];
+        limbs_to_bytes(&PRIME, &mut prime_piece[..32]);
+        // Blocks bigger than prime, prime and zero
+        for &piece in &[random_bytes(), [0xff; 4096], prime_piece, [0; 4096]] {
+            let expanded_iv = ExpandedIv::from(random_bytes());
+            for layers in 1..=3 {
+                let mut encoding = piece;
+                x86_64_sloth
+                    .encode(&mut encoding, expanded_iv, layers)
+                    .unwrap();
+
+                let mut decoding = encoding;
+                sloth.decode(&mut decoding, expanded_iv, layers).unwrap();
+                assert_eq!(decoding.to_vec(), piece.to_vec());
+
+                // Decoding of arbitrary data must match too
+                let mut decoding = piece;
+                sloth.decode(&mut decoding, expanded_iv, layers).unwrap();
+                let mut expected_decoding = piece;
+                x86_64_sloth
+                    .decode(&mut expected_decoding, expanded_iv, layers)
+                    .unwrap();
+                assert_eq!(decoding.to_vec(), expected_decoding.to_vec());
+            }
+        }
+
+        // Different encodings interleaved, including edge cases
+        let mut pieces = [[0u8; 4096]; LANES];
+        let mut expanded_ivs = [ExpandedIv::from([0u8; 32]); LANES];
+        for (index, (piece, expanded_iv)) in pieces.iter_mut().zip(&mut expanded_ivs).enumerate() {
+            *piece = match index {
+                0 => [0xff; 4096],
+                1 => prime_piece,
+                _ => random_bytes(),
+            };
+            *expanded_iv = ExpandedIv::from(random_bytes());
+        }
+        for layers in 1..=2 {
+            let mut encodings = pieces;
+            for (encoding, &expanded_iv) in encodings.iter_mut().zip(&expanded_ivs) {
+                x86_64_sloth.encode(encoding, expanded_iv, layers).unwrap();
+            }
+
+            let mut decodings = encodings;
+            sloth
+                .decode_lanes(&mut decodings, expanded_ivs, layers)
+                .unwrap();
+            for (decoding, piece) in decodings.iter().zip(&pieces) {
+                assert_eq!(decoding.to_vec(), piece.to_vec());
+            }
+
+            // Fewer encodings than lanes
+            let mut decodings = encodings;
+            sloth
+                .decode_up_to_lanes(
+                    &mut decodings[..LANES - 1],
+                    &ExpandedIv::slice_as_bytes(&expanded_ivs)[..LANES - 1],
+                    layers,
+                )
+                .unwrap();
+            for (decoding, piece) in decodings.iter().zip(&pieces).take(LANES - 1) {
+                assert_eq!(decoding.to_vec(), piece.to_vec());
+            }
+        }
+        assert_eq!(
+            sloth.decode_up_to_lanes(
+                &mut pieces[..2],
+                &ExpandedIv::slice_as_bytes(&expanded_ivs)[..1],
+                1
+            ),
+            Err(DecodeError::LengthMismatch)
+        );
+        assert_eq!(
+            sloth.decode_lanes(&mut pieces, expanded_ivs, 0),
+            Err(DecodeError::ZeroLayers)
+        );
+
+        // Pieces with the number of blocks that is not a multiple of `LANES`
+        for &blocks in &[
+            2,
+            LANES - 1,
+            LANES + 1,
+            PIECE_SIZE_BYTES / BLOCK_SIZE_BYTES + 3,
+        ] {
+            let piece = (0..blocks * BLOCK_SIZE_BYTES)
+                .map(|_| rand::random())
+                .collect::<Vec<u8>>();
+            let expanded_iv = ExpandedIv::from(random_bytes());
+            for layers in 1..=2 {
+                let mut encoding = piece.clone();
+                x86_64_sloth
+                    .encode_slice(&mut encoding, expanded_iv, layers)
+                    .unwrap();
+
+                let mut decoding = encoding;
+                sloth
+                    .decode_slice(&mut decoding, expanded_iv, layers)
+                    .unwrap();
+                assert_eq!(decoding, piece);
+            }
+        }
+
+        let mut piece = random_bytes();
+        assert_eq!(
+            sloth.decode(&mut piece, ExpandedIv::from(random_bytes()), 0),
+            Err(DecodeError::ZeroLayers)
+        );
+        assert_eq!(
+            sloth.decode_slice(&mut piece[..32], ExpandedIv::from(random_bytes()), 1),
+            Err(DecodeError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn test_reduce_edge_cases() {
+        if !Sloth::is_supported() {
+            return;
+        }
+
+        // `prime - 1` squared is `1`, numbers right below `2^256` and `2^260` exercise carries
+        let mut numbers = [[0u64; 4]; LANES];
+        numbers[0] = negate(&[1, 0, 0, 0]);
+        numbers[1] = [u64::MAX; 4];
+        numbers[2] = PRIME;
+        numbers[3] = [1, 0, 0, 0];
+        numbers[4] = [188, 0, 0, 0];
+        numbers[5] = [0, 0, 0, 1 << 63];
+        numbers[6] = [u64::MAX, 0, u64::MAX, 0];
+
+        for _ in 0..100 {
+            numbers[7] = [random(), random(), random(), random()];
+
+            // SAFETY: Checked above that CPU supports AVX-512F and AVX-512 IFMA
+            let results = unsafe {
+                let mut vectors = to_vectors(&numbers);
+                // Make limbs as big as possible (below `2^260`) while staying congruent
+                let top = _mm512_set1_epi64(((1u64 << 52) - 1) as i64);
+                vectors[4] = _mm512_or_si512(vectors[4], _mm512_slli_epi64::<48>(top));
+                vectors[4] = _mm512_and_si512(vectors[4], top);
+                from_vectors(&mul(&sqr(&vectors), &vectors))
+            };
+
+            for (number, result) in numbers.iter().zip(results.iter()) {
+                // Reference: `(number + 15 * 2^256)^3 mod prime` computed with scalar code
+                let number = reduce_full(number, 0xf);
+                let expected = scalar_mul(&scalar_mul(&number, &number), &number);
+                assert_eq!(result, &expected, "number {:?}", number);
+            }
+        }
+    }
+}
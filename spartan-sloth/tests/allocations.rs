@@ -0,0 +1,165 @@
+//! Checks that encoding and decoding with reused scratch doesn't allocate on the heap, both Rust
+//! and GMP allocations are counted
+
+use spartan_sloth::software::{largest_prime, Scratch, Sloth};
+use spartan_sloth::{Encoding, ExpandedIv, Piece};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::ffi::c_void;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Once;
+
+type GmpAlloc = unsafe extern "C" fn(usize) -> *mut c_void;
+type GmpRealloc = unsafe extern "C" fn(*mut c_void, usize, usize) -> *mut c_void;
+type GmpFree = unsafe extern "C" fn(*mut c_void, usize);
+
+extern "C" {
+    #[link_name = "__gmp_get_memory_functions"]
+    fn gmp_get_memory_functions(
+        alloc: *mut Option<GmpAlloc>,
+        realloc: *mut Option<GmpRealloc>,
+        free: *mut Option<GmpFree>,
+    );
+    #[link_name = "__gmp_set_memory_functions"]
+    fn gmp_set_memory_functions(
+        alloc: Option<GmpAlloc>,
+        realloc: Option<GmpRealloc>,
+        free: Option<GmpFree>,
+    );
+}
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// Only allocations made by the thread that enabled counting are counted
+    static COUNTING: Cell<bool> = const { Cell::new(false) };
+}
+
+fn record_allocation() {
+    if COUNTING.with(Cell::get) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record_allocation();
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record_allocation();
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+static mut GMP_ALLOC: Option<GmpAlloc> = None;
+static mut GMP_REALLOC: Option<GmpRealloc> = None;
+
+unsafe extern "C" fn gmp_alloc(size: usize) -> *mut c_void {
+    record_allocation();
+    (GMP_ALLOC.expect("Set before installing; qed"))(size)
+}
+
+unsafe extern "C" fn gmp_realloc(
+    ptr: *mut c_void,
+    old_size: usize,
+    new_size: usize,
+) -> *mut c_void {
+    record_allocation();
+    (GMP_REALLOC.expect("Set before installing; qed"))(ptr, old_size, new_size)
+}
+
+/// Wraps GMP memory functions, such that GMP allocations are counted too
+fn count_gmp_allocations() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| unsafe {
+        let mut free = None;
+        gmp_get_memory_functions(
+            &raw mut GMP_ALLOC,
+            &raw mut GMP_REALLOC,
+            &mut free as *mut Option<GmpFree>,
+        );
+        gmp_set_memory_functions(Some(gmp_alloc), Some(gmp_realloc), free);
+    });
+}
+
+/// Returns number of heap allocations made by the closure on the current thread
+fn count_allocations<F: FnOnce()>(f: F) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    COUNTING.with(|counting| counting.set(true));
+    f();
+    COUNTING.with(|counting| counting.set(false));
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+#[test]
+fn test_encode_decode_with_scratch_doesnt_allocate() {
+    count_gmp_allocations();
+
+    // 256-bit prime `2^256 - 189` doesn't use GMP, so larger prime is used
+
+    let sloth = Sloth::<64, 4096>::with_prime(largest_prime(64));
+    let piece = Piece::from([5u8; 4096]);
+    let prepared_piece = sloth.prepare_piece(&piece);
+    let mut scratch = Scratch::default();
+
+    // The first use allocates scratch
+    let mut encoding = Encoding::from([0u8; 4096]);
+    sloth
+        .encode_prepared(
+            &prepared_piece,
+            &mut encoding,
+            ExpandedIv::from([3u8; 64]),
+            2,
+            &mut scratch,
+        )
+        .unwrap();
+    let mut decoding = *encoding.as_bytes();
+    sloth
+        .decode_with_scratch(&mut decoding, ExpandedIv::from([3u8; 64]), 2, &mut scratch)
+        .unwrap();
+    assert!(decoding == *piece.as_bytes());
+
+    let allocations = count_allocations(|| {
+        for nonce in 0..4u8 {
+            let expanded_iv = ExpandedIv::from([nonce; 64]);
+
+            sloth
+                .encode_prepared(&prepared_piece, &mut encoding, expanded_iv, 2, &mut scratch)
+                .unwrap();
+            let mut decoding = *encoding.as_bytes();
+            sloth
+                .decode_with_scratch(&mut decoding, expanded_iv, 2, &mut scratch)
+                .unwrap();
+            assert!(decoding == *piece.as_bytes());
+
+            let mut encoding = *piece.as_bytes();
+            sloth
+                .encode_with_scratch(&mut encoding, expanded_iv, 2, &mut scratch)
+                .unwrap();
+        }
+    });
+
+    assert_eq!(allocations, 0);
+
+    // Make sure allocations are actually counted
+    assert!(
+        count_allocations(|| {
+            let mut encoding = *piece.as_bytes();
+            sloth
+                .encode(&mut encoding, ExpandedIv::from([3u8; 64]), 2)
+                .unwrap();
+        }) > 0
+    );
+}
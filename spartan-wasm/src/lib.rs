@@ -0,0 +1,317 @@
+//! WebAssembly bindings for verification of Spartan encodings
+//!
+//! Exports are generated with `wasm-bindgen`, byte arrays are accepted as `Uint8Array` and `nonce`
+//! as `BigInt`. Malformed inputs (wrong length or zero rounds) result in [`Error`] being thrown.
+#![warn(rust_2018_idioms, missing_debug_implementations, missing_docs)]
+
+use spartan_sloth::portable::Sloth;
+use spartan_sloth::{largest_known_prime, DecodeError};
+use std::fmt;
+use wasm_bindgen::prelude::*;
+
+/// Size of the genesis piece and encoding in bytes
+const PIECE_SIZE_BYTES: usize = 4096;
+/// Size of the encoding key hash and expanded IV in bytes
+const PRIME_SIZE_BYTES: usize = 32;
+
+/// Kind of [`Error`]
+#[wasm_bindgen]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ErrorKind {
+    /// Genesis piece has wrong length
+    InvalidGenesisPieceLength,
+    /// Encoding has wrong length
+    InvalidEncodingLength,
+    /// Encoding key hash has wrong length
+    InvalidEncodingKeyHashLength,
+    /// Expanded IV has wrong length
+    InvalidExpandedIvLength,
+    /// Number of rounds (layers) is zero
+    ZeroRounds,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ErrorKind::InvalidGenesisPieceLength => "Invalid genesis piece length",
+            ErrorKind::InvalidEncodingLength => "Invalid encoding length",
+            ErrorKind::InvalidEncodingKeyHashLength => "Invalid encoding key hash length",
+            ErrorKind::InvalidExpandedIvLength => "Invalid expanded IV length",
+            ErrorKind::ZeroRounds => "Number of rounds must not be zero",
+        })
+    }
+}
+
+/// Error thrown to JavaScript when inputs are malformed
+#[wasm_bindgen]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Error {
+    kind: ErrorKind,
+    /// Expected and actual lengths for errors caused by inputs of wrong length
+    lengths: Option<(usize, usize)>,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.lengths {
+            Some((expected_length, actual_length)) => write!(
+                f,
+                "{}: expected {} bytes, got {} bytes",
+                self.kind, expected_length, actual_length
+            ),
+            None => write!(f, "{}", self.kind),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[wasm_bindgen]
+impl Error {
+    /// Kind of error
+    #[wasm_bindgen(getter)]
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Expected length of the input in bytes, `undefined` if error is not related to length
+    #[wasm_bindgen(getter, js_name = expectedLength)]
+    pub fn expected_length(&self) -> Option<usize> {
+        self.lengths.map(|(expected_length, _)| expected_length)
+    }
+
+    /// Actual length of the input in bytes, `undefined` if error is not related to length
+    #[wasm_bindgen(getter, js_name = actualLength)]
+    pub fn actual_length(&self) -> Option<usize> {
+        self.lengths.map(|(_, actual_length)| actual_length)
+    }
+
+    /// Human-readable error message
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Converts slice into an array of specified length, returns error of specified kind otherwise
+fn to_array<const N: usize>(bytes: &[u8], kind: ErrorKind) -> Result<[u8; N], Error> {
+    let mut array = [0u8; N];
+    if bytes.len() != N {
+        return Err(Error {
+            kind,
+            lengths: Some((N, bytes.len())),
+        });
+    }
+    array.copy_from_slice(bytes);
+    Ok(array)
+}
+
+impl From<DecodeError> for Error {
+    fn from(error: DecodeError) -> Self {
+        match error {
+            DecodeError::ZeroLayers => Error {
+                kind: ErrorKind::ZeroRounds,
+                lengths: None,
+            },
+            DecodeError::LengthMismatch | DecodeError::InvalidLength => Error {
+                kind: ErrorKind::InvalidEncodingLength,
+                lengths: None,
+            },
+        }
+    }
+}
+
+/// Spartan instance for verification of encodings, uses 256-bit prime and 4096-byte genesis piece
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct Spartan {
+    inner: spartan_codec::Spartan<Sloth<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>>,
+}
+
+#[wasm_bindgen]
+impl Spartan {
+    /// New instance with 4096-byte genesis piece
+    #[wasm_bindgen(constructor)]
+    pub fn new(genesis_piece: &[u8]) -> Result<Spartan, Error> {
+        let genesis_piece = to_array(genesis_piece, ErrorKind::InvalidGenesisPieceLength)?;
+
+        Ok(Self {
+            inner: spartan_codec::Spartan::with_sloth(
+                genesis_piece.into(),
+                Sloth::with_prime(largest_known_prime().unwrap()),
+            ),
+        })
+    }
+
+    /// Check if previously created encoding is valid
+    #[wasm_bindgen(js_name = isValid)]
+    pub fn is_valid(
+        &self,
+        encoding: &[u8],
+        encoding_key_hash: &[u8],
+        nonce: u64,
+        rounds: usize,
+    ) -> Result<bool, Error> {
+        let encoding = to_array(encoding, ErrorKind::InvalidEncodingLength)?;
+        let encoding_key_hash =
+            to_array(encoding_key_hash, ErrorKind::InvalidEncodingKeyHashLength)?;
+
+        Ok(self
+            .inner
+            .is_valid(encoding.into(), encoding_key_hash.into(), nonce, rounds)?)
+    }
+}
+
+/// Decoder of 4096 byte encodings with SLOTH using 256-bit prime
+///
+/// SLOTH is initialized (prime is checked and Montgomery constants are computed) once in
+/// constructor, which takes much longer than decoding a single layer, so the same instance should
+/// be reused for decoding of many encodings.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct Decoder {
+    sloth: Sloth<PRIME_SIZE_BYTES, PIECE_SIZE_BYTES>,
+}
+
+impl Default for Decoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[wasm_bindgen]
+impl Decoder {
+    /// New decoder with 256-bit prime
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Decoder {
+        Self {
+            sloth: Sloth::with_prime(largest_known_prime().unwrap()),
+        }
+    }
+
+    /// Decodes an encoding, returns decoded piece
+    pub fn decode(
+        &self,
+        encoding: &[u8],
+        expanded_iv: &[u8],
+        layers: usize,
+    ) -> Result<Vec<u8>, Error> {
+        let mut piece = to_array::<PIECE_SIZE_BYTES>(encoding, ErrorKind::InvalidEncodingLength)?;
+        let expanded_iv = to_array(expanded_iv, ErrorKind::InvalidExpandedIvLength)?;
+
+        self.sloth.decode(&mut piece, expanded_iv.into(), layers)?;
+
+        Ok(piece.to_vec())
+    }
+}
+
+thread_local! {
+    /// Decoder used by [`decode()`], initialized on the first call
+    static DECODER: Decoder = Decoder::new();
+}
+
+/// Decodes a 4096 byte encoding with SLOTH using 256-bit prime, returns decoded piece
+///
+/// Same as [`Decoder::decode()`] with a decoder that is shared between calls.
+#[wasm_bindgen]
+pub fn decode(encoding: &[u8], expanded_iv: &[u8], layers: usize) -> Result<Vec<u8>, Error> {
+    DECODER.with(|decoder| decoder.decode(encoding, expanded_iv, layers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_encoding() {
+        let genesis_piece = [5u8; 4096];
+        let encoding_key_hash = [3u8; 32];
+        let encoding = spartan_codec::Spartan::with_sloth(
+            genesis_piece.into(),
+            Sloth::<32, 4096>::with_prime(largest_known_prime().unwrap()),
+        )
+        .encode(encoding_key_hash.into(), 1, 1)
+        .unwrap();
+
+        let spartan = Spartan::new(&genesis_piece).unwrap();
+        assert_eq!(
+            spartan.is_valid(encoding.as_ref(), &encoding_key_hash, 1, 1),
+            Ok(true)
+        );
+        assert_eq!(
+            spartan.is_valid(encoding.as_ref(), &encoding_key_hash, 2, 1),
+            Ok(false)
+        );
+    }
+
+    #[test]
+    fn test_decode() {
+        let piece = [5u8; 4096];
+        let expanded_iv = [3u8; 32];
+        let mut encoding = piece;
+        Sloth::<32, 4096>::with_prime(largest_known_prime().unwrap())
+            .encode(&mut encoding, expanded_iv.into(), 2)
+            .unwrap();
+
+        let decoder = Decoder::new();
+        for _ in 0..2 {
+            assert_eq!(
+                decoder.decode(&encoding, &expanded_iv, 2),
+                Ok(piece.to_vec())
+            );
+            assert_eq!(decode(&encoding, &expanded_iv, 2), Ok(piece.to_vec()));
+        }
+        assert_eq!(
+            decoder
+                .decode(&encoding[1..], &expanded_iv, 2)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidEncodingLength
+        );
+    }
+
+    #[test]
+    fn test_invalid_lengths() {
+        assert_eq!(
+            Spartan::new(&[0u8; 4095]).unwrap_err(),
+            Error {
+                kind: ErrorKind::InvalidGenesisPieceLength,
+                lengths: Some((4096, 4095)),
+            }
+        );
+
+        let spartan = Spartan::new(&[5u8; 4096]).unwrap();
+        assert_eq!(
+            spartan
+                .is_valid(&[0u8; 4096], &[0u8; 31], 1, 1)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidEncodingKeyHashLength
+        );
+        assert_eq!(
+            spartan
+                .is_valid(&[0u8; 4097], &[0u8; 32], 1, 1)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidEncodingLength
+        );
+        assert_eq!(
+            decode(&[0u8; 4096], &[], 1).unwrap_err().kind(),
+            ErrorKind::InvalidExpandedIvLength
+        );
+    }
+
+    #[test]
+    fn test_zero_rounds() {
+        let spartan = Spartan::new(&[5u8; 4096]).unwrap();
+        let error = spartan
+            .is_valid(&[0u8; 4096], &[0u8; 32], 1, 0)
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::ZeroRounds);
+        assert_eq!(error.expected_length(), None);
+        assert_eq!(
+            decode(&[0u8; 4096], &[0u8; 32], 0).unwrap_err().kind(),
+            ErrorKind::ZeroRounds
+        );
+    }
+}
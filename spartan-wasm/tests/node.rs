@@ -0,0 +1,59 @@
+//! Tests that run in Node.js with `wasm-pack test --node` or `cargo test` with
+//! `wasm-bindgen-test-runner` configured as runner for `wasm32-unknown-unknown` target
+#![cfg(target_arch = "wasm32")]
+
+use spartan_sloth::largest_known_prime;
+use spartan_sloth::portable::Sloth;
+use spartan_wasm::{decode, Decoder, ErrorKind, Spartan};
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn test_is_valid() {
+    let genesis_piece = [5u8; 4096];
+    let encoding_key_hash = [3u8; 32];
+    let nonce = u64::MAX - 1;
+    let encoding = spartan_codec::Spartan::with_sloth(
+        genesis_piece.into(),
+        Sloth::<32, 4096>::with_prime(largest_known_prime().unwrap()),
+    )
+    .encode(encoding_key_hash.into(), nonce, 2)
+    .unwrap();
+
+    let spartan = Spartan::new(&genesis_piece).unwrap();
+    assert!(spartan
+        .is_valid(encoding.as_ref(), &encoding_key_hash, nonce, 2)
+        .unwrap());
+    assert!(!spartan
+        .is_valid(encoding.as_ref(), &encoding_key_hash, nonce, 1)
+        .unwrap());
+}
+
+#[wasm_bindgen_test]
+fn test_decode() {
+    let piece = [5u8; 4096];
+    let expanded_iv = [3u8; 32];
+    let mut encoding = piece;
+    Sloth::<32, 4096>::with_prime(largest_known_prime().unwrap())
+        .encode(&mut encoding, expanded_iv.into(), 1)
+        .unwrap();
+
+    assert_eq!(decode(&encoding, &expanded_iv, 1).unwrap(), piece.to_vec());
+    assert_eq!(
+        Decoder::new().decode(&encoding, &expanded_iv, 1).unwrap(),
+        piece.to_vec()
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_errors() {
+    let error = Spartan::new(&[]).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidGenesisPieceLength);
+    assert_eq!(error.expected_length(), Some(4096));
+    assert_eq!(error.actual_length(), Some(0));
+
+    let error = decode(&[0u8; 4096], &[0u8; 33], 1).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidExpandedIvLength);
+
+    let error = decode(&[0u8; 4096], &[0u8; 32], 0).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::ZeroRounds);
+}